@@ -0,0 +1,23 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day1;
+
+impl Generate for Day1 {
+    /// Elves
+    const SIZE: usize = 250;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        // Part two sums the top three elves
+        let elves: Vec<String> = (0..size.max(3))
+            .map(|_| {
+                let items: Vec<String> = (0..rng.range(1..=15))
+                    .map(|_| rng.range(1000..=60000).to_string())
+                    .collect();
+
+                items.join("\n")
+            })
+            .collect();
+
+        elves.join("\n\n")
+    }
+}
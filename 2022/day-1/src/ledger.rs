@@ -0,0 +1,354 @@
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, BinaryHeap},
+    io::{self, BufRead},
+};
+
+use aoc_core::parse::Line;
+
+/// Elf of the list, with the calories it carries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    /// Position in the list, starting at 0
+    pub index: usize,
+    pub calories: usize,
+}
+
+/// Statistics of the calories carried by the elves, recorded one elf at a time so that the list
+/// does not have to fit in memory.
+///
+/// Only the `k` elves carrying the most are kept, with the number of elves carrying each total
+/// for the median. Past `MAX_BUCKETS` different totals, the totals are counted by ranges twice
+/// as wide, as many times as needed, so that the median is approximate but the memory bounded.
+///
+/// ```
+/// use aoc_2022_day_1::ledger::{Elf, Ledger};
+///
+/// let input = "1000\n2000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+/// let ledger = Ledger::read(input.as_bytes(), 2).unwrap();
+///
+/// let top = ledger.top();
+/// assert_eq!(top[0], Elf { index: 3, calories: 24000 });
+/// assert_eq!(top[1], Elf { index: 2, calories: 11000 });
+/// assert_eq!(ledger.min(), Some(Elf { index: 0, calories: 3000 }));
+/// assert_eq!(ledger.median(), Some(10000.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ledger {
+    k: usize,
+    /// The `k` elves carrying the most, the one carrying the least first. Of two elves carrying
+    /// the same calories, the one further in the list comes first, to be dropped first.
+    top: BinaryHeap<Reverse<(usize, Reverse<usize>)>>,
+    min: Option<Elf>,
+    max: Option<Elf>,
+    elves: usize,
+    total: u128,
+    /// Number of elves carrying a total in each range of `bucket` calories, by start of the range
+    histogram: BTreeMap<usize, usize>,
+    bucket: usize,
+}
+
+impl Ledger {
+    /// Most ranges of totals counted for the median
+    pub const MAX_BUCKETS: usize = 1024;
+
+    /// Empty ledger keeping the `k` elves carrying the most
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            top: BinaryHeap::with_capacity(k + 1),
+            min: None,
+            max: None,
+            elves: 0,
+            total: 0,
+            histogram: BTreeMap::new(),
+            bucket: 1,
+        }
+    }
+
+    /// Ledger of the elves listed by `reader`, one line per item and an empty line between elves,
+    /// read one line at a time. Extra empty lines (eg. at the end) do not count as elves. An
+    /// invalid line fails with an `InvalidData` error wrapping the `ParseError`.
+    pub fn read(mut reader: impl BufRead, k: usize) -> io::Result<Self> {
+        let mut ledger = Ledger::new(k);
+        let mut buffer = String::new();
+        let mut number = 0;
+        let mut calories = 0;
+        // Whether the current elf has any item
+        let mut items = false;
+
+        while reader.read_line(&mut buffer)? > 0 {
+            number += 1;
+            let line = Line {
+                number,
+                text: buffer.trim_end_matches(['\n', '\r']),
+            };
+
+            if line.text.is_empty() {
+                if items {
+                    ledger.record(calories);
+                }
+                calories = 0;
+                items = false;
+            } else {
+                calories += line
+                    .parse::<usize>(line.text, "a number of calories")
+                    .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+                items = true;
+            }
+
+            buffer.clear();
+        }
+        if items {
+            ledger.record(calories);
+        }
+
+        Ok(ledger)
+    }
+
+    /// Records the next elf of the list
+    pub fn record(&mut self, calories: usize) {
+        let elf = Elf {
+            index: self.elves,
+            calories,
+        };
+
+        self.top.push(Reverse((calories, Reverse(elf.index))));
+        if self.top.len() > self.k {
+            self.top.pop();
+        }
+
+        if self.min.is_none_or(|min| calories < min.calories) {
+            self.min = Some(elf);
+        }
+        if self.max.is_none_or(|max| calories > max.calories) {
+            self.max = Some(elf);
+        }
+
+        self.elves += 1;
+        self.total += calories as u128;
+        *self
+            .histogram
+            .entry(calories / self.bucket * self.bucket)
+            .or_default() += 1;
+
+        while self.histogram.len() > Self::MAX_BUCKETS {
+            self.bucket *= 2;
+            let mut merged = BTreeMap::new();
+            for (start, count) in std::mem::take(&mut self.histogram) {
+                *merged.entry(start / self.bucket * self.bucket).or_default() += count;
+            }
+            self.histogram = merged;
+        }
+    }
+
+    /// Number of elves recorded
+    pub fn elves(&self) -> usize {
+        self.elves
+    }
+
+    /// The `k` elves carrying the most (or all the elves if there are less), from the one carrying
+    /// the most. Of two elves carrying the same calories, the first in the list comes first.
+    pub fn top(&self) -> Vec<Elf> {
+        let mut top: Vec<_> = self
+            .top
+            .iter()
+            .map(|&Reverse((calories, Reverse(index)))| Elf { index, calories })
+            .collect();
+        top.sort_by_key(|elf| (Reverse(elf.calories), elf.index));
+
+        top
+    }
+
+    /// Calories carried by the `k` elves carrying the most, all together
+    pub fn top_total(&self) -> usize {
+        self.top
+            .iter()
+            .map(|&Reverse((calories, _))| calories)
+            .sum()
+    }
+
+    /// First elf carrying the most
+    pub fn max(&self) -> Option<Elf> {
+        self.max
+    }
+
+    /// First elf carrying the least
+    pub fn min(&self) -> Option<Elf> {
+        self.min
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        (self.elves > 0).then(|| self.total as f64 / self.elves as f64)
+    }
+
+    /// Middle total, or the mean of the two middle totals for an even number of elves. Once the
+    /// totals are counted by ranges, a total is taken as the middle of its range, which is off by
+    /// at most half the width of the ranges (see `median_error`).
+    pub fn median(&self) -> Option<f64> {
+        if self.elves == 0 {
+            return None;
+        }
+
+        // Totals at the positions `(elves - 1) / 2` and `elves / 2` of the sorted totals
+        let (low, high) = ((self.elves - 1) / 2, self.elves / 2);
+        let mut middle = [None, None];
+        let mut seen = 0;
+        for (&start, &count) in &self.histogram {
+            let calories = start as f64 + (self.bucket - 1) as f64 / 2.0;
+            seen += count;
+            for (position, total) in [low, high].into_iter().zip(&mut middle) {
+                if position < seen && total.is_none() {
+                    *total = Some(calories);
+                }
+            }
+
+            if seen > high {
+                break;
+            }
+        }
+
+        let [Some(low), Some(high)] = middle else {
+            unreachable!("the histogram should count every elf");
+        };
+        Some((low + high) / 2.0)
+    }
+
+    /// Most calories between the `median` and the exact median, 0 while it is exact
+    pub fn median_error(&self) -> f64 {
+        (self.bucket - 1) as f64 / 2.0
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use aoc_core::{Generate, Rng, Solution};
+
+    use super::*;
+    use crate::Day1;
+
+    #[test]
+    fn statistics() {
+        let ledger = Ledger::read(include_str!("../input.test.txt").as_bytes(), 3).unwrap();
+
+        assert_eq!(ledger.elves(), 5);
+        assert_eq!(ledger.top_total(), 45000);
+        assert_eq!(
+            ledger.top().iter().map(|elf| elf.index).collect::<Vec<_>>(),
+            [3, 2, 4]
+        );
+        assert_eq!(
+            ledger.max(),
+            Some(Elf {
+                index: 3,
+                calories: 24000
+            })
+        );
+        assert_eq!(ledger.mean(), Some(11000.0));
+        assert_eq!(ledger.median(), Some(10000.0));
+    }
+
+    #[test]
+    fn ties() {
+        let mut ledger = Ledger::new(2);
+        for calories in [5, 1, 5, 5, 1] {
+            ledger.record(calories);
+        }
+
+        let indexes: Vec<_> = ledger.top().iter().map(|elf| elf.index).collect();
+        assert_eq!(indexes, [0, 2]);
+        assert_eq!(ledger.min().map(|elf| elf.index), Some(1));
+        assert_eq!(ledger.median(), Some(5.0));
+
+        ledger.record(2);
+        assert_eq!(ledger.median(), Some(3.5));
+
+        let empty = Ledger::new(3);
+        assert_eq!(empty.top(), []);
+        assert_eq!(empty.median(), None);
+        assert_eq!(empty.mean(), None);
+    }
+
+    #[test]
+    fn approximate_median() {
+        let mut ledger = Ledger::new(1);
+        let totals: Vec<_> = (0..10_000).map(|elf| elf * 7919 % 100_000).collect();
+        for &calories in &totals {
+            ledger.record(calories);
+        }
+
+        let mut sorted = totals.clone();
+        sorted.sort_unstable();
+        let exact = (sorted[4999] + sorted[5000]) as f64 / 2.0;
+
+        assert!(ledger.histogram.len() <= Ledger::MAX_BUCKETS);
+        assert!(ledger.median_error() > 0.0);
+        assert!((ledger.median().unwrap() - exact).abs() <= ledger.median_error());
+        assert_eq!(ledger.top_total(), sorted[9999]);
+    }
+
+    #[test]
+    fn same_as_parsed() {
+        for seed in 0..10 {
+            let input = Day1.generate(&mut Rng::new(seed), 100);
+            let calories = Day1.parse(&input).unwrap();
+            let ledger = Ledger::read(input.as_bytes(), 10).unwrap();
+
+            let mut sorted = calories.clone();
+            sorted.sort_unstable();
+            assert_eq!(ledger.elves(), calories.len());
+            assert_eq!(ledger.top_total(), sorted.iter().rev().take(10).sum());
+            assert_eq!(ledger.min().unwrap().calories, sorted[0]);
+            assert_eq!(
+                ledger.max().unwrap().calories,
+                calories[ledger.max().unwrap().index]
+            );
+            assert_eq!(
+                ledger.median(),
+                Some((sorted[49] + sorted[50]) as f64 / 2.0)
+            );
+        }
+    }
+
+    #[test]
+    fn blank_lines() {
+        for input in [
+            "1000\n2000\n\n4000\n\n",
+            "\n1000\n2000\n\n\n\n4000\n",
+            "1000\n2000\n\n4000\n\n\n",
+        ] {
+            let ledger = Ledger::read(input.as_bytes(), 1).unwrap();
+
+            assert_eq!(ledger.elves(), 2);
+            assert_eq!(
+                ledger.min(),
+                Some(Elf {
+                    index: 0,
+                    calories: 3000
+                })
+            );
+            assert_eq!(
+                ledger.top(),
+                [Elf {
+                    index: 1,
+                    calories: 4000
+                }]
+            );
+            assert_eq!(ledger.mean(), Some(3500.0));
+        }
+
+        assert_eq!(Ledger::read("\n\n".as_bytes(), 1).unwrap().elves(), 0);
+    }
+
+    #[test]
+    fn invalid_line() {
+        let error = Ledger::read("1000\n\n20x0\n".as_bytes(), 1).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        let error = error.into_inner().unwrap();
+        let error = error.downcast_ref::<aoc_core::ParseError>().unwrap();
+        assert_eq!((error.line, error.column), (3, 1));
+    }
+}
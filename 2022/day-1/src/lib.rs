@@ -0,0 +1,106 @@
+//! Day 1 of 2022: Calorie Counting.
+//!
+//! The input is the total of the calories carried by each elf, in the order of the list.
+//!
+//! ```
+//! use aoc_2022_day_1::top_calories;
+//!
+//! let calories = [6000, 4000, 11000, 24000, 10000];
+//! assert_eq!(top_calories(&calories, 1), 24000);
+//! assert_eq!(top_calories(&calories, 3), 45000);
+//! ```
+//!
+//! The [`ledger`] reads the list one line at a time instead, for lists too long to be kept in
+//! memory.
+
+mod generate;
+pub mod ledger;
+
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use aoc_core::{parse::lines, Answer, ParseError, Print, Solution};
+
+/// Solution of the day, with the calories of each elf as input
+#[derive(Default)]
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        to_calories(input)
+    }
+
+    fn part_one(&self, calories: &Self::Input) -> Answer {
+        top_calories(calories, 1).into()
+    }
+
+    fn part_two(&self, calories: &Self::Input) -> Answer {
+        top_calories(calories, 3).into()
+    }
+}
+
+impl Print for Day1 {
+    /// Prints each elf as carrying a single item
+    fn print(&self, calories: &Self::Input) -> String {
+        let elves: Vec<String> = calories.iter().map(usize::to_string).collect();
+        elves.join("\n\n")
+    }
+}
+
+/// Calories carried by the `count` elves carrying the most, all together
+pub fn top_calories(calories: &[usize], count: usize) -> usize {
+    // The elves carrying the most so far, the one carrying the least on top
+    let mut top = BinaryHeap::with_capacity(count + 1);
+    for &elf in calories {
+        top.push(Reverse(elf));
+        if top.len() > count {
+            top.pop();
+        }
+    }
+
+    top.into_iter().map(|Reverse(elf)| elf).sum()
+}
+
+fn to_calories(input: &str) -> Result<Vec<usize>, ParseError> {
+    let mut calories = vec![0];
+
+    for line in lines(input) {
+        if line.text.is_empty() {
+            calories.push(0);
+        } else {
+            *calories.last_mut().unwrap() +=
+                line.parse::<usize>(line.text, "a number of calories")?;
+        }
+    }
+
+    Ok(calories)
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day1::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(24000));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day1::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(45000));
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day1, 0..20, 30) {
+            panic!("{error}");
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day10;
+
+/// Cycles drawn on the CRT
+const CYCLES: usize = 240;
+
+impl Generate for Day10 {
+    /// Instructions, more are added if needed to run the 240 cycles of the CRT
+    const SIZE: usize = 140;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let mut instructions = Vec::new();
+        let mut cycles = 0;
+
+        while instructions.len() < size || cycles <= CYCLES {
+            if rng.chance(0.3) {
+                instructions.push("noop".to_owned());
+                cycles += 1;
+            } else {
+                instructions.push(format!("addx {}", rng.range(-15..=15)));
+                cycles += 2;
+            }
+        }
+
+        instructions.join("\n")
+    }
+}
@@ -0,0 +1,241 @@
+//! Day 10 of 2022: Cathode-Ray Tube.
+//!
+//! The input is the program run by the CPU of the device.
+//!
+//! ```
+//! use aoc_2022_day_10::{exec_instructions, Instruction};
+//!
+//! let cpu = exec_instructions(&[Instruction::Noop, Instruction::Addx(3), Instruction::Addx(-5)]);
+//! assert_eq!(cpu.history(), [1, 1, 1, 4, 4, -1]);
+//! ```
+
+mod generate;
+
+use std::fmt::{self, Display};
+
+use aoc_core::{
+    parse::{lines, Line},
+    Answer, ParseError, Print, Solution,
+};
+
+/// Solution of the day, with the program as input
+#[derive(Default)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        lines(input)
+            .filter(|line| !line.text.trim().is_empty())
+            .map(|line| Instruction::parse(&line))
+            .collect()
+    }
+
+    fn part_one(&self, instructions: &Self::Input) -> Answer {
+        let cpu = exec_instructions(instructions);
+
+        let mut cycle = 20;
+        let mut result = 0;
+
+        while let Some(register) = cpu.history.get(cycle - 1) {
+            result += register * cycle as i32;
+            cycle += 40;
+        }
+
+        result.into()
+    }
+
+    fn part_two(&self, instructions: &Self::Input) -> Answer {
+        let cpu = exec_instructions(instructions);
+
+        let crt = Crt {
+            width: 40,
+            rows: 6,
+            sprite_width: 3,
+            off_pixel: '.',
+            on_pixel: '#',
+        };
+
+        Answer::Drawing(crt.print(cpu.history))
+    }
+}
+
+impl Print for Day10 {
+    fn print(&self, instructions: &Self::Input) -> String {
+        let lines: Vec<String> = instructions.iter().map(ToString::to_string).collect();
+
+        lines.join("\n")
+    }
+}
+
+/// CPU after running `instructions` from its initial state
+pub fn exec_instructions(instructions: &[Instruction]) -> Cpu {
+    let mut cpu = Cpu::new();
+
+    for instruction in instructions {
+        cpu.execute(*instruction);
+    }
+
+    cpu
+}
+
+/// Screen drawing a pixel per cycle, lit when the sprite centered on the register covers it
+pub struct Crt {
+    pub width: u32,
+    pub rows: u32,
+    pub sprite_width: u32,
+    pub on_pixel: char,
+    pub off_pixel: char,
+}
+
+impl Crt {
+    /// Rows of pixels drawn for the values of the register during each cycle
+    pub fn print(&self, register: Vec<i32>) -> String {
+        let sprite_offset = (self.sprite_width / 2) as i32;
+        let mut result = String::with_capacity((self.rows * self.width + self.rows) as usize);
+
+        for row in 0..self.rows {
+            for col in 0..self.width {
+                match register.get((self.width * row + col) as usize) {
+                    Some(sprite_pos) => {
+                        let sprite_range = sprite_pos - sprite_offset..=sprite_pos + sprite_offset;
+                        let pixel = if sprite_range.contains(&(col as i32)) {
+                            self.on_pixel
+                        } else {
+                            self.off_pixel
+                        };
+                        result.push(pixel)
+                    }
+                    None => result.push(self.off_pixel),
+                }
+            }
+            let last_row = row == self.rows - 1;
+            if !last_row {
+                result.push('\n');
+            }
+        }
+
+        result
+    }
+}
+
+/// CPU with a single register, starting at 1
+pub struct Cpu {
+    cycle: u32,
+    register: i32,
+    history: Vec<i32>,
+}
+
+impl Default for Cpu {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Cpu {
+    pub fn new() -> Self {
+        Self {
+            cycle: 1,
+            register: 1,
+            history: vec![1],
+        }
+    }
+
+    /// Value of the register during each cycle, from the first one, then after the last one
+    pub fn history(&self) -> &[i32] {
+        &self.history
+    }
+
+    pub fn execute(&mut self, instruction: Instruction) {
+        let consumed_cycles = instruction.cycles();
+        self.cycle += consumed_cycles;
+
+        for _ in 1..consumed_cycles {
+            self.history.push(self.register);
+        }
+
+        self.register += match instruction {
+            Instruction::Noop => 0,
+            Instruction::Addx(value) => value,
+        };
+
+        self.history.push(self.register);
+    }
+}
+
+/// Instruction of the CPU, `addx` adding to the register in two cycles and `noop` taking one
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    Addx(i32),
+    Noop,
+}
+
+impl Instruction {
+    fn parse(line: &Line) -> Result<Instruction, ParseError> {
+        let text = line.text.trim();
+        if text == "noop" {
+            Ok(Instruction::Noop)
+        } else if let Some(arg) = text.strip_prefix("addx ") {
+            Ok(Instruction::Addx(
+                line.parse(arg, "a numeric argument for addx")?,
+            ))
+        } else {
+            Err(line.error(text, "`noop` or `addx <value>`"))
+        }
+    }
+
+    fn cycles(&self) -> u32 {
+        match self {
+            Instruction::Noop => 1,
+            Instruction::Addx(_) => 2,
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Noop => write!(f, "noop"),
+            Instruction::Addx(value) => write!(f, "addx {value}"),
+        }
+    }
+}
+
+// --- TESTS ---
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day10::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(13140));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day10::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(
+            day.part_two(&input),
+            Answer::Drawing(
+                r"##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######....."
+                    .to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day10, 0..20, 50) {
+            panic!("{error}");
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day11;
+
+/// Divisors of the tests. Their product, which bounds the worry levels of part two, stays small
+/// enough for a squared worry level to fit in 64 bits.
+const DIVISORS: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+impl Generate for Day11 {
+    /// Monkeys, at most 9
+    const SIZE: usize = 8;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let monkeys = size.clamp(2, DIVISORS.len());
+        let mut divisors = DIVISORS;
+        rng.shuffle(&mut divisors);
+
+        let notes: Vec<String> = (0..monkeys)
+            .map(|monkey| {
+                let items: Vec<String> = (0..rng.range(1..=8))
+                    .map(|_| rng.range(50..=99).to_string())
+                    .collect();
+
+                let operation = match rng.index(4) {
+                    0 => "* old".to_owned(),
+                    1 => format!("* {}", rng.range(2..=19)),
+                    _ => format!("+ {}", rng.range(1..=8)),
+                };
+
+                // Throws to two other monkeys
+                let if_true = (monkey + 1 + rng.index(monkeys - 1)) % monkeys;
+                let mut if_false = (monkey + 1 + rng.index(monkeys - 1)) % monkeys;
+                if if_false == if_true && monkeys > 2 {
+                    if_false = (if_false + 1) % monkeys;
+                    if if_false == monkey {
+                        if_false = (if_false + 1) % monkeys;
+                    }
+                }
+
+                [
+                    format!("Monkey {monkey}:"),
+                    format!("  Starting items: {}", items.join(", ")),
+                    format!("  Operation: new = old {operation}"),
+                    format!("  Test: divisible by {}", divisors[monkey]),
+                    format!("    If true: throw to monkey {if_true}"),
+                    format!("    If false: throw to monkey {if_false}"),
+                ]
+                .join("\n")
+            })
+            .collect();
+
+        notes.join("\n\n")
+    }
+}
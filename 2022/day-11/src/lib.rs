@@ -0,0 +1,344 @@
+//! Day 11 of 2022: Monkey in the Middle.
+//!
+//! The input is the notes on each monkey: the worry levels of its items and how it throws them.
+//!
+//! ```
+//! use aoc_2022_day_11::{Monkey, MonkeyGroup, Operation};
+//!
+//! let monkey = |items, operation, throw_to| Monkey {
+//!     items,
+//!     operation,
+//!     divisor: 23,
+//!     true_branch: throw_to,
+//!     false_branch: throw_to,
+//! };
+//! let monkeys = vec![
+//!     monkey(vec![79, 98], Operation::Mul(19), 1),
+//!     monkey(vec![54], Operation::Square, 0),
+//! ];
+//!
+//! let mut group = MonkeyGroup::new(monkeys, 3);
+//! group.exec_round();
+//! assert_eq!(group.inspections(), [2, 3]);
+//! assert_eq!(group.monkey_business(), 6);
+//! ```
+
+mod generate;
+
+use std::fmt::{self, Display};
+
+use aoc_core::{
+    parse::{lines, Line},
+    Answer, ParseError, Print, Solution,
+};
+
+/// Solution of the day, with the notes on the monkeys as input
+#[derive(Default)]
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<_> = lines(input).collect();
+
+        // The monkey business needs the two most active monkeys
+        let monkeys = lines.len().div_ceil(7);
+        if monkeys < 2 {
+            return Err(ParseError::end_of_input(
+                input,
+                "the notes of a second monkey",
+            ));
+        }
+
+        lines
+            .chunks(7)
+            .map(|monkey_lines| Monkey::parse(input, monkey_lines, monkeys))
+            .collect()
+    }
+
+    fn part_one(&self, monkeys: &Self::Input) -> Answer {
+        const ROUNDS: u8 = 20;
+        const RELIEF_FACTOR: Item = 3;
+        let mut monkey_group = MonkeyGroup::new(monkeys.clone(), RELIEF_FACTOR);
+
+        for _ in 0..ROUNDS {
+            monkey_group.exec_round();
+        }
+
+        monkey_group.monkey_business().into()
+    }
+
+    fn part_two(&self, monkeys: &Self::Input) -> Answer {
+        const ROUNDS: u16 = 10_000;
+        const RELIEF_FACTOR: Item = 1;
+        let mut monkey_group = MonkeyGroup::new(monkeys.clone(), RELIEF_FACTOR);
+
+        for _ in 0..ROUNDS {
+            monkey_group.exec_round();
+        }
+
+        monkey_group.monkey_business().into()
+    }
+}
+
+impl Print for Day11 {
+    fn print(&self, monkeys: &Self::Input) -> String {
+        let notes: Vec<String> = monkeys
+            .iter()
+            .enumerate()
+            .map(|(i, monkey)| monkey.print(i))
+            .collect();
+
+        notes.join("\n\n")
+    }
+}
+
+/// Worry level of an item
+pub type Item = u64;
+
+/// Monkeys throwing items to each other, counting how many items each of them inspected
+#[derive(Debug)]
+pub struct MonkeyGroup {
+    monkeys: Vec<Monkey>,
+    inspections: Vec<u32>,
+    relief_factor: Item,
+    divisor_product: Item,
+}
+
+impl MonkeyGroup {
+    /// Group of `monkeys`, the worry levels being divided by `relief_factor` after each inspection
+    pub fn new(monkeys: Vec<Monkey>, relief_factor: Item) -> Self {
+        Self {
+            inspections: vec![0; monkeys.len()],
+            divisor_product: monkeys.iter().map(|m| m.divisor).product(),
+            monkeys,
+            relief_factor,
+        }
+    }
+
+    /// Each monkey in turn inspects and throws all its items
+    pub fn exec_round(&mut self) {
+        let monkeys = &mut self.monkeys;
+        for i in 0..monkeys.len() {
+            let monkey = &monkeys[i];
+            self.inspections[i] += monkey.items.len() as u32;
+
+            for j in 0..monkey.items.len() {
+                let monkey = &monkeys[i];
+                let item = monkey.items[j];
+
+                // Bless fasterthanli 🙏 (https://fasterthanli.me/series/advent-of-code-2022/part-11#math-check)
+                let item =
+                    (monkey.operation.apply(item) / self.relief_factor) % self.divisor_product;
+                let throw_to = monkey.throw_to(item);
+                monkeys[throw_to].items.push(item);
+            }
+
+            monkeys[i].items.clear();
+        }
+    }
+
+    /// Number of items inspected by each monkey
+    pub fn inspections(&self) -> &[u32] {
+        &self.inspections
+    }
+
+    /// Product of the inspections of the two most active monkeys
+    pub fn monkey_business(&self) -> u64 {
+        let mut inspections = self.inspections.clone();
+        inspections.sort();
+        let len = inspections.len();
+
+        inspections[len - 1] as u64 * inspections[len - 2] as u64
+    }
+}
+
+/// Monkey, throwing each item to `true_branch` if its worry level is divisible by `divisor` and
+/// to `false_branch` otherwise
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Monkey {
+    pub items: Vec<Item>,
+    /// Change of the worry level of an item when it is inspected
+    pub operation: Operation,
+    pub divisor: Item,
+    pub true_branch: usize,
+    pub false_branch: usize,
+}
+
+/// Operation applied to the worry level `old`: `old + value`, `old * value`, `old + old` or
+/// `old * old`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Operation {
+    Add(Item),
+    Mul(Item),
+    Double,
+    Square,
+}
+
+impl Operation {
+    pub fn apply(&self, n: Item) -> Item {
+        match self {
+            Operation::Add(value) => n + value,
+            Operation::Mul(value) => n * value,
+            Operation::Double => n + n,
+            Operation::Square => n * n,
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::Add(value) => write!(f, "+ {value}"),
+            Operation::Mul(value) => write!(f, "* {value}"),
+            Operation::Double => write!(f, "+ old"),
+            Operation::Square => write!(f, "* old"),
+        }
+    }
+}
+
+impl Monkey {
+    /// Monkey to which an item of worry level `item` is thrown
+    pub fn throw_to(&self, item: Item) -> usize {
+        if item.is_multiple_of(self.divisor) {
+            self.true_branch
+        } else {
+            self.false_branch
+        }
+    }
+
+    /// Notes of the monkey numbered `i`
+    fn print(&self, i: usize) -> String {
+        let items: Vec<String> = self.items.iter().map(ToString::to_string).collect();
+
+        [
+            format!("Monkey {i}:"),
+            format!("  Starting items: {}", items.join(", ")),
+            format!("  Operation: new = old {}", self.operation),
+            format!("  Test: divisible by {}", self.divisor),
+            format!("    If true: throw to monkey {}", self.true_branch),
+            format!("    If false: throw to monkey {}", self.false_branch),
+        ]
+        .join("\n")
+    }
+
+    /// Notes of a monkey among `monkeys`, to which it can throw items
+    fn parse(input: &str, lines: &[Line], monkeys: usize) -> Result<Self, ParseError> {
+        // Finds the line `i` of the monkey and strips its description
+        let field = |i: usize, description: &str| {
+            let line = lines
+                .get(i)
+                .ok_or_else(|| ParseError::end_of_input(input, format!("`{description}`")))?;
+            let value = line.strip_prefix(line.text.trim_start(), description)?;
+
+            Ok::<_, ParseError>((line, value))
+        };
+
+        let (line, items) = field(1, "Starting items: ")?;
+        let items = items
+            .split(',')
+            .map(|n| line.parse(n, "a worry level"))
+            .collect::<Result<_, _>>()?;
+
+        let (line, operation) = field(2, "Operation: new = old ")?;
+        let (operation_str, value) = line.split_once(operation, " ")?;
+        let operation = match (operation_str, value) {
+            ("+", "old") => Operation::Double,
+            ("*", "old") => Operation::Square,
+            ("+", value) => Operation::Add(line.parse(value, "a number or `old`")?),
+            ("*", value) => Operation::Mul(line.parse(value, "a number or `old`")?),
+            _ => return Err(line.error(operation_str, "`+` or `*`")),
+        };
+
+        let (line, divisor) = field(3, "Test: divisible by ")?;
+        let divisor = line.parse(divisor, "a divisor")?;
+        let branch = |i: usize, description: &str| {
+            let (line, branch) = field(i, description)?;
+            let expected = format!("a monkey number below {monkeys}");
+
+            match line.parse(branch, &expected)? {
+                monkey if monkey < monkeys => Ok(monkey),
+                _ => Err(line.error(branch, expected)),
+            }
+        };
+        let true_branch = branch(4, "If true: throw to monkey ")?;
+        let false_branch = branch(5, "If false: throw to monkey ")?;
+
+        Ok(Monkey {
+            items,
+            operation,
+            divisor,
+            true_branch,
+            false_branch,
+        })
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day11::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(10605));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day11::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(2713310158));
+    }
+
+    #[test]
+    fn invalid_monkey() {
+        let input =
+            include_str!("../input.test.txt").replacen("new = old * 19", "new = old / 19", 1);
+
+        let error = Day11::example().parse(&input).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.expected.as_str()),
+            (3, 24, "`+` or `*`")
+        );
+    }
+
+    #[test]
+    fn unknown_monkey() {
+        let input =
+            include_str!("../input.test.txt").replacen("throw to monkey 3", "throw to monkey 4", 1);
+
+        let error = Day11::example().parse(&input).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.expected.as_str()),
+            (6, 31, "a monkey number below 4")
+        );
+    }
+
+    #[test]
+    fn single_monkey() {
+        let input: String = include_str!("../input.test.txt")
+            .lines()
+            .take(6)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let error = Day11::example().parse(&input).unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (7, "the notes of a second monkey")
+        );
+        assert!(Day11::example().parse("").is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day11, 0..20, 8) {
+            panic!("{error}");
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day12;
+
+/// Heights from `a` to `z`
+const HEIGHTS: usize = 26;
+
+impl Generate for Day12 {
+    /// Rows of the heightmap, which is four times wider than high
+    const SIZE: usize = 41;
+
+    /// Random heights, crossed by a climbable way from the start on the left to the end on the
+    /// right
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let height = size.max(2);
+        let width = (height * 4).max(HEIGHTS);
+
+        let mut map: Vec<Vec<u8>> = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| b'a' + rng.index(HEIGHTS) as u8)
+                    .collect()
+            })
+            .collect();
+
+        // Goes right, moving up or down in the columns
+        let mut y = rng.index(height);
+        let mut way = Vec::new();
+        for x in 0..width {
+            let target = rng.index(height);
+            while y != target && rng.chance(0.8) {
+                way.push((x, y));
+                y = if target > y { y + 1 } else { y - 1 };
+            }
+            way.push((x, y));
+        }
+
+        // The start is followed by a `b`, and the way climbs up to the `z` of the end
+        let steps = way.len() - 2;
+        for (i, &(x, y)) in way.iter().enumerate() {
+            map[y][x] = match i {
+                0 => b'S',
+                i if i == way.len() - 1 => b'E',
+                i => b'b' + ((i - 1) * (HEIGHTS - 2) / steps) as u8,
+            };
+        }
+
+        let rows: Vec<String> = map
+            .into_iter()
+            .map(|row| String::from_utf8(row).unwrap())
+            .collect();
+        rows.join("\n")
+    }
+}
@@ -0,0 +1,223 @@
+//! Day 12 of 2022: Hill Climbing Algorithm.
+//!
+//! The input is the heightmap, with the start and the end of the climb.
+//!
+//! ```
+//! use aoc_2022_day_12::Climb;
+//! use geometry::Point;
+//! use grid::Grid;
+//!
+//! // abc
+//! // fed
+//! let heights = Grid::parse("abc\nfed", |c| Ok(c as u8 - b'a')).unwrap();
+//! let climb = Climb::new(heights, Point::new(0, 0), Point::new(0, 1), 1);
+//!
+//! let search = climb.search([climb.start()], true);
+//! assert_eq!(search.goal_cost(), Some(5));
+//! ```
+
+mod generate;
+
+use aoc_core::{Answer, ParseError, Part, Print, Solution};
+use geometry::Point;
+use grid::Grid;
+use raster::{Image, Render, Rgb};
+use search::Search;
+
+/// Solution of the day, with the heightmap as input
+#[derive(Default)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Climb;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        const MAX_CLIMB: u8 = 1;
+        Climb::parse(input, MAX_CLIMB)
+    }
+
+    fn part_one(&self, climb: &Self::Input) -> Answer {
+        let search = climb.search([climb.start], true);
+
+        search
+            .goal_cost()
+            .expect("the end should be reachable")
+            .into()
+    }
+
+    fn part_two(&self, climb: &Self::Input) -> Answer {
+        // Searching from every possible start at once finds the way from the nearest one
+        let search = climb.search(climb.possible_starts(), true);
+
+        search
+            .goal_cost()
+            .expect("the end should be reachable")
+            .into()
+    }
+}
+
+impl Print for Day12 {
+    fn print(&self, climb: &Self::Input) -> String {
+        let mut cells = climb.heights.map(|height| (b'a' + height) as char);
+        cells[climb.start] = 'S';
+        cells[climb.end] = 'E';
+
+        cells.to_string().trim_end().to_owned()
+    }
+}
+
+impl Render for Day12 {
+    /// Colours the cost to reach each cell from the start (the best one for part two), with the
+    /// shortest way to the end in white and the unreachable cells in gray
+    fn render(&self, climb: &Self::Input, part: Part) -> Image {
+        let way = match part {
+            Part::One => climb.search([climb.start], true).path(&climb.end),
+            Part::Two => climb.search(climb.possible_starts(), true).path(&climb.end),
+        };
+        let start = way.as_ref().map_or(climb.start, |way| way[0]);
+        let costs = climb.search([start], false);
+
+        let max_cost = costs.costs().map(|(_, cost)| cost).max();
+        let max_cost = max_cost.unwrap_or(0).max(1) as f64;
+
+        let heights = &climb.heights;
+        let mut image = Image::from_grid(heights, |&height| Rgb::gray(30 + height * 4));
+        for (point, cost) in costs.costs() {
+            image.set_cell(heights, *point, Rgb::heat(cost as f64 / max_cost));
+        }
+
+        for point in way.unwrap_or_default() {
+            image.set_cell(heights, point, Rgb::WHITE);
+        }
+
+        image
+    }
+}
+
+/// Heightmap on which each step can climb by at most `max_climb`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Climb {
+    heights: Grid<u8>,
+    start: Point,
+    end: Point,
+    max_climb: u8,
+}
+
+impl Climb {
+    /// Climb over `grid`, whose heights go from 0 (`a`) to 25 (`z`)
+    pub fn new(grid: Grid<u8>, start: Point, end: Point, max_step: u8) -> Self {
+        Self {
+            max_climb: max_step,
+            heights: grid,
+            start,
+            end,
+        }
+    }
+
+    fn parse(input: &str, max_step: u8) -> Result<Self, ParseError> {
+        let mut start = None;
+        let mut end = None;
+
+        let mut grid = Grid::parse(input.trim(), |c| match c {
+            'a'..='z' | 'S' | 'E' => Ok(c as u8),
+            _ => Err("a height (`a` to `z`), `S` or `E`"),
+        })?;
+        for (point, cell) in grid.iter_mut() {
+            if *cell == b'S' {
+                start = Some(point);
+                *cell = b'a';
+            } else if *cell == b'E' {
+                end = Some(point);
+                *cell = b'z';
+            }
+
+            *cell -= b'a';
+        }
+
+        let start = start.ok_or_else(|| ParseError::end_of_input(input, "a start (`S`)"))?;
+        let end = end.ok_or_else(|| ParseError::end_of_input(input, "an end (`E`)"))?;
+
+        Ok(Climb::new(grid, start, end, max_step))
+    }
+
+    pub fn heights(&self) -> &Grid<u8> {
+        &self.heights
+    }
+
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    pub fn end(&self) -> Point {
+        self.end
+    }
+
+    /// Lowest cells next to a cell one step higher
+    pub fn possible_starts(&self) -> Vec<Point> {
+        self.heights
+            .points()
+            .filter(|p| self.heights[*p] == 0)
+            .filter(|p| self.heights.neighbours4(*p).any(|n| self.heights[n] == 1))
+            .collect()
+    }
+
+    /// Cells which can be climbed from `point`
+    pub fn climbable(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        let max_height = self.heights[point] + self.max_climb;
+
+        point
+            .neighbours4()
+            .filter(move |&n| self.heights.get(n).is_some_and(|h| *h <= max_height))
+    }
+
+    /// Searches the ways from the nearest of `starts`, stopping at the end if `to_end` is set
+    pub fn search(&self, starts: impl IntoIterator<Item = Point>, to_end: bool) -> Search<Point> {
+        search::bfs(
+            starts,
+            |&point| self.climbable(point),
+            |&point| to_end && point == self.end,
+        )
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day12::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(31));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day12::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(29));
+    }
+
+    #[test]
+    fn render() {
+        let day = Day12::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+
+        let way = input.search([input.start], true).path(&input.end).unwrap();
+        assert_eq!(way.len(), 32);
+        assert_eq!((way[0], way[31]), (input.start, input.end));
+
+        let image = day.render(&input, Part::Two);
+        assert_eq!((image.width(), image.height()), (8, 5));
+        assert_eq!(image.get(5, 2), Rgb::WHITE);
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day12, 0..20, 20) {
+            panic!("{error}");
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day13;
+
+/// Deepest nesting of the lists
+const MAX_DEPTH: u32 = 4;
+
+impl Generate for Day13 {
+    /// Pairs of packets
+    const SIZE: usize = 150;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let pairs: Vec<String> = (0..size.max(1))
+            .map(|_| format!("{}\n{}", two_numbers(rng), two_numbers(rng)))
+            .collect();
+
+        pairs.join("\n\n")
+    }
+}
+
+/// Packet with at least two numbers, which cannot be equal to the divider packets `[[2]]` and
+/// `[[6]]`
+fn two_numbers(rng: &mut Rng) -> String {
+    loop {
+        let packet = packet(rng, 0);
+        let numbers = packet
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|n| !n.is_empty());
+
+        if numbers.count() >= 2 {
+            return packet;
+        }
+    }
+}
+
+/// List of numbers and lists, written as in the puzzle
+fn packet(rng: &mut Rng, depth: u32) -> String {
+    let items: Vec<String> = (0..rng.range(0..=5))
+        .map(|_| {
+            if depth < MAX_DEPTH && rng.chance(0.3) {
+                packet(rng, depth + 1)
+            } else {
+                rng.range(0..=10).to_string()
+            }
+        })
+        .collect();
+
+    format!("[{}]", items.join(","))
+}
@@ -0,0 +1,282 @@
+//! Day 13 of 2022: Distress Signal.
+//!
+//! The input is the list of the pairs of packets received.
+//!
+//! ```
+//! use aoc_2022_day_13::{Order, Packet, PacketPair};
+//!
+//! let left: Packet = "[[1],[2,3,4]]".parse().unwrap();
+//! let right: Packet = "[[1],4]".parse().unwrap();
+//! assert!(left < right);
+//! assert_eq!(PacketPair { left, right }.order(), Order::Right);
+//! ```
+
+mod generate;
+
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use aoc_core::{
+    parse::{lines, Line},
+    Answer, ParseError, Print, Solution,
+};
+
+/// Solution of the day, with the pairs of packets as input
+#[derive(Default)]
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<PacketPair>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        PacketPair::parse_input(input)
+    }
+
+    fn part_one(&self, pairs: &Self::Input) -> Answer {
+        let result: usize = pairs
+            .iter()
+            .map(|pair| pair.order())
+            .enumerate()
+            .map(|(i, order)| (i + 1, order))
+            .filter(|(_, order)| matches!(order, Order::Right))
+            .map(|(i, _)| i)
+            .sum();
+
+        result.into()
+    }
+
+    fn part_two(&self, pairs: &Self::Input) -> Answer {
+        let mut packets: Vec<_> = pairs
+            .iter()
+            .flat_map(|pair| [pair.left.clone(), pair.right.clone()])
+            .collect();
+
+        let distress_signal_1 = Packet::List(vec![Packet::List(vec![Packet::Number(2)])]);
+        let distress_signal_2 = Packet::List(vec![Packet::List(vec![Packet::Number(6)])]);
+
+        packets.push(distress_signal_1.clone());
+        packets.push(distress_signal_2.clone());
+
+        packets.sort();
+
+        let result: usize = packets
+            .iter()
+            .enumerate()
+            .map(|(i, packet)| (i + 1, packet))
+            .filter(|(_, packet)| {
+                matches!((*packet).cmp(&distress_signal_1), Ordering::Equal)
+                    || matches!((*packet).cmp(&distress_signal_2), Ordering::Equal)
+            })
+            .map(|(i, _)| i)
+            .product();
+
+        result.into()
+    }
+}
+
+impl Print for Day13 {
+    fn print(&self, pairs: &Self::Input) -> String {
+        let pairs: Vec<String> = pairs
+            .iter()
+            .map(|pair| format!("{}\n{}", pair.left, pair.right))
+            .collect();
+
+        pairs.join("\n\n")
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct PacketPair {
+    pub left: Packet,
+    pub right: Packet,
+}
+
+/// Packet, ordered as the puzzle compares them: a number against a list is compared as a list of
+/// that number
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Packet {
+    Number(u8),
+    List(Vec<Packet>),
+}
+
+/// Order of the packets of a pair
+#[derive(Debug, PartialEq, Eq)]
+pub enum Order {
+    Right,
+    Wrong,
+    Equal,
+}
+
+impl PacketPair {
+    fn parse_input(input: &str) -> Result<Vec<Self>, ParseError> {
+        let lines: Vec<_> = lines(input).collect();
+
+        lines
+            .chunks(3)
+            .map(|chunk| match chunk {
+                [first, second, ..] => Self::parse(first, second),
+                _ => Err(ParseError::end_of_input(input, "a second packet")),
+            })
+            .collect()
+    }
+
+    fn parse(first: &Line, second: &Line) -> Result<Self, ParseError> {
+        Ok(Self {
+            left: Packet::parse(first, first.text.trim())?,
+            right: Packet::parse(second, second.text.trim())?,
+        })
+    }
+
+    pub fn order(&self) -> Order {
+        match self.left.cmp(&self.right) {
+            Ordering::Equal => Order::Equal,
+            Ordering::Less => Order::Right,
+            Ordering::Greater => Order::Wrong,
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Number(l), Self::Number(r)) => l.cmp(r),
+            (Self::List(l), Self::List(r)) => {
+                for (l_pack, r_pack) in l.iter().zip(r) {
+                    match l_pack.cmp(r_pack) {
+                        Ordering::Equal => (),
+                        other => return other,
+                    }
+                }
+
+                l.len().cmp(&r.len())
+            }
+            (list, Self::Number(r)) => list.cmp(&Self::List(vec![Self::Number(*r)])),
+            (Self::Number(l), list) => Self::List(vec![Self::Number(*l)]).cmp(list),
+        }
+    }
+}
+
+impl Packet {
+    /// Parses `packet`, a slice of `line`
+    fn parse(line: &Line, packet: &str) -> Result<Self, ParseError> {
+        let (packet, rest) = Self::parse_prefix(line, packet)?;
+
+        if rest.is_empty() {
+            Ok(packet)
+        } else {
+            Err(line.error(rest, "the end of the packet"))
+        }
+    }
+
+    /// Parses the packet at the start of `text`, returning it with the text after it
+    fn parse_prefix<'a>(line: &Line, text: &'a str) -> Result<(Self, &'a str), ParseError> {
+        let Some(mut rest) = text.strip_prefix('[') else {
+            let end = text
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(text.len());
+            let number = line.parse(&text[..end], "a number or `[`")?;
+
+            return Ok((Self::Number(number), &text[end..]));
+        };
+
+        let mut list = vec![];
+        if let Some(rest) = rest.strip_prefix(']') {
+            return Ok((Self::List(list), rest));
+        }
+
+        loop {
+            let (packet, after) = Self::parse_prefix(line, rest)?;
+            list.push(packet);
+
+            match after.chars().next() {
+                Some(',') => rest = &after[1..],
+                Some(']') => return Ok((Self::List(list), &after[1..])),
+                _ => return Err(line.error(after, "`,` or `]`")),
+            }
+        }
+    }
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let line = lines(text)
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(text, "a packet"))?;
+
+        Packet::parse(&line, line.text.trim())
+    }
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Number(number) => write!(f, "{number}"),
+            Packet::List(list) => {
+                let packets: Vec<String> = list.iter().map(ToString::to_string).collect();
+                write!(f, "[{}]", packets.join(","))
+            }
+        }
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day13::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day13::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(140));
+    }
+
+    #[test]
+    fn nested_lists() {
+        let day = Day13::example();
+        let pairs = day.parse("[[1],[2,[3]],4]\n[[]]").unwrap();
+
+        let packet = |text| Packet::parse(&lines(text).next().unwrap(), text).unwrap();
+        assert_eq!(
+            pairs[0].left,
+            Packet::List(vec![
+                packet("[1]"),
+                Packet::List(vec![Packet::Number(2), packet("[3]")]),
+                Packet::Number(4),
+            ])
+        );
+        assert_eq!(pairs[0].right, Packet::List(vec![Packet::List(vec![])]));
+
+        let error = day.parse("[1,2]]\n[]").unwrap_err();
+        assert_eq!(
+            (error.column, error.expected.as_str()),
+            (6, "the end of the packet")
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day13, 0..20, 20) {
+            panic!("{error}");
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day14;
+
+impl Generate for Day14 {
+    /// Paths of rock
+    const SIZE: usize = 150;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let paths: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let mut x = rng.range(460..=540);
+                let mut y = rng.range(2..=170);
+                let mut points = vec![format!("{x},{y}")];
+
+                // Alternates horizontal and vertical lines
+                let horizontal = rng.chance(0.5);
+                for i in 0..rng.range(1..=4) {
+                    if (i % 2 == 0) == horizontal {
+                        x = (x + rng.range(-10..=10)).clamp(440, 560);
+                    } else {
+                        y = (y + rng.range(-10..=10)).clamp(2, 175);
+                    }
+                    points.push(format!("{x},{y}"));
+                }
+
+                points.join(" -> ")
+            })
+            .collect();
+
+        paths.join("\n")
+    }
+}
@@ -0,0 +1,379 @@
+//! Day 14 of 2022: Regolith Reservoir.
+//!
+//! The input is the list of the paths of rock, as the points joined by straight lines.
+//!
+//! ```
+//! use aoc_2022_day_14::Cave;
+//! use geometry::Point;
+//!
+//! let paths = vec![
+//!     vec![Point::new(498, 4), Point::new(498, 6), Point::new(496, 6)],
+//!     vec![Point::new(503, 4), Point::new(502, 4), Point::new(502, 9), Point::new(494, 9)],
+//! ];
+//!
+//! let mut cave = Cave::new(&paths, false);
+//! while cave.step() {}
+//! assert_eq!(cave.resting_sand(), 24);
+//! ```
+
+mod generate;
+pub mod line;
+
+use std::{
+    cmp::{max, min},
+    collections::HashSet,
+    fmt::Display,
+    io::Write,
+};
+
+use animation::{Animate, Canvas, Color, Glyph, Paint};
+use aoc_core::{parse::lines, Answer, ParseError, Part, Print, Solution};
+use geometry::Point;
+use grid::Grid;
+use raster::{Image, Render, Rgb};
+
+use crate::line::Line;
+
+/// Solution of the day, with the paths of rock as input
+#[derive(Default)]
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Vec<Point>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Cave::parse_coords(input)
+    }
+
+    fn part_one(&self, paths: &Self::Input) -> Answer {
+        let mut cave = Cave::new(paths, false);
+        while cave.step() {}
+
+        cave.resting_sand().into()
+    }
+
+    fn part_two(&self, paths: &Self::Input) -> Answer {
+        let mut cave = Cave::new(paths, true);
+        while cave.step() {}
+
+        cave.resting_sand().into()
+    }
+}
+
+impl Print for Day14 {
+    fn print(&self, paths: &Self::Input) -> String {
+        let lines: Vec<String> = paths
+            .iter()
+            .map(|path| {
+                let points: Vec<String> = path
+                    .iter()
+                    .map(|point| format!("{},{}", point.x, point.y))
+                    .collect();
+                points.join(" -> ")
+            })
+            .collect();
+
+        lines.join("\n")
+    }
+}
+
+impl Animate for Day14 {
+    fn animate<W: Write>(&self, paths: &Self::Input, part: Part, canvas: &mut Canvas<W>) {
+        let mut cave = Cave::new(paths, part == Part::Two);
+
+        canvas.run(&mut cave, Cave::step, |canvas, cave| {
+            let mut frame = cave.grid.clone();
+            if let Some(sand) = cave.sand_falling {
+                frame[sand] = Element::Sand;
+            }
+
+            canvas.draw(&frame, format!("Resting sand: {}", cave.resting_sand()));
+        });
+    }
+}
+
+impl Render for Day14 {
+    fn render(&self, paths: &Self::Input, part: Part) -> Image {
+        let mut cave = Cave::new(paths, part == Part::Two);
+        while cave.step() {}
+
+        Image::from_grid(&cave.grid, |element| match element {
+            Element::Air => Rgb(16, 16, 32),
+            Element::Rock => Rgb::gray(140),
+            Element::Sand => Rgb(230, 190, 90),
+            Element::SandSource => Rgb(220, 40, 40),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Element {
+    Air,
+    Rock,
+    Sand,
+    SandSource,
+}
+
+/// Slice of the cave in which sand falls from `(500, 0)`, a unit at a time, y going down
+pub struct Cave {
+    sand_falling: Option<Point>,
+    sand_source: Point,
+    grid: Grid<Element>,
+    moves: [Point; 3],
+}
+
+impl Cave {
+    /// Content of the cave, with the sand at rest
+    pub fn grid(&self) -> &Grid<Element> {
+        &self.grid
+    }
+
+    /// Unit of sand falling, if any
+    pub fn sand_falling(&self) -> Option<Point> {
+        self.sand_falling
+    }
+
+    pub fn resting_sand(&self) -> usize {
+        self.grid
+            .values()
+            .filter(|element| matches!(element, Element::Sand))
+            .count()
+    }
+
+    /// Moves the falling sand by one position, or pours a new unit of sand, returning `false`
+    /// once sand falls out of the cave or blocks the source
+    pub fn step(&mut self) -> bool {
+        match self.sand_falling {
+            Some(cur_pos) => {
+                for (i, next_pos) in self
+                    .moves
+                    .iter()
+                    .map(|&movement| cur_pos + movement)
+                    .enumerate()
+                {
+                    let element = self.grid.get_mut(next_pos);
+
+                    match element {
+                        None => return false,
+                        Some(Element::Air) => {
+                            self.sand_falling = Some(next_pos);
+                            break;
+                        }
+                        Some(Element::SandSource) => {
+                            panic!("sand cannot fall on top of sand source")
+                        }
+                        Some(_) if i == self.moves.len() - 1 => {
+                            *self.grid.get_mut(cur_pos).unwrap() = Element::Sand;
+                            self.sand_falling = None;
+                        }
+                        _ => (),
+                    }
+                }
+            }
+            None if matches!(self.grid.get(self.sand_source).unwrap(), Element::Sand) => {
+                return false
+            }
+            None => self.sand_falling = Some(self.sand_source),
+        };
+
+        true
+    }
+
+    /// Cave with the rock paths of `lines_coords`, and an endless floor two units under the lowest
+    /// rock if `has_floor` is set
+    pub fn new(lines_coords: &[Vec<Point>], has_floor: bool) -> Self {
+        let sand_x = 500;
+
+        // The sand source is in the grid even when no rock is under it
+        let coords = lines_coords.iter().flatten().copied();
+        let (min_coord, max_coord) = Point::min_max(coords.chain([Point::new(sand_x, 0)]))
+            .expect("should contain the sand source");
+
+        let (mut min_x, mut max_x) = (min_coord.x, max_coord.x);
+        let min_y = 0;
+
+        let mut height = max_coord.y - min_y + 1;
+
+        let mut lines = Self::to_lines(lines_coords);
+        if has_floor {
+            height += 2;
+            // The sand rests at most `height - 2` units away from the source on each side, on the
+            // floor which goes one unit further
+            min_x = min(min_x, sand_x - (height - 1));
+            max_x = max(max_x, sand_x + (height - 1));
+
+            let floor = Line {
+                start: Point::new(min_x, min_y + height - 1),
+                end: Point::new(max_x, min_y + height - 1),
+            };
+            lines.push(floor);
+        }
+
+        let mut grid = Grid::with_bounds(
+            Point::new(min_x, min_y),
+            Point::new(max_x, min_y + height - 1),
+            Element::Air,
+        );
+        for coord in Self::to_rock_coords(lines) {
+            grid[coord] = Element::Rock;
+        }
+
+        let sand_source = Point::new(sand_x, min_y);
+        grid[sand_source] = Element::SandSource;
+
+        Self {
+            grid,
+            sand_source,
+            sand_falling: None,
+            moves: [(0, 1).into(), (-1, 1).into(), (1, 1).into()],
+        }
+    }
+
+    fn parse_coords(input: &str) -> Result<Vec<Vec<Point>>, ParseError> {
+        let paths: Vec<Vec<Point>> = lines(input)
+            .map(|line| {
+                line.text
+                    .split("->")
+                    .map(|pair| {
+                        let (x, y) = line.split_once(pair, ",")?;
+                        Ok(Point::new(
+                            line.parse(x, "an x coordinate")?,
+                            line.parse(y, "a y coordinate")?,
+                        ))
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+
+        if paths.is_empty() {
+            return Err(ParseError::end_of_input(input, "a rock path"));
+        }
+
+        Ok(paths)
+    }
+
+    fn to_lines(coords: &[Vec<Point>]) -> Vec<Line> {
+        coords
+            .iter()
+            .flat_map(|rock_structure| {
+                rock_structure.windows(2).map(|line| Line {
+                    start: line[0],
+                    end: line[1],
+                })
+            })
+            .collect()
+    }
+
+    fn to_rock_coords(lines: Vec<Line>) -> HashSet<Point> {
+        lines.iter().flat_map(|line| line.iter()).collect()
+    }
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let char = match self {
+            Self::Air => '.',
+            Self::Rock => '#',
+            Self::Sand => 'o',
+            Self::SandSource => '+',
+        };
+
+        write!(f, "{}", char)
+    }
+}
+
+impl Paint for Element {
+    fn glyph(&self) -> Glyph {
+        match self {
+            Self::Air => Glyph::new('.', Color::Gray),
+            Self::Rock => Glyph::new('#', Color::White),
+            Self::Sand => Glyph::new('o', Color::Yellow),
+            Self::SandSource => Glyph::new('+', Color::Red),
+        }
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use aoc_core::{Generate, Rng};
+
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day14::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(24));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day14::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(93));
+    }
+
+    #[test]
+    fn invalid_coords() {
+        let day = Day14::example();
+
+        let error = day.parse("498,4 -> 498,6\n503,4 -> 502;4").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 15));
+        assert_eq!(error.expected, "`,`");
+
+        let error = day.parse("498,4 -> 498,x").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 14));
+    }
+
+    #[test]
+    fn animation() {
+        let day = Day14::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        let options = animation::Options {
+            fps: 0,
+            steps_per_frame: 10,
+        };
+        let mut canvas = Canvas::with_writer(Vec::new(), options);
+
+        day.animate(&input, Part::One, &mut canvas);
+
+        let output = String::from_utf8(canvas.into_writer()).unwrap();
+        assert!(output.ends_with("Resting sand: 24"));
+    }
+
+    #[test]
+    fn render() {
+        let day = Day14::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+
+        let image = day.render(&input, Part::Two);
+        assert_eq!((image.width(), image.height()), (23, 12));
+        // The sand covers the source
+        assert_eq!(image.get(500 - 489, 0), Rgb(230, 190, 90));
+        assert_eq!(image.get(0, 11), Rgb::gray(140));
+    }
+
+    #[test]
+    fn rocks_beside_the_source() {
+        let day = Day14::example();
+
+        // The rocks of `aoc generate 14 --seed 9 --size 3` are all on the right of the source
+        let input = day.generate(&mut Rng::new(9), 3);
+        let paths = day.parse(&input).unwrap();
+        assert_eq!(day.part_one(&paths), Answer::Number(0));
+
+        // The sand piles up on the floor, further right than the rocks go
+        let paths = day.parse("440,5 -> 450,5").unwrap();
+        assert_eq!(day.part_one(&paths), Answer::Number(0));
+        assert_eq!(day.part_two(&paths), Answer::Number(49));
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day14, 0..20, 30) {
+            panic!("{error}");
+        }
+    }
+}
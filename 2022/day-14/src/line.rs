@@ -1,15 +1,17 @@
-use crate::{coord::Coord, point::Point};
+use geometry::Point;
 
+/// Horizontal, vertical or diagonal segment of a rock path
 pub struct Line {
-    pub start: Coord,
-    pub end: Coord,
+    pub start: Point,
+    pub end: Point,
 }
 
+/// Points of a line, from its start to its end
 pub struct LineIter {
-    start: Coord,
-    end: Coord,
+    start: Point,
+    end: Point,
     dir: Point,
-    cur: Option<Coord>,
+    cur: Option<Point>,
 }
 
 impl Line {
@@ -24,19 +26,19 @@ impl LineIter {
             start: line.start,
             end: line.end,
             cur: None,
-            dir: line.end.diff(line.start).dir(),
+            dir: (line.end - line.start).signum(),
         }
     }
 }
 
 impl Iterator for LineIter {
-    type Item = Coord;
+    type Item = Point;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.cur = match self.cur {
             None => Some(self.start),
             Some(cur) if cur == self.end => return None,
-            Some(cur) => Some(cur.add_signed_checked(self.dir).expect("overflow occurred")),
+            Some(cur) => Some(cur + self.dir),
         };
 
         self.cur
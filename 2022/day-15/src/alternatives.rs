@@ -0,0 +1,101 @@
+use aoc_core::{
+    differential::{Alternatives, Solver},
+    Part,
+};
+use geometry::Point;
+
+use crate::{Day15, Map};
+
+impl Alternatives for Day15 {
+    fn alternatives() -> Vec<Solver<Self>> {
+        vec![
+            Solver {
+                name: "position by position",
+                part: Part::One,
+                solve: |day, map| count_positions(map, day.row).into(),
+            },
+            Solver {
+                name: "line intersections",
+                part: Part::Two,
+                solve: |day, map| {
+                    let beacon = intersect_borders(map, day.search_max)
+                        .expect("should find distress beacon");
+
+                    (4_000_000 * beacon.x as i64 + beacon.y as i64).into()
+                },
+            },
+        ]
+    }
+}
+
+/// Tests every position of the row reached by a sensor
+fn count_positions(map: &Map, y: i32) -> usize {
+    let min = map
+        .circles
+        .iter()
+        .map(|c| c.center.x - c.radius as i32)
+        .min();
+    let max = map
+        .circles
+        .iter()
+        .map(|c| c.center.x + c.radius as i32)
+        .max();
+    let (Some(min), Some(max)) = (min, max) else {
+        return 0;
+    };
+
+    (min..=max)
+        .map(|x| Point::new(x, y))
+        .filter(|&point| map.circles.iter().any(|circle| circle.contains(point)))
+        .filter(|&point| map.sensor_beacons.iter().all(|pair| pair.beacon != point))
+        .count()
+}
+
+/// The distress beacon is right outside the borders of sensor areas. Borders are on lines where
+/// `x + y` or `x - y` is constant, so the beacon is at the crossing of two such lines.
+fn intersect_borders(map: &Map, max: Point) -> Option<Point> {
+    let mut sums = Vec::new();
+    let mut differences = Vec::new();
+    for circle in &map.circles {
+        let (center, outside) = (circle.center, circle.radius as i32 + 1);
+        sums.extend([center.x + center.y - outside, center.x + center.y + outside]);
+        differences.extend([center.x - center.y - outside, center.x - center.y + outside]);
+    }
+
+    sums.iter()
+        .flat_map(|&sum| differences.iter().map(move |&difference| (sum, difference)))
+        .filter(|(sum, difference)| (sum + difference) % 2 == 0)
+        .map(|(sum, difference)| Point::new((sum + difference) / 2, (sum - difference) / 2))
+        .filter(|point| (0..=max.x).contains(&point.x) && (0..=max.y).contains(&point.y))
+        .find(|&point| map.circles.iter().all(|circle| !circle.contains(point)))
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use aoc_core::{differential::compare, Solution};
+
+    use super::*;
+
+    #[test]
+    fn same_answers() {
+        let day = Day15::example();
+
+        if let Err(disagreement) = compare(&day, 0..100, 12) {
+            panic!("{disagreement}");
+        }
+    }
+
+    #[test]
+    fn example() {
+        let day = Day15::example();
+        let map = day.parse(include_str!("../input.test.txt")).unwrap();
+
+        assert_eq!(count_positions(&map, 10), 26);
+        assert_eq!(
+            intersect_borders(&map, day.search_max),
+            Some(Point::new(14, 11))
+        );
+    }
+}
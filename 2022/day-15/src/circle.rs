@@ -1,6 +1,7 @@
-use crate::point::Point;
+use geometry::Point;
 
-#[derive(Clone, Copy)]
+/// Points within a Manhattan distance of `radius` from `center`, a diamond
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Circle {
     pub center: Point,
     pub radius: u32,
@@ -0,0 +1,89 @@
+use aoc_core::{Generate, Rng};
+use geometry::Point;
+
+use crate::Day15;
+
+impl Generate for Day15 {
+    /// Sensors
+    const SIZE: usize = 30;
+
+    /// Sensors which cover the search area of the distress beacon but a single position: four
+    /// sensors far in the corners leave a diamond-shaped hole around it, the others are inside the
+    /// area and do not reach it
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let max = self.search_max;
+
+        // The beacon is not in the first column, where the coverage would not have a gap
+        let hidden = Point::new(
+            rng.range(1..=max.x as i64 - 1) as i32,
+            rng.range(0..=max.y as i64) as i32,
+        );
+
+        let far = max.x.max(max.y) + 1;
+        let mut sensors = Vec::new();
+        for (dx, dy) in [(-1, -1), (-1, 1), (1, -1), (1, 1)] {
+            let sensor = hidden + Point::new(dx * far, dy * far);
+            let beacon = sensor + Point::new(dx * (2 * far - 1), 0);
+            sensors.push((sensor, beacon));
+        }
+
+        while sensors.len() < size {
+            let sensor = Point::new(
+                rng.range(0..=max.x as i64) as i32,
+                rng.range(0..=max.y as i64) as i32,
+            );
+            let Some(reach) = sensor.manhattan_dist(hidden).checked_sub(1) else {
+                continue;
+            };
+
+            let distance = rng.range(1..=reach.max(1) as i64) as i32;
+            let dx = rng.range(-distance as i64..=distance as i64) as i32;
+            let dy = (distance - dx.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+            let beacon = sensor + Point::new(dx, dy);
+
+            if beacon != hidden && sensor.manhattan_dist(beacon) <= reach {
+                sensors.push((sensor, beacon));
+            }
+        }
+
+        rng.shuffle(&mut sensors);
+        let lines: Vec<String> = sensors
+            .into_iter()
+            .map(|(sensor, beacon)| {
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                    sensor.x, sensor.y, beacon.x, beacon.y
+                )
+            })
+            .collect();
+
+        lines.join("\n")
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use aoc_core::{Answer, Solution};
+
+    use super::*;
+
+    #[test]
+    fn single_uncovered_position() {
+        let day = Day15::example();
+
+        for seed in 0..20 {
+            let map = day.parse(&day.generate(&mut Rng::new(seed), 10)).unwrap();
+            let Answer::Number(frequency) = day.part_two(&map) else {
+                panic!("part two should find the distress beacon");
+            };
+
+            let beacon = Point::new(
+                (frequency / 4_000_000) as i32,
+                (frequency % 4_000_000) as i32,
+            );
+            assert!(map.circles.iter().all(|circle| !circle.contains(beacon)));
+        }
+    }
+}
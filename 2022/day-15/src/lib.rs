@@ -0,0 +1,265 @@
+//! Day 15 of 2022: Beacon Exclusion Zone.
+//!
+//! The input is the list of the sensors, with the closest beacon to each of them.
+//!
+//! ```
+//! use aoc_2022_day_15::{Map, SensorBeacon};
+//! use geometry::Point;
+//!
+//! let map = Map::new(vec![SensorBeacon {
+//!     sensor: Point::new(8, 7),
+//!     beacon: Point::new(2, 10),
+//! }]);
+//! assert_eq!(map.non_beacon_places(10), 12);
+//! assert_eq!(map.sensors_coverage(10).ranges(), [2..=14]);
+//! ```
+
+mod alternatives;
+pub mod circle;
+mod generate;
+
+use aoc_core::{
+    parse::{lines, Line},
+    Answer, ParseError, Print, Solution,
+};
+use geometry::Point;
+use intervals::IntervalSet;
+use std::{collections::HashSet, ops::RangeInclusive};
+
+use crate::circle::Circle;
+
+/// Solution of the day, with the sensors as input
+pub struct Day15 {
+    /// Row in which the positions where a beacon cannot be are counted
+    row: i32,
+    /// Maximum coordinates of the distress beacon
+    search_max: Point,
+}
+
+impl Default for Day15 {
+    fn default() -> Self {
+        Self {
+            row: 2_000_000,
+            search_max: (4_000_000, 4_000_000).into(),
+        }
+    }
+}
+
+impl Solution for Day15 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let sensor_beacons = lines(input)
+            .map(|line| SensorBeacon::parse(&line))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Map::new(sensor_beacons))
+    }
+
+    fn part_one(&self, map: &Self::Input) -> Answer {
+        map.non_beacon_places(self.row).into()
+    }
+
+    fn part_two(&self, map: &Self::Input) -> Answer {
+        let max = self.search_max;
+        let distress_beacon = map
+            .find_distress_beacon(0..=max.x, 0..=max.y)
+            .expect("should find distress beacon");
+
+        let distress_signal: isize =
+            (4_000_000 * distress_beacon.x as isize) + distress_beacon.y as isize;
+
+        distress_signal.into()
+    }
+
+    fn example() -> Self {
+        Self {
+            row: 10,
+            search_max: (20, 20).into(),
+        }
+    }
+}
+
+impl Print for Day15 {
+    fn print(&self, map: &Self::Input) -> String {
+        let lines: Vec<String> = map
+            .sensor_beacons
+            .iter()
+            .map(|SensorBeacon { sensor, beacon }| {
+                format!(
+                    "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+                    sensor.x, sensor.y, beacon.x, beacon.y
+                )
+            })
+            .collect();
+
+        lines.join("\n")
+    }
+}
+
+/// Sensors, with the area in which each of them knows there is no other beacon than its closest
+#[derive(Debug, PartialEq, Eq)]
+pub struct Map {
+    circles: Vec<Circle>,
+    sensor_beacons: Vec<SensorBeacon>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct SensorBeacon {
+    pub sensor: Point,
+    /// Closest beacon to the sensor
+    pub beacon: Point,
+}
+
+impl Map {
+    pub fn new(sensor_beacons: Vec<SensorBeacon>) -> Self {
+        let circles: Vec<_> = sensor_beacons.iter().map(|pair| pair.circle()).collect();
+
+        Self {
+            circles,
+            sensor_beacons,
+        }
+    }
+
+    /// Positions of row `y` within reach of a sensor
+    pub fn sensors_coverage(&self, y: i32) -> IntervalSet<i32> {
+        self.circles
+            .iter()
+            .filter_map(|circle| {
+                let center = circle.center;
+                let dy = center.y.abs_diff(y);
+
+                // Check if circle intersect y line
+                if dy > circle.radius {
+                    return None;
+                }
+
+                let dx = circle.radius - dy;
+                Some(center.x - dx as i32..=center.x + dx as i32)
+            })
+            .collect()
+    }
+
+    /// Number of positions of row `y` which cannot contain a beacon
+    pub fn non_beacon_places(&self, y: i32) -> usize {
+        let coverage = self.sensors_coverage(y);
+        let beacons: HashSet<_> = self
+            .sensor_beacons
+            .iter()
+            .map(|pair| pair.beacon)
+            .filter(|beacon| beacon.y == y && coverage.contains(beacon.x))
+            .collect();
+
+        coverage.len() as usize - beacons.len()
+    }
+
+    /// First position within the ranges which no sensor reaches
+    pub fn find_distress_beacon(
+        &self,
+        x_range: RangeInclusive<i32>,
+        y_range: RangeInclusive<i32>,
+    ) -> Option<Point> {
+        y_range.into_iter().find_map(|y| {
+            let uncovered = self.sensors_coverage(y).complement(x_range.clone());
+
+            uncovered.ranges().first().map(|range| Point {
+                x: *range.start(),
+                y,
+            })
+        })
+    }
+}
+
+impl SensorBeacon {
+    fn parse(line: &Line) -> Result<SensorBeacon, ParseError> {
+        let rest = line.strip_prefix(line.text.trim(), "Sensor at x=")?;
+        let (sensor_x, rest) = line.split_once(rest, ", y=")?;
+        let (sensor_y, rest) = line.split_once(rest, ": closest beacon is at x=")?;
+        let (beacon_x, beacon_y) = line.split_once(rest, ", y=")?;
+
+        let sensor = Point {
+            x: line.parse(sensor_x, "the sensor x")?,
+            y: line.parse(sensor_y, "the sensor y")?,
+        };
+
+        let beacon = Point {
+            x: line.parse(beacon_x, "the beacon x")?,
+            y: line.parse(beacon_y, "the beacon y")?,
+        };
+
+        Ok(SensorBeacon { sensor, beacon })
+    }
+
+    /// Area of the sensor
+    pub fn circle(&self) -> Circle {
+        Circle {
+            center: self.sensor,
+            radius: self.sensor.manhattan_dist(self.beacon),
+        }
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day15::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(26));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day15::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(56000011));
+    }
+
+    #[test]
+    fn sensor_coverage() {
+        let sensor_beacons = vec![
+            SensorBeacon {
+                sensor: (10, 10).into(),
+                beacon: (10, 8).into(),
+            },
+            SensorBeacon {
+                sensor: (0, 0).into(),
+                beacon: (0, 10).into(),
+            },
+        ];
+
+        let map = Map::new(sensor_beacons);
+        let coverage = map.sensors_coverage(10);
+
+        assert_eq!(coverage.ranges(), [0..=0, 8..=12]);
+        assert_eq!(map.non_beacon_places(10), 5);
+        assert_eq!(map.non_beacon_places(9), 6);
+    }
+
+    #[test]
+    fn invalid_sensor() {
+        let day = Day15::example();
+
+        let error = day
+            .parse("Sensor at x=2, y=18: closest beacon is at x=-2, y=1a")
+            .expect_err("input should be invalid");
+        assert_eq!((error.line, error.column), (1, 51));
+        assert_eq!(error.expected, "the beacon y");
+
+        let error = day
+            .parse("Sensor at x=2, y=18: beacon at x=-2, y=15")
+            .expect_err("input should be invalid");
+        assert_eq!(error.expected, "`: closest beacon is at x=`");
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day15::example(), 0..20, 20) {
+            panic!("{error}");
+        }
+    }
+}
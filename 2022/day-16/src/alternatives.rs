@@ -0,0 +1,91 @@
+use std::collections::HashMap;
+
+use aoc_core::{
+    differential::{Alternatives, Solver},
+    Part,
+};
+
+use crate::{Day16, Volcano};
+
+impl Alternatives for Day16 {
+    fn alternatives() -> Vec<Solver<Self>> {
+        vec![
+            Solver {
+                name: "best pressure per set of valves",
+                part: Part::One,
+                solve: |_, volcano| {
+                    let best = best_per_set(volcano, 30);
+                    best.values().copied().max().unwrap_or(0).into()
+                },
+            },
+            Solver {
+                name: "best pressure per set of valves",
+                part: Part::Two,
+                solve: |_, volcano| {
+                    let best: Vec<_> = best_per_set(volcano, 26).into_iter().collect();
+
+                    // The elephant and I open disjoint sets of valves
+                    let pairs = best
+                        .iter()
+                        .flat_map(|mine| best.iter().map(move |its| (mine, its)));
+                    pairs
+                        .filter(|((mine, _), (its, _))| mine & its == 0)
+                        .map(|((_, mine), (_, its))| mine + its)
+                        .max()
+                        .unwrap_or(0)
+                        .into()
+                },
+            },
+        ]
+    }
+}
+
+/// Most pressure released in `minutes` by opening each set of valves (as a bit mask of their
+/// indexes), for all the sets which can be opened in time
+fn best_per_set(volcano: &Volcano, minutes: u32) -> HashMap<u64, u32> {
+    let mut best = HashMap::new();
+    let mut stack = vec![(volcano.start_index, minutes, 0_u64, 0)];
+
+    while let Some((valve, minutes_left, opened, pressure)) = stack.pop() {
+        let entry = best.entry(opened).or_insert(0);
+        *entry = (*entry).max(pressure);
+
+        for &next in &volcano.pressurized_indexes {
+            let Some(minutes_left) =
+                minutes_left.checked_sub(volcano.valves[valve].distances[next] + 1)
+            else {
+                continue;
+            };
+
+            if opened & (1 << next) == 0 {
+                let released = volcano.valves[next].flow_rate * minutes_left;
+                stack.push((next, minutes_left, opened | 1 << next, pressure + released));
+            }
+        }
+    }
+
+    best
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use aoc_core::{differential::compare, Solution};
+
+    use super::*;
+
+    #[test]
+    fn same_answers() {
+        if let Err(disagreement) = compare(&Day16, 0..20, 24) {
+            panic!("{disagreement}");
+        }
+    }
+
+    #[test]
+    fn example() {
+        let volcano = Day16.parse(include_str!("../input.test.txt")).unwrap();
+
+        assert_eq!(best_per_set(&volcano, 30).values().max(), Some(&1651));
+    }
+}
@@ -1,4 +1,5 @@
-#[derive(Debug, Copy, Clone)]
+/// Set of indexes below 64, stored in the bits of a `u64`
+#[derive(Debug, Copy, Clone, Default)]
 pub struct BitSet {
     set: u64,
 }
@@ -43,27 +44,27 @@ mod test {
     fn bit_set() {
         let mut set = BitSet::new();
 
-        assert_eq!(set.contains(0), false);
-        assert_eq!(set.contains(63), false);
-        assert_eq!(set.contains(30), false);
+        assert!(!set.contains(0));
+        assert!(!set.contains(63));
+        assert!(!set.contains(30));
 
         set.set(0);
         set.set(63);
-        assert_eq!(set.contains(0), true);
-        assert_eq!(set.contains(63), true);
-        assert_eq!(set.contains(30), false);
+        assert!(set.contains(0));
+        assert!(set.contains(63));
+        assert!(!set.contains(30));
 
         set.set(0);
         set.set(63);
-        assert_eq!(set.contains(0), true);
-        assert_eq!(set.contains(63), true);
-        assert_eq!(set.contains(30), false);
+        assert!(set.contains(0));
+        assert!(set.contains(63));
+        assert!(!set.contains(30));
 
         set.remove(0);
         set.remove(63);
         set.remove(30);
-        assert_eq!(set.contains(0), false);
-        assert_eq!(set.contains(63), false);
-        assert_eq!(set.contains(30), false);
+        assert!(!set.contains(0));
+        assert!(!set.contains(63));
+        assert!(!set.contains(30));
     }
 }
@@ -0,0 +1,85 @@
+use std::collections::BTreeSet;
+
+use aoc_core::{Generate, Rng};
+
+use crate::Day16;
+
+/// Valves with a flow rate, which the search tries all orders of
+const MAX_PRESSURIZED: usize = 15;
+
+impl Generate for Day16 {
+    /// Valves, at most 64
+    const SIZE: usize = 60;
+
+    /// Valves laid out like in the puzzle inputs: `AA` and the valves with a flow rate are linked
+    /// by corridors of stuck valves
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let count = size.clamp(2, 64);
+        let pressurized = (count / 4).clamp(1, MAX_PRESSURIZED);
+
+        let mut names = BTreeSet::from(["AA".to_owned()]);
+        while names.len() < count {
+            let letter = |rng: &mut Rng| (b'A' + rng.index(26) as u8) as char;
+            names.insert(format!("{}{}", letter(rng), letter(rng)));
+        }
+        let mut names: Vec<String> = names.into_iter().skip(1).collect();
+        rng.shuffle(&mut names);
+        names.insert(0, "AA".to_owned());
+
+        // `AA` and the pressurized valves come first, connected by a random tree and a few loops
+        let junctions = pressurized + 1;
+        let mut links: Vec<(usize, usize)> = (1..junctions).map(|j| (rng.index(j), j)).collect();
+        for _ in 0..junctions / 2 {
+            let (a, b) = (rng.index(junctions), rng.index(junctions));
+            if a != b {
+                links.push((a, b));
+            }
+        }
+
+        // The stuck valves lengthen the links
+        let mut corridors = vec![Vec::new(); links.len()];
+        for valve in junctions..count {
+            corridors[rng.index(links.len())].push(valve);
+        }
+
+        let mut tunnels: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); count];
+        for (&(a, b), corridor) in links.iter().zip(&corridors) {
+            let path: Vec<usize> = [a]
+                .into_iter()
+                .chain(corridor.iter().copied())
+                .chain([b])
+                .collect();
+            for pair in path.windows(2) {
+                tunnels[pair[0]].insert(pair[1]);
+                tunnels[pair[1]].insert(pair[0]);
+            }
+        }
+
+        let mut lines: Vec<String> = (0..count)
+            .map(|valve| {
+                let flow_rate = if (1..junctions).contains(&valve) {
+                    rng.range(3..=25)
+                } else {
+                    0
+                };
+
+                let others: Vec<&str> = tunnels[valve]
+                    .iter()
+                    .map(|&other| names[other].as_str())
+                    .collect();
+                let tunnels = match others[..] {
+                    [other] => format!("tunnel leads to valve {other}"),
+                    _ => format!("tunnels lead to valves {}", others.join(", ")),
+                };
+
+                format!(
+                    "Valve {} has flow rate={flow_rate}; {tunnels}",
+                    names[valve]
+                )
+            })
+            .collect();
+
+        rng.shuffle(&mut lines);
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,302 @@
+//! Day 16 of 2022: Proboscidea Volcanium.
+//!
+//! The input is the list of the valves, with their flow rate and the tunnels between them.
+//!
+//! ```
+//! use aoc_2022_day_16::Volcano;
+//!
+//! // AA - BB - CC, the valve BB releasing 13 units of pressure a minute and CC 2
+//! let valves = vec![("AA".to_owned(), 0), ("BB".to_owned(), 13), ("CC".to_owned(), 2)];
+//! let volcano = Volcano::new(valves, &[vec![1], vec![0, 2], vec![1]], 0);
+//!
+//! assert_eq!(volcano.max_pressure(30, 0), 28 * 13 + 26 * 2);
+//! ```
+
+mod alternatives;
+pub mod bitset;
+mod generate;
+
+use std::collections::HashMap;
+
+use aoc_core::{parse::lines, Answer, ParseError, Print, Solution};
+
+use crate::bitset::BitSet;
+
+/// Solution of the day, with the valves as input
+#[derive(Default)]
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Volcano;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Volcano::parse(input)
+    }
+
+    fn part_one(&self, volcano: &Self::Input) -> Answer {
+        volcano.max_pressure(30, 0).into()
+    }
+
+    fn part_two(&self, volcano: &Self::Input) -> Answer {
+        volcano.max_pressure(26, 26).into()
+    }
+}
+
+impl Print for Day16 {
+    /// Prints the tunnels of each valve in the order of the valves, which may differ from the
+    /// parsed one
+    fn print(&self, volcano: &Self::Input) -> String {
+        let lines: Vec<String> = volcano
+            .valves
+            .iter()
+            .map(|valve| {
+                let others: Vec<&str> = volcano
+                    .valves
+                    .iter()
+                    .zip(&valve.distances)
+                    .filter(|(_, distance)| **distance == 1)
+                    .map(|(other, _)| other.name.as_str())
+                    .collect();
+                let tunnels = match others[..] {
+                    [other] => format!("tunnel leads to valve {other}"),
+                    _ => format!("tunnels lead to valves {}", others.join(", ")),
+                };
+
+                format!(
+                    "Valve {} has flow rate={}; {tunnels}",
+                    valve.name, valve.flow_rate
+                )
+            })
+            .collect();
+
+        lines.join("\n")
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Valve {
+    flow_rate: u32,
+    distances: Vec<u32>,
+    name: String,
+}
+
+/// Valves of the volcano, with the distances between each pair of them
+#[derive(Debug, PartialEq, Eq)]
+pub struct Volcano {
+    valves: Vec<Valve>,
+    start_index: usize,
+    pressurized_indexes: Vec<usize>,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct State {
+    valve_idx: (usize, usize),
+    visited: BitSet,
+    minutes_left: (u32, u32),
+}
+
+impl Volcano {
+    /// Volcano whose valve `i` has the name and flow rate of `valves[i]` and tunnels to the valves
+    /// of `tunnels[i]`, the valves being opened from the valve `start`
+    pub fn new(valves: Vec<(String, u32)>, tunnels: &[Vec<usize>], start: usize) -> Self {
+        let valves: Vec<_> = valves
+            .into_iter()
+            .map(|(name, flow_rate)| Valve {
+                flow_rate,
+                distances: vec![],
+                name,
+            })
+            .collect();
+
+        let mut volcano = Volcano {
+            start_index: start,
+            pressurized_indexes: valves
+                .iter()
+                .enumerate()
+                .filter(|(_, valve)| valve.flow_rate > 0)
+                .map(|(i, _)| i)
+                .collect(),
+            valves,
+        };
+        volcano.calc_distances(tunnels);
+
+        volcano
+    }
+
+    /// Most pressure released by opening valves for `minutes`, with the help of an elephant
+    /// opening valves for `elephant_minutes` (0 to open them alone)
+    pub fn max_pressure(&self, minutes: u32, elephant_minutes: u32) -> u32 {
+        self.max_pressure_from(State {
+            minutes_left: (minutes, elephant_minutes),
+            valve_idx: (self.start_index, self.start_index),
+            visited: BitSet::new(),
+        })
+    }
+
+    fn max_pressure_from(&self, state: State) -> u32 {
+        let (my_minutes_left, elefant_minutes_left) = state.minutes_left;
+        let (my_valve, elefant_valve) = state.valve_idx;
+        let my_turn = my_minutes_left >= elefant_minutes_left;
+
+        let valve_idx = if my_turn { my_valve } else { elefant_valve };
+        let minutes_left = my_minutes_left.max(elefant_minutes_left);
+
+        let cur_valve = self.valves.get(valve_idx).unwrap();
+
+        // Stop opening valves, leaving the remaining ones to the other if it still has time
+        let mut max_pressure = if my_minutes_left.min(elefant_minutes_left) > 0 {
+            self.max_pressure_from(State {
+                minutes_left: if my_turn {
+                    (0, elefant_minutes_left)
+                } else {
+                    (my_minutes_left, 0)
+                },
+                ..state
+            })
+        } else {
+            0
+        };
+
+        // Try to visite all pressurized valves which were not yet visited
+        for next_valve_idx in self
+            .pressurized_indexes
+            .iter()
+            .filter(|idx| !state.visited.contains(**idx))
+            .copied()
+        {
+            let minutes_spent = cur_valve.distances[next_valve_idx] + 1;
+
+            // Go to next valve only if there is minutes left
+            let Some(minutes_left) = minutes_left.checked_sub(minutes_spent) else {
+                continue;
+            };
+
+            let flow_rate = self.valves[next_valve_idx].flow_rate;
+            let pressure_gain = flow_rate * minutes_left;
+
+            let mut visited = state.visited;
+            visited.set(next_valve_idx);
+            let new_state = State {
+                visited,
+                valve_idx: if my_turn {
+                    (next_valve_idx, elefant_valve)
+                } else {
+                    (my_valve, next_valve_idx)
+                },
+                minutes_left: if my_turn {
+                    (minutes_left, elefant_minutes_left)
+                } else {
+                    (my_minutes_left, minutes_left)
+                },
+            };
+
+            // Calculate how much pressure we are gonna get taking this branch
+            max_pressure = max_pressure.max(pressure_gain + self.max_pressure_from(new_state));
+        }
+
+        max_pressure
+    }
+
+    fn parse(input: &str) -> Result<Volcano, ParseError> {
+        let lines: Vec<_> = lines(input).collect();
+
+        // Create valves with flow_rate and zero costs, in the order of the input
+        let valves: Vec<_> = lines
+            .iter()
+            .map(|line| {
+                let rest = line.strip_prefix(line.text, "Valve ")?;
+                let (valve_name, rest) = line.split_once(rest, " has flow rate=")?;
+
+                let (flow_rate, rest) = line.split_once(rest, "; ")?;
+                let neighboors: Vec<&str> = rest
+                    .split_whitespace()
+                    .skip(4)
+                    .map(|s| s.trim_end_matches(","))
+                    .collect();
+                if neighboors.is_empty() {
+                    return Err(line.error_at_end("the tunnels leading to other valves"));
+                }
+
+                Ok((
+                    valve_name,
+                    (
+                        line.parse::<u32>(flow_rate, "a flow rate")?,
+                        (line, neighboors),
+                    ),
+                ))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        // Get valves indexes
+        let name_to_index: HashMap<String, usize> = valves
+            .iter()
+            .enumerate()
+            .map(|(index, (name, _))| (name.to_string(), index))
+            .collect();
+
+        let start_index = *name_to_index
+            .get("AA")
+            .ok_or_else(|| ParseError::end_of_input(input, "a valve named AA"))?;
+        let neighboors: Vec<Vec<usize>> = valves
+            .iter()
+            .map(|(_, (_, (line, neighboors)))| {
+                neighboors
+                    .iter()
+                    .map(|name| {
+                        name_to_index
+                            .get(*name)
+                            .copied()
+                            .ok_or_else(|| line.error(name, "the name of a valve"))
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        let valves = valves
+            .into_iter()
+            .map(|(name, (flow_rate, _))| (name.to_string(), flow_rate))
+            .collect();
+
+        Ok(Volcano::new(valves, &neighboors, start_index))
+    }
+
+    fn calc_distances(&mut self, neighboors: &[Vec<usize>]) {
+        let indexes: Vec<usize> = (0..self.valves.len()).collect();
+        let distances = search::all_pairs(&indexes, |&i| neighboors[i].iter().map(|&n| (n, 1)));
+
+        for (valve, distances) in self.valves.iter_mut().zip(distances) {
+            valve.distances = distances
+                .into_iter()
+                .map(|distance| distance.unwrap_or(u32::MAX))
+                .collect();
+        }
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day16::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(1651));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day16::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(1707));
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day16, 0..20, 20) {
+            panic!("{error}");
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day17;
+
+impl Generate for Day17 {
+    /// Jets of hot gas
+    const SIZE: usize = 10091;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (0..size.max(1)).map(|_| *rng.pick(&['<', '>'])).collect()
+    }
+}
@@ -0,0 +1,509 @@
+//! Day 17 of 2022: Pyroclastic Flow.
+//!
+//! The input is the pattern of the jets pushing the rocks falling in the chamber.
+//!
+//! ```
+//! use aoc_2022_day_17::{tower_height, Direction};
+//!
+//! let jet_pattern: Vec<_> = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>"
+//!     .chars()
+//!     .map(|c| if c == '>' { Direction::Right } else { Direction::Left })
+//!     .collect();
+//!
+//! assert_eq!(tower_height(&jet_pattern, 2022), 3068);
+//! assert_eq!(tower_height(&jet_pattern, 1_000_000_000_000), 1514285714288);
+//! ```
+
+mod generate;
+
+use std::{cmp::max, fmt::Display, io::Write, ops::RangeInclusive};
+
+use animation::{Animate, Canvas, Color, Glyph, Paint};
+use aoc_core::{
+    parse::{lines, Line},
+    Answer, ParseError, Part, Print, Solution,
+};
+use cycle::{Cycle, Detector};
+use geometry::Point;
+use grid::Grid;
+
+/// Solution of the day, with the jet pattern as input
+#[derive(Default)]
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<Direction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let line = lines(input)
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input, "a jet pattern"))?;
+
+        Direction::parse_jet_pattern(&line)
+    }
+
+    fn part_one(&self, jet_pattern: &Self::Input) -> Answer {
+        let rock_kinds = rock_kinds();
+
+        let mut chamber = Chamber::new(jet_pattern.clone(), 7);
+
+        for rock_kind in rock_kinds.iter().cycle().take(2022) {
+            chamber.drop_rock(rock_kind);
+        }
+
+        chamber.height.into()
+    }
+
+    /// It is not feasible to simulate the fall of 1_000_000_000_000 rocks, but the top of the
+    /// chamber ends up repeating itself: the height is extrapolated from the cycle of the rocks
+    fn part_two(&self, jet_pattern: &Self::Input) -> Answer {
+        tower_height(jet_pattern, 1_000_000_000_000).into()
+    }
+}
+
+impl Print for Day17 {
+    fn print(&self, jet_pattern: &Self::Input) -> String {
+        jet_pattern
+            .iter()
+            .map(|direction| match direction {
+                Direction::Right => '>',
+                Direction::Left => '<',
+            })
+            .collect()
+    }
+}
+
+impl Animate for Day17 {
+    /// Part two drops the rocks until their cycle is found
+    fn animate<W: Write>(&self, jet_pattern: &Self::Input, part: Part, canvas: &mut Canvas<W>) {
+        const VIEW_HEIGHT: i32 = 30;
+
+        let rocks = match part {
+            Part::One => 2022,
+            Part::Two => find_cycle(jet_pattern).0.end(),
+        };
+        let rock_kinds = rock_kinds();
+        let mut rock_kinds = rock_kinds.iter().cycle().take(rocks);
+
+        let chamber = Chamber::new(jet_pattern.clone(), 7);
+        let mut state: (Chamber, Option<Rock>) = (chamber, None);
+
+        canvas.set_y_up(true);
+        canvas.run(
+            &mut state,
+            |(chamber, falling)| {
+                let rock = match falling.take() {
+                    Some(rock) => rock,
+                    None => match rock_kinds.next() {
+                        Some(kind) => chamber.spawn_rock(kind),
+                        None => return false,
+                    },
+                };
+
+                *falling = chamber.fall(rock);
+                true
+            },
+            |canvas, (chamber, falling)| {
+                // Window of the top of the chamber, in which y = 0 is its bottom row
+                let top = chamber.height as i32 + 7;
+                let bottom = max(top - VIEW_HEIGHT + 1, 0);
+
+                let mut frame = Grid::new(
+                    chamber.width as usize,
+                    VIEW_HEIGHT as usize,
+                    Glyph::plain(' '),
+                );
+                for (point, glyph) in frame.iter_mut() {
+                    if let Some(element) = chamber.grid.get(point + Point::new(0, bottom)) {
+                        *glyph = element.glyph();
+                    }
+                }
+                for point in falling.iter().flat_map(Rock::points) {
+                    if let Some(glyph) = frame.get_mut(point - Point::new(0, bottom)) {
+                        *glyph = Glyph::new('@', Color::Yellow);
+                    }
+                }
+
+                let status = format!(
+                    "Rocks: {}, height: {}",
+                    chamber.rocks_dropped, chamber.height
+                );
+                canvas.draw(&frame, status);
+            },
+        );
+    }
+}
+
+/// Height of the tower of rocks after `rocks` rocks have fallen, extrapolated from the cycle of the
+/// rocks
+pub fn tower_height(jet_pattern: &[Direction], rocks: usize) -> u64 {
+    let (cycle, heights) = find_cycle(jet_pattern);
+
+    cycle.extrapolate(&heights, rocks)
+}
+
+/// Drops rocks until the chamber is in the same state as after a previous rock, returning their
+/// cycle with the height of the chamber after each rock until its end
+pub fn find_cycle(jet_pattern: &[Direction]) -> (Cycle, Vec<u64>) {
+    let rock_kinds = rock_kinds();
+    // The rocks repeat after a few times the length of the jet pattern
+    let max_rocks = (jet_pattern.len() * 10).max(1000);
+
+    let mut chamber = Chamber::new(jet_pattern.to_vec(), 7);
+    let mut detector = Detector::new();
+    let mut heights = Vec::new();
+
+    for rock_kind in rock_kinds.iter().cycle().take(max_rocks) {
+        heights.push(chamber.height as u64);
+
+        // The next rocks fall the same way from the same rock kind, jet and surface
+        let key = (
+            chamber.rocks_dropped % rock_kinds.len(),
+            chamber.cur_jet_pattern,
+            chamber.surface(),
+        );
+        if let Some(cycle) = detector.record(key) {
+            return (cycle, heights);
+        }
+
+        chamber.drop_rock(rock_kind);
+    }
+
+    panic!("the rocks should repeat after {max_rocks} rocks");
+}
+
+/// Kinds of the rocks, in the order in which they fall
+pub fn rock_kinds() -> Vec<RockKind> {
+    vec![
+        // ####
+        RockKind::new(vec![
+            Point { x: 0, y: 0 },
+            Point { x: 1, y: 0 },
+            Point { x: 2, y: 0 },
+            Point { x: 3, y: 0 },
+        ]),
+        // .#.
+        // ###
+        // .#.
+        RockKind::new(vec![
+            Point { x: 1, y: 0 },
+            Point { x: 0, y: -1 },
+            Point { x: 1, y: -1 },
+            Point { x: 2, y: -1 },
+            Point { x: 1, y: -2 },
+        ]),
+        // ..#
+        // ..#
+        // ###
+        RockKind::new(vec![
+            Point { x: 2, y: 0 },
+            Point { x: 2, y: -1 },
+            Point { x: 0, y: -2 },
+            Point { x: 1, y: -2 },
+            Point { x: 2, y: -2 },
+        ]),
+        // #
+        // #
+        // #
+        // #
+        RockKind::new(vec![
+            Point { x: 0, y: 0 },
+            Point { x: 0, y: -1 },
+            Point { x: 0, y: -2 },
+            Point { x: 0, y: -3 },
+        ]),
+        // ##
+        // ##
+        RockKind::new(vec![
+            Point { x: 0, y: 0 },
+            Point { x: 0, y: -1 },
+            Point { x: 1, y: 0 },
+            Point { x: 1, y: -1 },
+        ]),
+    ]
+}
+
+/// Shape of a rock, its points going down from its top row
+#[derive(Debug)]
+pub struct RockKind {
+    points: Vec<Point>,
+    y_range: RangeInclusive<i32>,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Rock<'a> {
+    pos: Point,
+    kind: &'a RockKind,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Element {
+    Air,
+    Rock,
+}
+
+/// Direction of a jet
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+    Right,
+    Left,
+}
+
+/// Chamber in which the rocks fall, pushed by the jets
+pub struct Chamber {
+    rocks_dropped: usize,
+    width: u32,
+    height: u32,
+    grid: Grid<Element>,
+    jet_pattern: Vec<Direction>,
+    cur_jet_pattern: usize,
+}
+
+impl Chamber {
+    /// The rocks appear two units away from the left wall, and the widest is 4 units wide
+    pub const MIN_WIDTH: u32 = 6;
+
+    /// Empty chamber, `width` units wide, whose grid grows with the tower of rocks.
+    ///
+    /// Panics if `width` is less than `MIN_WIDTH`, or if `jet_pattern` is empty.
+    pub fn new(jet_pattern: Vec<Direction>, width: u32) -> Chamber {
+        assert!(
+            width >= Self::MIN_WIDTH,
+            "the chamber should be at least {} units wide",
+            Self::MIN_WIDTH
+        );
+        assert!(
+            !jet_pattern.is_empty(),
+            "the jet pattern should not be empty"
+        );
+
+        let mut grid =
+            Grid::with_bounds(Point::ZERO, Point::new(width as i32 - 1, 0), Element::Air);
+
+        // Set rock floor
+        for x in 0..width {
+            *grid.get_mut((x as _, 0).into()).unwrap() = Element::Rock;
+        }
+
+        Chamber {
+            height: 0,
+            cur_jet_pattern: 0,
+            rocks_dropped: 0,
+            width,
+            grid,
+            jet_pattern,
+        }
+    }
+
+    /// Drops a rock until it rests on the floor or on other rocks
+    pub fn drop_rock(&mut self, kind: &RockKind) {
+        let mut rock = self.spawn_rock(kind);
+
+        while let Some(moved) = self.fall(rock) {
+            rock = moved;
+        }
+    }
+
+    /// Height of the tower of rocks
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Places a rock of `kind` above the tower, growing the grid to hold it
+    fn spawn_rock<'a>(&mut self, kind: &'a RockKind) -> Rock<'a> {
+        let rock = Rock {
+            pos: (2, self.height as i32 + 4 - kind.y_range.start()).into(),
+            kind,
+        };
+
+        // Doubles the height of the grid, so that it is copied only a few times
+        let top = rock.pos.y + kind.y_range.end();
+        if top > self.grid.max().y {
+            let rows = top.max(2 * self.grid.height() as i32);
+            self.grid.grow_to_include(Point::new(0, rows), Element::Air);
+        }
+
+        rock
+    }
+
+    /// Pushes `rock` with the next jet and moves it down, or rests it if it cannot fall anymore
+    fn fall<'a>(&mut self, mut rock: Rock<'a>) -> Option<Rock<'a>> {
+        if let Some(moved) = self.apply_jet(rock) {
+            rock = moved;
+        }
+
+        if let Some(moved) = self.try_move_down(rock) {
+            return Some(moved);
+        }
+
+        for rock_point in rock.points() {
+            let grid_element = self
+                .grid
+                .get_mut(rock_point)
+                .expect("rock should be inside the grid");
+            debug_assert!(matches!(grid_element, Element::Air));
+            *grid_element = Element::Rock;
+        }
+
+        self.rocks_dropped += 1;
+        self.height = max((rock.pos.y + rock.kind.y_range.end()) as u32, self.height);
+
+        None
+    }
+
+    fn apply_jet<'a>(&mut self, mut rock: Rock<'a>) -> Option<Rock<'a>> {
+        rock.pos.x += match self.next_jet() {
+            Direction::Right => 1,
+            Direction::Left => -1,
+        };
+
+        if self.is_valid_rock(&rock) {
+            Some(rock)
+        } else {
+            None
+        }
+    }
+
+    fn try_move_down<'a>(&self, mut rock: Rock<'a>) -> Option<Rock<'a>> {
+        rock.pos.y -= 1;
+
+        if self.is_valid_rock(&rock) {
+            Some(rock)
+        } else {
+            None
+        }
+    }
+
+    fn is_valid_rock(&self, &rock: &Rock) -> bool {
+        rock.points()
+            .all(|p| matches!(self.grid.get(p), Some(Element::Air)))
+    }
+
+    /// Air that the falling rocks can reach, pushed by the jets from above the top rock, relative
+    /// to the top. The rocks further down make no difference to how the next rocks fall, except
+    /// in wells deeper than `SURFACE_DEPTH` which are not followed.
+    fn surface(&self) -> Vec<Point> {
+        const SURFACE_DEPTH: i32 = 32;
+
+        let top = Point::new(0, self.height as i32 + 1);
+        let starts = (0..self.width as i32).map(|x| Point::new(x, top.y));
+        let moves = |&point: &Point| {
+            [Point::new(-1, 0), Point::new(1, 0), Point::new(0, -1)]
+                .map(|direction| point + direction)
+                .into_iter()
+                .filter(|&next| next.y > top.y - SURFACE_DEPTH)
+                .filter(|&next| matches!(self.grid.get(next), Some(Element::Air)))
+        };
+
+        let reached = search::bfs(starts, moves, |_| false);
+        let mut surface: Vec<_> = reached.costs().map(|(&point, _)| point - top).collect();
+        surface.sort_unstable();
+
+        surface
+    }
+
+    fn next_jet(&mut self) -> Direction {
+        let direction = self.jet_pattern.get(self.cur_jet_pattern).unwrap();
+
+        self.cur_jet_pattern += 1;
+        self.cur_jet_pattern %= self.jet_pattern.len();
+
+        *direction
+    }
+}
+
+impl RockKind {
+    fn new(body: Vec<Point>) -> Self {
+        let (min, max) = Point::min_max(body.iter().copied()).unwrap_or_default();
+
+        Self {
+            points: body,
+            y_range: min.y..=max.y,
+        }
+    }
+}
+
+impl<'a> Rock<'a> {
+    fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        self.kind.points.iter().map(|p| self.pos + *p)
+    }
+}
+
+impl Direction {
+    fn parse_jet_pattern(line: &Line) -> Result<Vec<Direction>, ParseError> {
+        let pattern = line.text.trim();
+
+        pattern
+            .char_indices()
+            .map(|(i, c)| match c {
+                '>' => Ok(Direction::Right),
+                '<' => Ok(Direction::Left),
+                _ => Err(line.error(&pattern[i..], "`<` or `>`")),
+            })
+            .collect()
+    }
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let char = match self {
+            Element::Rock => '#',
+            Element::Air => '.',
+        };
+
+        write!(f, "{}", char)
+    }
+}
+
+impl Paint for Element {
+    fn glyph(&self) -> Glyph {
+        match self {
+            Element::Rock => Glyph::new('#', Color::White),
+            Element::Air => Glyph::new('.', Color::Gray),
+        }
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day17::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(3068));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day17::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(1514285714288));
+    }
+
+    #[test]
+    fn animation() {
+        let day = Day17::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        let options = animation::Options {
+            fps: 0,
+            steps_per_frame: 100,
+        };
+        let mut canvas = Canvas::with_writer(Vec::new(), options);
+
+        day.animate(&input, Part::One, &mut canvas);
+
+        let output = String::from_utf8(canvas.into_writer()).unwrap();
+        assert!(output.ends_with("Rocks: 2022, height: 3068"));
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day17, 0..20, 100) {
+            panic!("{error}");
+        }
+    }
+}
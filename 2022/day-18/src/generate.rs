@@ -0,0 +1,32 @@
+use std::collections::HashSet;
+
+use aoc_core::{Generate, Rng};
+
+use crate::Day18;
+
+impl Generate for Day18 {
+    /// Cubes
+    const SIZE: usize = 2800;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let count = size.max(1);
+        // Fills a third of a cube, leaving air pockets inside the droplet
+        let side = ((count * 3) as f64).cbrt().ceil() as i64;
+
+        let mut cubes = HashSet::new();
+        let mut lines = Vec::new();
+        while lines.len() < count {
+            let cube = (
+                rng.range(0..=side - 1),
+                rng.range(0..=side - 1),
+                rng.range(0..=side - 1),
+            );
+
+            if cubes.insert(cube) {
+                lines.push(format!("{},{},{}", cube.0, cube.1, cube.2));
+            }
+        }
+
+        lines.join("\n")
+    }
+}
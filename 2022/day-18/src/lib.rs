@@ -0,0 +1,160 @@
+//! Day 18 of 2022: Boiling Boulders.
+//!
+//! The input is the list of the cubes of the lava droplet.
+//!
+//! ```
+//! use aoc_2022_day_18::{grid_from_points, pour_water, surface_area, Element};
+//! use geometry::Point3;
+//!
+//! // Two cubes side by side
+//! let cubes = [Point3::new(1, 1, 1), Point3::new(2, 1, 1)];
+//! let mut grid = grid_from_points(cubes.into_iter());
+//! assert_eq!(surface_area(&grid, Element::Air), 10);
+//!
+//! pour_water(&mut grid);
+//! assert_eq!(surface_area(&grid, Element::Water), 10);
+//! ```
+
+mod generate;
+
+use aoc_core::{
+    parse::{lines, Line},
+    Answer, ParseError, Print, Solution,
+};
+use geometry::Point3;
+use grid::Grid3;
+
+/// Solution of the day, with the cubes in a grid as input
+#[derive(Default)]
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Grid3<Element>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let points: Vec<_> = lines(input)
+            .map(|line| parse_point(&line))
+            .collect::<Result<_, _>>()?;
+
+        if points.is_empty() {
+            return Err(ParseError::end_of_input(input, "a cube"));
+        }
+
+        Ok(grid_from_points(points.into_iter()))
+    }
+
+    fn part_one(&self, grid: &Self::Input) -> Answer {
+        surface_area(grid, Element::Air).into()
+    }
+
+    fn part_two(&self, grid: &Self::Input) -> Answer {
+        let mut grid = grid.clone();
+
+        pour_water(&mut grid);
+
+        surface_area(&grid, Element::Water).into()
+    }
+}
+
+impl Print for Day18 {
+    fn print(&self, grid: &Self::Input) -> String {
+        let lines: Vec<String> = grid
+            .iter()
+            .filter(|(_, element)| matches!(element, Element::Rock))
+            .map(|(point, _)| format!("{},{},{}", point.x, point.y, point.z))
+            .collect();
+
+        lines.join("\n")
+    }
+}
+
+/// Content of a cube of the grid
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Element {
+    Air,
+    Rock,
+    Water,
+}
+
+fn parse_point(line: &Line) -> Result<Point3, ParseError> {
+    let (x, rest) = line.split_once(line.text, ",")?;
+    let (y, z) = line.split_once(rest, ",")?;
+
+    Ok(Point3 {
+        x: line.parse(x, "the x coordinate")?,
+        y: line.parse(y, "the y coordinate")?,
+        z: line.parse(z, "the z coordinate")?,
+    })
+}
+
+/// Grid with rock at `points`, surrounded by air
+pub fn grid_from_points(iter: impl Iterator<Item = Point3> + Clone) -> Grid3<Element> {
+    let (min, max) = Point3::min_max(iter.clone()).expect("empty iterator");
+    let padding = Point3::new(1, 1, 1);
+
+    let mut grid = Grid3::with_bounds(min - padding, max + padding, Element::Air);
+
+    for point in iter {
+        *grid.get_mut_unchecked(point) = Element::Rock;
+    }
+
+    grid
+}
+
+/// Fills with water the air reached from the corner of the grid, which is outside of the droplet
+pub fn pour_water(grid: &mut Grid3<Element>) {
+    let outside = search::bfs(
+        [grid.min()],
+        |point| {
+            point
+                .neighbours6()
+                .filter(|p| matches!(grid.get(*p), Some(Element::Air)))
+        },
+        |_| false,
+    );
+
+    for (point, _) in outside.costs() {
+        *grid.get_mut_unchecked(*point) = Element::Water;
+    }
+}
+
+/// Number of the sides of the rock cubes touching a cube of `exposed_to`
+pub fn surface_area(grid: &Grid3<Element>, exposed_to: Element) -> usize {
+    grid.iter()
+        .filter(|(_, element)| matches!(element, Element::Rock))
+        .map(|(point, _)| {
+            point
+                .neighbours6()
+                .filter_map(|n| grid.get(n))
+                .filter(|&&e| e == exposed_to)
+                .count()
+        })
+        .sum()
+}
+
+// --- TESTS ---
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day18::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(64));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day18::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(58));
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day18, 0..20, 50) {
+            panic!("{error}");
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Times part one against the exhaustive search of `alternatives.rs`, which explores every branch
+//! instead of skipping those which cannot open more geodes: `cargo bench -p aoc-2022-day-19`.
+
+use std::time::{Duration, Instant};
+
+use aoc_2022_day_19::Day19;
+use aoc_core::{differential::Alternatives, Answer, Generate, Part, Rng, Solution};
+
+/// Runs of each solver, of which the median is reported
+const ITERATIONS: usize = 5;
+
+fn main() {
+    let input = Day19.generate(&mut Rng::new(0), 10);
+    let blueprints = Day19
+        .parse(&input)
+        .expect("the generated blueprints should be valid");
+    let exhaustive = Day19::alternatives()
+        .into_iter()
+        .find(|solver| solver.part == Part::One)
+        .expect("part one should have an exhaustive search");
+
+    let (answer, pruned) = median(|| Day19.part_one(&blueprints));
+    let (expected, exhaustive) = median(|| (exhaustive.solve)(&Day19, &blueprints));
+    assert_eq!(answer, expected, "the searches should open the same geodes");
+
+    println!(
+        "{} generated blueprints, 24 minutes (median of {ITERATIONS} runs):",
+        blueprints.len()
+    );
+    println!("  exhaustive search: {exhaustive:?}");
+    println!("  pruned search:     {pruned:?}");
+    println!(
+        "  {:.1} times faster",
+        exhaustive.as_secs_f64() / pruned.as_secs_f64()
+    );
+}
+
+/// Answer of `solve`, with the median of its durations
+fn median(solve: impl Fn() -> Answer) -> (Answer, Duration) {
+    let mut answer = None;
+    let mut durations: Vec<_> = (0..ITERATIONS)
+        .map(|_| {
+            let start = Instant::now();
+            answer = Some(solve());
+            start.elapsed()
+        })
+        .collect();
+    durations.sort_unstable();
+
+    (answer.unwrap(), durations[ITERATIONS / 2])
+}
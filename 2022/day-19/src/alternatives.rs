@@ -0,0 +1,78 @@
+use aoc_core::{
+    differential::{Alternatives, Solver},
+    Part,
+};
+
+use crate::{Blueprint, Day19, Resource, State, Values};
+
+impl Alternatives for Day19 {
+    fn alternatives() -> Vec<Solver<Self>> {
+        vec![Solver {
+            name: "exhaustive search",
+            part: Part::One,
+            solve: |_, blueprints| {
+                blueprints
+                    .iter()
+                    .map(|blueprint| {
+                        blueprint.id as usize * exhaustive_geodes(blueprint, 24) as usize
+                    })
+                    .sum::<usize>()
+                    .into()
+            },
+        }]
+    }
+}
+
+/// Most geodes that can be opened in `minutes`, exploring every branch until the end
+fn exhaustive_geodes(blueprint: &Blueprint, minutes: u8) -> u16 {
+    let initial_state = State {
+        minutes_left: minutes,
+        resources: Values([0, 0, 0, 0]),
+        robots: Values([1, 0, 0, 0]),
+    };
+
+    find_best_state(blueprint, initial_state).resources[Resource::Geode as usize]
+}
+
+fn find_best_state(blueprint: &Blueprint, initial_state: State) -> State {
+    initial_state
+        .branch(blueprint)
+        .map(|branch| find_best_state(blueprint, branch))
+        .max_by_key(|state| state.resources[Resource::Geode as usize])
+        .unwrap_or(initial_state)
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use aoc_core::{differential::compare, Generate, Rng, Solution};
+
+    use super::*;
+
+    #[test]
+    fn same_answers() {
+        if let Err(disagreement) = compare(&Day19, 0..5, 5) {
+            panic!("{disagreement}");
+        }
+    }
+
+    /// The branches skipped by `max_geodes` should never hold more geodes, whatever the blueprint
+    /// and the number of minutes
+    #[test]
+    fn same_geodes() {
+        for seed in 0..10 {
+            let input = Day19.generate(&mut Rng::new(seed), 5);
+
+            for blueprint in Day19.parse(&input).unwrap() {
+                for minutes in [0, 1, 8, 16, 22] {
+                    assert_eq!(
+                        blueprint.max_geodes(minutes),
+                        exhaustive_geodes(&blueprint, minutes),
+                        "{blueprint} in {minutes} minutes"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day19;
+
+impl Generate for Day19 {
+    /// Blueprints
+    const SIZE: usize = 30;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let blueprints: Vec<String> = (1..=size.max(1))
+            .map(|id| {
+                format!(
+                    "Blueprint {id}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                     Each obsidian robot costs {} ore and {} clay. \
+                     Each geode robot costs {} ore and {} obsidian.",
+                    rng.range(2..=4),
+                    rng.range(2..=4),
+                    rng.range(2..=4),
+                    rng.range(5..=20),
+                    rng.range(2..=4),
+                    rng.range(5..=20),
+                )
+            })
+            .collect();
+
+        blueprints.join("\n")
+    }
+}
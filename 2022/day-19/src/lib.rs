@@ -0,0 +1,396 @@
+//! Day 19 of 2022: Not Enough Minerals.
+//!
+//! The input is the list of the blueprints, with the cost of each kind of robot.
+//!
+//! ```
+//! use aoc_2022_day_19::{Blueprint, Day19};
+//! use aoc_core::Solution;
+//!
+//! let blueprints = Day19
+//!     .parse(
+//!         "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. \
+//!          Each obsidian robot costs 3 ore and 14 clay. \
+//!          Each geode robot costs 2 ore and 7 obsidian.",
+//!     )
+//!     .unwrap();
+//!
+//! assert_eq!(blueprints[0].id(), 1);
+//! assert_eq!(blueprints[0].max_geodes(24), 9);
+//!
+//! // The same blueprint, built without its text
+//! let blueprint = Blueprint::new(1, 4, 2, (3, 14), (2, 7));
+//! assert_eq!(blueprint, blueprints[0]);
+//! assert_eq!(blueprint.obsidian_robot_cost(), (3, 14));
+//! ```
+
+mod alternatives;
+mod generate;
+
+use std::{
+    fmt::{self, Display},
+    ops::{Add, AddAssign, Deref, Mul, Sub},
+};
+
+use aoc_core::{
+    parse::{lines, Line},
+    Answer, ParseError, Print, Solution,
+};
+
+/// Solution of the day, with the blueprints as input
+#[derive(Default)]
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        lines(input).map(|line| Blueprint::parse(&line)).collect()
+    }
+
+    fn part_one(&self, blueprints: &Self::Input) -> Answer {
+        blueprints
+            .iter()
+            .map(|blueprint| blueprint.id as usize * blueprint.max_geodes(24) as usize)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part_two(&self, blueprints: &Self::Input) -> Answer {
+        blueprints
+            .iter()
+            .take(3)
+            .map(|blueprint| blueprint.max_geodes(32) as usize)
+            .product::<usize>()
+            .into()
+    }
+}
+
+impl Print for Day19 {
+    fn print(&self, blueprints: &Self::Input) -> String {
+        let lines: Vec<String> = blueprints.iter().map(ToString::to_string).collect();
+
+        lines.join("\n")
+    }
+}
+
+const RESOURCES_TYPES: usize = 4;
+
+#[derive(Copy, Clone)]
+enum Resource {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Values([u16; RESOURCES_TYPES]);
+
+/// Costs of the robots collecting each resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Blueprint {
+    id: u8,
+    costs: [Values; RESOURCES_TYPES],
+    max_costs: Values,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct State {
+    minutes_left: u8,
+    robots: Values,
+    resources: Values,
+}
+
+impl Blueprint {
+    /// Blueprint `id`, with the costs in ore of the ore and clay robots, in ore and clay of the
+    /// obsidian robot, and in ore and obsidian of the geode robot
+    pub fn new(id: u8, ore: u16, clay: u16, obsidian: (u16, u16), geode: (u16, u16)) -> Self {
+        let costs = [
+            Values([ore, 0, 0, 0]),
+            Values([clay, 0, 0, 0]),
+            Values([obsidian.0, obsidian.1, 0, 0]),
+            Values([geode.0, 0, geode.1, 0]),
+        ];
+
+        Blueprint {
+            id,
+            // No more robots collecting a resource are needed than the most it can be spent a
+            // minute
+            max_costs: Values(std::array::from_fn(|i| {
+                costs.iter().map(|c| c[i]).max().unwrap()
+            })),
+            costs,
+        }
+    }
+
+    pub fn id(&self) -> u8 {
+        self.id
+    }
+
+    /// Ore cost of an ore robot
+    pub fn ore_robot_cost(&self) -> u16 {
+        self.costs[Resource::Ore as usize][Resource::Ore as usize]
+    }
+
+    /// Ore cost of a clay robot
+    pub fn clay_robot_cost(&self) -> u16 {
+        self.costs[Resource::Clay as usize][Resource::Ore as usize]
+    }
+
+    /// Ore and clay costs of an obsidian robot
+    pub fn obsidian_robot_cost(&self) -> (u16, u16) {
+        let cost = self.costs[Resource::Obsidian as usize];
+
+        (cost[Resource::Ore as usize], cost[Resource::Clay as usize])
+    }
+
+    /// Ore and obsidian costs of a geode robot
+    pub fn geode_robot_cost(&self) -> (u16, u16) {
+        let cost = self.costs[Resource::Geode as usize];
+
+        (
+            cost[Resource::Ore as usize],
+            cost[Resource::Obsidian as usize],
+        )
+    }
+
+    /// Most geodes that can be opened in `minutes`, starting with one ore robot
+    pub fn max_geodes(&self, minutes: u8) -> u16 {
+        let initial_state = State {
+            minutes_left: minutes,
+            resources: Values([0, 0, 0, 0]),
+            robots: Values([1, 0, 0, 0]),
+        };
+
+        let mut best = 0;
+        self.find_best_state(initial_state, &mut best);
+
+        best
+    }
+
+    /// Raises `best` to the most geodes that can be opened from `state`, skipping the branches
+    /// which cannot open more than `best` even by building a geode robot every minute
+    fn find_best_state(&self, state: State, best: &mut u16) {
+        let geodes = state.geodes_when_idle();
+        *best = (*best).max(geodes);
+
+        let minutes = state.minutes_left as u32;
+        if geodes as u32 + minutes * minutes.saturating_sub(1) / 2 <= *best as u32 {
+            return;
+        }
+
+        for branch in state.branch(self) {
+            self.find_best_state(branch, best);
+        }
+    }
+
+    fn parse(line: &Line) -> Result<Blueprint, ParseError> {
+        let rest = line.strip_prefix(line.text.trim(), "Blueprint ")?;
+        let (id, rest) = line.split_once(rest, ": Each ore robot costs ")?;
+        let (ore_cost, rest) = line.split_once(rest, " ore. Each clay robot costs ")?;
+        let (clay_cost, rest) = line.split_once(rest, " ore. Each obsidian robot costs ")?;
+        let (obsidian_cost_1, rest) = line.split_once(rest, " ore and ")?;
+        let (obsidian_cost_2, rest) = line.split_once(rest, " clay. Each geode robot costs ")?;
+        let (geode_cost_1, rest) = line.split_once(rest, " ore and ")?;
+        let (geode_cost_2, _) = line.split_once(rest, " obsidian.")?;
+
+        Ok(Blueprint::new(
+            line.parse(id, "a blueprint id")?,
+            line.parse(ore_cost, "the ore robot cost")?,
+            line.parse(clay_cost, "the clay robot cost")?,
+            (
+                line.parse(obsidian_cost_1, "the obsidian robot ore cost")?,
+                line.parse(obsidian_cost_2, "the obsidian robot clay cost")?,
+            ),
+            (
+                line.parse(geode_cost_1, "the geode robot ore cost")?,
+                line.parse(geode_cost_2, "the geode robot obsidian cost")?,
+            ),
+        ))
+    }
+}
+
+impl Display for Blueprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let obsidian = self.obsidian_robot_cost();
+        let geode = self.geode_robot_cost();
+
+        write!(
+            f,
+            "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+             Each obsidian robot costs {} ore and {} clay. \
+             Each geode robot costs {} ore and {} obsidian.",
+            self.id,
+            self.ore_robot_cost(),
+            self.clay_robot_cost(),
+            obsidian.0,
+            obsidian.1,
+            geode.0,
+            geode.1
+        )
+    }
+}
+
+impl State {
+    /// Geodes opened at the end if no more robot is built
+    fn geodes_when_idle(&self) -> u16 {
+        let geode = Resource::Geode as usize;
+
+        self.resources[geode] + self.robots[geode] * self.minutes_left as u16
+    }
+
+    fn branch(self, blueprint: &Blueprint) -> impl Iterator<Item = Self> {
+        [
+            self.try_build(Resource::Geode, blueprint),
+            self.try_build(Resource::Obsidian, blueprint),
+            self.try_build(Resource::Clay, blueprint),
+            self.try_build(Resource::Ore, blueprint),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    fn try_build(&self, kind: Resource, blueprint: &Blueprint) -> Option<State> {
+        let kind = kind as usize;
+        let cost = blueprint.costs[kind];
+
+        // Check if it makes sense to build such robot
+        if kind != Resource::Geode as usize && self.robots[kind] >= blueprint.max_costs[kind] {
+            return None;
+        }
+
+        // Check if we have the robots necessary to build what we want
+        // (eg. if we want to build an geode robot we need obsidian and ore robots)
+        if cost
+            .iter()
+            .enumerate()
+            .filter(|(_, cost)| **cost > 0)
+            .any(|(resource, _)| self.robots[resource] == 0)
+        {
+            return None;
+        }
+
+        // We can safelly build the robot (based on: https://github.com/Crazytieguy/advent-of-code/blob/master/2022/src/bin/day19/main.rs)
+        (0..self.minutes_left)
+            .rev()
+            .zip(0..)
+            .find_map(|(minutes_left, minutes_waiting)| {
+                let resources = self.resources + self.robots * minutes_waiting;
+
+                match resources.checked_sub(cost) {
+                    Some(resources_after_build) => {
+                        let mut new_robots = self.robots;
+                        new_robots.0[kind] += 1;
+
+                        Some(State {
+                            minutes_left,
+                            resources: resources_after_build + self.robots,
+                            robots: new_robots,
+                        })
+                    }
+                    None if minutes_left == 0 => Some(State {
+                        minutes_left,
+                        resources: resources + self.robots,
+                        robots: self.robots,
+                    }),
+                    _ => None,
+                }
+            })
+    }
+}
+
+impl Deref for Values {
+    type Target = [u16; RESOURCES_TYPES];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Add for Values {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Values(std::array::from_fn(|i| self[i] + rhs[i]))
+    }
+}
+
+impl AddAssign for Values {
+    fn add_assign(&mut self, rhs: Self) {
+        for i in 0..self.len() {
+            self.0[i] += rhs.0[i];
+        }
+    }
+}
+
+impl Sub for Values {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Values(std::array::from_fn(|i| self[i] - rhs[i]))
+    }
+}
+
+impl Mul<u16> for Values {
+    type Output = Self;
+
+    fn mul(mut self, rhs: u16) -> Self::Output {
+        for i in 0..self.len() {
+            self.0[i] *= rhs;
+        }
+
+        self
+    }
+}
+
+impl Values {
+    fn checked_sub(mut self, rhs: Self) -> Option<Self> {
+        for i in 0..self.len() {
+            self.0[i] = self[i].checked_sub(rhs[i])?;
+        }
+
+        Some(self)
+    }
+}
+
+// --- TESTS ---
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn invalid_blueprint() {
+        let input = include_str!("../input.test.txt").replacen("costs 2 ore.", "costs two ore.", 1);
+
+        let error = Day19::example().parse(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 64));
+        assert_eq!(error.expected, "the clay robot cost");
+
+        let input = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore.";
+        let error = Day19::example().parse(input).unwrap_err();
+        assert_eq!(error.expected, "`ore. Each obsidian robot costs`");
+    }
+
+    #[test]
+    fn test_part_one() {
+        let day = Day19::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(33));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day19::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(56 * 62));
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day19, 0..20, 30) {
+            panic!("{error}");
+        }
+    }
+}
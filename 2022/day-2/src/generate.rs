@@ -0,0 +1,22 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day2;
+
+impl Generate for Day2 {
+    /// Rounds
+    const SIZE: usize = 2500;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let rounds: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}",
+                    rng.pick(&['A', 'B', 'C']),
+                    rng.pick(&['X', 'Y', 'Z'])
+                )
+            })
+            .collect();
+
+        rounds.join("\n")
+    }
+}
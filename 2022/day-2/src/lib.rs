@@ -0,0 +1,234 @@
+//! Day 2 of 2022: Rock Paper Scissors.
+//!
+//! The input is the strategy guide, whose second column is read as the shape to play by part one
+//! and as the result to reach by part two.
+//!
+//! ```
+//! use aoc_2022_day_2::{map_my_match_result, Match, Response, Shape};
+//!
+//! let round = Match::force_result(map_my_match_result(Response::Z), Shape::Rock);
+//! assert_eq!(round.my_shape, Shape::Paper);
+//! assert_eq!(round.get_points(), 8);
+//! ```
+
+mod generate;
+
+use aoc_core::{parse::lines, Answer, ParseError, Print, Solution};
+
+/// Solution of the day, with the rounds of the strategy guide as input
+#[derive(Default)]
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(Shape, Response)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        lines(input)
+            .map(|line| {
+                let (opponent_symbol, my_symbol) = line.split_once(line.text, " ")?;
+
+                Ok((
+                    map_opponent_shape(opponent_symbol)
+                        .ok_or_else(|| line.error(opponent_symbol, "`A`, `B` or `C`"))?,
+                    Response::parse(my_symbol)
+                        .ok_or_else(|| line.error(my_symbol, "`X`, `Y` or `Z`"))?,
+                ))
+            })
+            .collect()
+    }
+
+    fn part_one(&self, input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|&(opponent_shape, response)| Match {
+                opponent_shape,
+                my_shape: map_my_shape(response),
+            })
+            .map(|_match| _match.get_points())
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part_two(&self, input: &Self::Input) -> Answer {
+        input
+            .iter()
+            .map(|&(opponent_shape, response)| {
+                let match_result = map_my_match_result(response);
+
+                Match::force_result(match_result, opponent_shape)
+            })
+            .map(|_match| _match.get_points())
+            .sum::<u32>()
+            .into()
+    }
+}
+
+impl Print for Day2 {
+    fn print(&self, rounds: &Self::Input) -> String {
+        let lines: Vec<String> = rounds
+            .iter()
+            .map(|(opponent_shape, response)| {
+                let opponent_symbol = match opponent_shape {
+                    Shape::Rock => 'A',
+                    Shape::Paper => 'B',
+                    Shape::Scissors => 'C',
+                };
+                let my_symbol = match response {
+                    Response::X => 'X',
+                    Response::Y => 'Y',
+                    Response::Z => 'Z',
+                };
+
+                format!("{opponent_symbol} {my_symbol}")
+            })
+            .collect();
+
+        lines.join("\n")
+    }
+}
+
+/// Shape played in a round, worth its value in points
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Shape {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+
+/// Second column of the strategy guide, whose meaning changes between parts
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Response {
+    X,
+    Y,
+    Z,
+}
+
+/// Result of a round for me, worth its value in points
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatchResult {
+    Lose = 0,
+    Draw = 3,
+    Win = 6,
+}
+
+/// Round of the tournament
+#[derive(Debug, PartialEq, Eq)]
+pub struct Match {
+    pub opponent_shape: Shape,
+    pub my_shape: Shape,
+}
+
+impl Match {
+    /// Points I score in the round, for my shape and its result
+    pub fn get_points(&self) -> u32 {
+        self.my_shape as u32 + self.get_result() as u32
+    }
+
+    pub fn get_result(&self) -> MatchResult {
+        match self.my_shape {
+            Shape::Rock => match self.opponent_shape {
+                Shape::Rock => MatchResult::Draw,
+                Shape::Paper => MatchResult::Lose,
+                Shape::Scissors => MatchResult::Win,
+            },
+            Shape::Paper => match self.opponent_shape {
+                Shape::Rock => MatchResult::Win,
+                Shape::Paper => MatchResult::Draw,
+                Shape::Scissors => MatchResult::Lose,
+            },
+            Shape::Scissors => match self.opponent_shape {
+                Shape::Rock => MatchResult::Lose,
+                Shape::Paper => MatchResult::Win,
+                Shape::Scissors => MatchResult::Draw,
+            },
+        }
+    }
+
+    /// Round in which I play the shape giving `result` against `opponent_shape`
+    pub fn force_result(result: MatchResult, opponent_shape: Shape) -> Match {
+        let my_shape = match result {
+            MatchResult::Lose => match opponent_shape {
+                Shape::Rock => Shape::Scissors,
+                Shape::Paper => Shape::Rock,
+                Shape::Scissors => Shape::Paper,
+            },
+            MatchResult::Win => match opponent_shape {
+                Shape::Rock => Shape::Paper,
+                Shape::Paper => Shape::Scissors,
+                Shape::Scissors => Shape::Rock,
+            },
+            MatchResult::Draw => opponent_shape,
+        };
+
+        Match {
+            opponent_shape,
+            my_shape,
+        }
+    }
+}
+
+fn map_opponent_shape(symbol: &str) -> Option<Shape> {
+    match symbol {
+        "A" => Some(Shape::Rock),
+        "B" => Some(Shape::Paper),
+        "C" => Some(Shape::Scissors),
+        _ => None,
+    }
+}
+
+/// Shape I play for a response of the guide, in part one
+pub fn map_my_shape(response: Response) -> Shape {
+    match response {
+        Response::X => Shape::Rock,
+        Response::Y => Shape::Paper,
+        Response::Z => Shape::Scissors,
+    }
+}
+
+/// Result I should reach for a response of the guide, in part two
+pub fn map_my_match_result(response: Response) -> MatchResult {
+    match response {
+        Response::X => MatchResult::Lose,
+        Response::Y => MatchResult::Draw,
+        Response::Z => MatchResult::Win,
+    }
+}
+
+impl Response {
+    fn parse(symbol: &str) -> Option<Response> {
+        match symbol {
+            "X" => Some(Response::X),
+            "Y" => Some(Response::Y),
+            "Z" => Some(Response::Z),
+            _ => None,
+        }
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day2::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(15));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day2::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(12));
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day2, 0..20, 50) {
+            panic!("{error}");
+        }
+    }
+}
@@ -0,0 +1,135 @@
+use aoc_core::{
+    differential::{Alternatives, Solver},
+    Part,
+};
+
+use crate::{grove_coordinates, Day20, DECRYPTION_KEY, MIXES_COUNT};
+
+impl Alternatives for Day20 {
+    fn alternatives() -> Vec<Solver<Self>> {
+        vec![
+            Solver {
+                name: "block list",
+                part: Part::One,
+                solve: |_, encrypted| grove_coordinates(&mix_in_blocks(encrypted, 1)).into(),
+            },
+            Solver {
+                name: "block list",
+                part: Part::Two,
+                solve: |_, encrypted| {
+                    let decrypted: Vec<_> = encrypted
+                        .iter()
+                        .map(|&(idx, value)| (idx, value * DECRYPTION_KEY))
+                        .collect();
+
+                    grove_coordinates(&mix_in_blocks(&decrypted, MIXES_COUNT)).into()
+                },
+            },
+        ]
+    }
+}
+
+/// Mixes `times` the numbers, which are in their original order
+fn mix_in_blocks(numbers: &[(usize, i64)], times: u8) -> Vec<(usize, i64)> {
+    let cycle = (numbers.len() as i64 - 1).max(1);
+    let mut list = BlockList::new(numbers.len());
+
+    for _ in 0..times {
+        for &(idx, value) in numbers {
+            let position = list.remove(idx);
+            list.insert((position as i64 + value).rem_euclid(cycle) as usize, idx);
+        }
+    }
+
+    list.blocks
+        .concat()
+        .into_iter()
+        .map(|idx| numbers[idx])
+        .collect()
+}
+
+/// Indexes of the numbers split in blocks of about √n, so that a number is found and moved without
+/// shifting all the others.
+struct BlockList {
+    blocks: Vec<Vec<usize>>,
+    /// Block holding each index
+    block_of: Vec<usize>,
+    block_len: usize,
+}
+
+impl BlockList {
+    fn new(len: usize) -> Self {
+        let mut list = Self {
+            blocks: vec![(0..len).collect()],
+            block_of: vec![0; len],
+            block_len: ((len as f64).sqrt() as usize).max(1),
+        };
+        list.split();
+
+        list
+    }
+
+    /// Splits the list again in blocks of `block_len`, once a block has grown too much
+    fn split(&mut self) {
+        let indexes = self.blocks.concat();
+        self.blocks = indexes.chunks(self.block_len).map(Vec::from).collect();
+
+        for (block, indexes) in self.blocks.iter().enumerate() {
+            for &idx in indexes {
+                self.block_of[idx] = block;
+            }
+        }
+    }
+
+    /// Removes `idx`, returning its position in the list
+    fn remove(&mut self, idx: usize) -> usize {
+        let block = self.block_of[idx];
+        let offset = self.blocks[block].iter().position(|&i| i == idx).unwrap();
+        self.blocks[block].remove(offset);
+
+        self.blocks[..block].iter().map(Vec::len).sum::<usize>() + offset
+    }
+
+    fn insert(&mut self, mut position: usize, idx: usize) {
+        let last = self.blocks.len() - 1;
+
+        for (block, indexes) in self.blocks.iter_mut().enumerate() {
+            if position <= indexes.len() || block == last {
+                indexes.insert(position, idx);
+                self.block_of[idx] = block;
+
+                if indexes.len() > 2 * self.block_len {
+                    self.split();
+                }
+                return;
+            }
+
+            position -= indexes.len();
+        }
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use aoc_core::{differential::compare, Solution};
+
+    use super::*;
+    use crate::mix;
+
+    #[test]
+    fn same_answers() {
+        if let Err(disagreement) = compare(&Day20, 0..20, 300) {
+            panic!("{disagreement}");
+        }
+    }
+
+    #[test]
+    fn same_order() {
+        let numbers = Day20.parse(include_str!("../input.test.txt")).unwrap();
+
+        assert_eq!(mix_in_blocks(&numbers, 1), mix(numbers.clone()));
+        assert_eq!(mix_in_blocks(&[(0, 7)], 3), [(0, 7)]);
+    }
+}
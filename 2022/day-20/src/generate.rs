@@ -0,0 +1,23 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day20;
+
+impl Generate for Day20 {
+    /// Numbers of the file
+    const SIZE: usize = 5000;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        // Exactly one zero, from which the grove coordinates are found
+        let mut numbers = vec![0];
+        while numbers.len() < size.max(1) {
+            let number = rng.range(-10_000..=10_000);
+            if number != 0 {
+                numbers.push(number);
+            }
+        }
+        rng.shuffle(&mut numbers);
+
+        let lines: Vec<String> = numbers.iter().map(i64::to_string).collect();
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,160 @@
+//! Day 20 of 2022: Grove Positioning System.
+//!
+//! The input is the encrypted file, a list of numbers.
+//!
+//! ```
+//! use aoc_2022_day_20::{grove_coordinates, mix};
+//!
+//! // The numbers with their position in the file
+//! let encrypted: Vec<_> = [1, 2, -3, 3, -2, 0, 4].into_iter().enumerate().collect();
+//! let mixed = mix(encrypted);
+//!
+//! // 1000, 2000 and 3000 positions after the 0, going around the circular list
+//! assert_eq!(grove_coordinates(&mixed), 3);
+//! ```
+
+mod alternatives;
+mod generate;
+
+use aoc_core::{parse::lines, Answer, ParseError, Print, Solution};
+
+const DECRYPTION_KEY: i64 = 811_589_153;
+const MIXES_COUNT: u8 = 10;
+
+/// Solution of the day, with the numbers and their position in the file as input
+#[derive(Default)]
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<(usize, i64)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_numbers(input)
+    }
+
+    fn part_one(&self, encrypted: &Self::Input) -> Answer {
+        let mixed = mix(encrypted.clone());
+
+        grove_coordinates(&mixed).into()
+    }
+
+    fn part_two(&self, encrypted: &Self::Input) -> Answer {
+        let mut mixed: Vec<_> = encrypted
+            .iter()
+            .map(|&(idx, value)| (idx, value * DECRYPTION_KEY))
+            .collect();
+
+        for _ in 0..MIXES_COUNT {
+            mixed = mix(mixed);
+        }
+
+        grove_coordinates(&mixed).into()
+    }
+}
+
+impl Print for Day20 {
+    fn print(&self, encrypted: &Self::Input) -> String {
+        let lines: Vec<String> = encrypted
+            .iter()
+            .map(|(_, value)| value.to_string())
+            .collect();
+
+        lines.join("\n")
+    }
+}
+
+/// Sum of the numbers 1000, 2000 and 3000 positions after the 0.
+///
+/// Panics if there is no 0 (which the parser rules out).
+pub fn grove_coordinates(mixed: &[(usize, i64)]) -> i64 {
+    let zero_idx = mixed
+        .iter()
+        .position(|(_, value)| *value == 0)
+        .expect("the file should contain a 0");
+
+    let v1 = mixed[(zero_idx + 1000) % mixed.len()].1;
+    let v2 = mixed[(zero_idx + 2000) % mixed.len()].1;
+    let v3 = mixed[(zero_idx + 3000) % mixed.len()].1;
+
+    v1 + v2 + v3
+}
+
+/// Moves each number, in the order of their position in the file, forward or backward by its
+/// value
+pub fn mix(encrypted: Vec<(usize, i64)>) -> Vec<(usize, i64)> {
+    let mut mixed = encrypted;
+    // Positions of the other numbers around which a number moves (a single number stays put)
+    let cycle = (mixed.len() as i64 - 1).max(1);
+
+    for n in 0..mixed.len() {
+        let old_idx = mixed.iter().position(|(idx, _)| n == *idx).unwrap();
+        let offset = mixed[old_idx].1;
+
+        let new_idx = old_idx as i64 + offset;
+        let new_idx = new_idx.rem_euclid(cycle);
+
+        // Move element from `old_idx` to `new_idx`
+        let removed = mixed.remove(old_idx);
+        mixed.insert(new_idx as usize, removed);
+    }
+
+    mixed
+}
+
+/// Parses the numbers, checking that the grove coordinates can be found from a 0
+fn parse_numbers(input: &str) -> Result<Vec<(usize, i64)>, ParseError> {
+    let numbers: Vec<_> = lines(input)
+        .enumerate()
+        .map(|(i, line)| Ok((i, line.parse(line.text, "an i64 number")?)))
+        .collect::<Result<_, _>>()?;
+
+    if !numbers.iter().any(|&(_, value)| value == 0) {
+        return Err(ParseError::end_of_input(input, "a `0`"));
+    }
+
+    Ok(numbers)
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day20::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(3));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day20::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(1623178306));
+    }
+
+    #[test]
+    fn single_number() {
+        let day = Day20::example();
+        let input = day.parse("0").unwrap();
+
+        assert_eq!(day.part_one(&input), Answer::Number(0));
+        assert_eq!(day.part_two(&input), Answer::Number(0));
+    }
+
+    #[test]
+    fn missing_zero() {
+        let error = Day20.parse("1\n2\n-3").unwrap_err();
+        assert_eq!((error.line, error.expected.as_str()), (4, "a `0`"));
+        assert!(Day20.parse("").is_err());
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day20, 0..20, 50) {
+            panic!("{error}");
+        }
+    }
+}
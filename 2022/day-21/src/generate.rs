@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+
+use aoc_core::{Generate, Rng};
+
+use crate::Day21;
+
+/// Deepest nesting of the jobs computing a constant
+const MAX_DEPTH: u32 = 3;
+
+impl Generate for Day21 {
+    /// Monkeys between `root` and `humn`
+    const SIZE: usize = 70;
+
+    /// Jobs where the number yelled by `root` depends on `humn` through a chain of operations with
+    /// constants, each computed by other monkeys. The chain is monotonic, and multiplications are
+    /// balanced by divisions to keep the numbers small, so the search of part two finds `humn`.
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let mut jobs = Jobs {
+            names: HashSet::from(["root".to_owned(), "humn".to_owned()]),
+            lines: Vec::new(),
+        };
+
+        let mut value = rng.range(1..=1000);
+        jobs.lines.push(format!("humn: {value}"));
+
+        let mut name = "humn".to_owned();
+        // Product of the multiplications divided by the one of the divisions
+        let mut slope = 1.0;
+        for _ in 0..size.max(1) {
+            let constant = rng.range(1..=100);
+            let (job, next) = match rng.index(5) {
+                0 if slope < 10.0 => {
+                    let factor = rng.range(2..=9);
+                    slope *= factor as f64;
+                    let other = jobs.constant(rng, factor, 0);
+                    (format!("{name} * {other}"), value * factor)
+                }
+                0 | 1 if slope > 0.1 => {
+                    let divisor = rng.range(2..=9);
+                    slope /= divisor as f64;
+                    let other = jobs.constant(rng, divisor, 0);
+                    (format!("{name} / {other}"), value / divisor)
+                }
+                2 => {
+                    let other = jobs.constant(rng, constant, 0);
+                    (format!("{other} - {name}"), constant - value)
+                }
+                3 => {
+                    let other = jobs.constant(rng, constant, 0);
+                    (format!("{name} - {other}"), value - constant)
+                }
+                _ => {
+                    let other = jobs.constant(rng, constant, 0);
+                    (format!("{other} + {name}"), constant + value)
+                }
+            };
+
+            let monkey = jobs.name(rng);
+            jobs.lines.push(format!("{monkey}: {job}"));
+            name = monkey;
+            value = next;
+        }
+
+        // The other side of the equality of part two
+        let other = jobs.constant(rng, value, 0);
+        let operation = rng.pick(&['+', '-', '*']);
+        let root = if rng.chance(0.5) {
+            format!("root: {name} {operation} {other}")
+        } else {
+            format!("root: {other} {operation} {name}")
+        };
+        jobs.lines.push(root);
+
+        rng.shuffle(&mut jobs.lines);
+        jobs.lines.join("\n")
+    }
+}
+
+struct Jobs {
+    names: HashSet<String>,
+    lines: Vec<String>,
+}
+
+impl Jobs {
+    /// New name of four letters
+    fn name(&mut self, rng: &mut Rng) -> String {
+        loop {
+            let name: String = (0..4)
+                .map(|_| (b'a' + rng.index(26) as u8) as char)
+                .collect();
+
+            if self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    /// Adds monkeys yelling `value`, returning the name of the one yelling it
+    fn constant(&mut self, rng: &mut Rng, value: i64, depth: u32) -> String {
+        let name = self.name(rng);
+
+        let job = if depth == MAX_DEPTH || rng.chance(0.3) {
+            value.to_string()
+        } else {
+            let (a, operation, b) = match rng.index(4) {
+                0 => {
+                    let b = rng.range(1..=value.abs().max(1));
+                    (value - b, '+', b)
+                }
+                1 => {
+                    let b = rng.range(1..=20);
+                    (value + b, '-', b)
+                }
+                2 if value % 2 == 0 && value != 0 => (value / 2, '*', 2),
+                _ => {
+                    let b = rng.range(2..=5);
+                    (value * b, '/', b)
+                }
+            };
+
+            let a = self.constant(rng, a, depth + 1);
+            let b = self.constant(rng, b, depth + 1);
+            format!("{a} {operation} {b}")
+        };
+
+        self.lines.push(format!("{name}: {job}"));
+        name
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use aoc_core::{Answer, Solution};
+
+    use super::*;
+
+    #[test]
+    fn humn_balances_root() {
+        let day = Day21;
+
+        for seed in 0..20 {
+            let mut monkeys = day.parse(&day.generate(&mut Rng::new(seed), 30)).unwrap();
+            let Answer::Number(humn) = day.part_two(&monkeys) else {
+                panic!("part two should find the number to yell");
+            };
+
+            monkeys.transform_root_to_sub();
+            monkeys.set_value("humn", humn);
+            assert_eq!(monkeys.yell("root"), Some(0));
+        }
+    }
+}
@@ -0,0 +1,308 @@
+//! Day 21 of 2022: Monkey Math.
+//!
+//! The input is the job of each monkey: yelling a number, or the result of an operation on the
+//! numbers yelled by two other monkeys.
+//!
+//! ```
+//! use aoc_2022_day_21::{Job, Monkeys, Operation};
+//!
+//! let mut monkeys = Monkeys::default();
+//! monkeys.set_job("root", Job::Operation("humn".into(), Operation::Add, "four".into()));
+//! monkeys.set_job("four", Job::Number(4));
+//! monkeys.set_job("humn", Job::Number(2));
+//!
+//! assert_eq!(monkeys.yell("root"), Some(6));
+//! // The number to yell for both numbers of `root` to be equal
+//! assert_eq!(monkeys.human_number(), 4);
+//! ```
+
+mod generate;
+
+use std::{
+    cmp::{max, min},
+    collections::HashMap,
+    fmt::{self, Display},
+};
+
+use aoc_core::{parse::lines, Answer, ParseError, Print, Solution};
+
+/// Solution of the day, with the jobs of the monkeys as input
+#[derive(Default)]
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Monkeys;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Monkeys::parse(input)
+    }
+
+    fn part_one(&self, monkeys: &Self::Input) -> Answer {
+        monkeys
+            .yell("root")
+            .expect("`root` should be parsed")
+            .into()
+    }
+
+    fn part_two(&self, monkeys: &Self::Input) -> Answer {
+        monkeys.human_number().into()
+    }
+}
+
+impl Print for Day21 {
+    /// Prints the monkeys sorted by name
+    fn print(&self, monkeys: &Self::Input) -> String {
+        let mut names: Vec<&String> = monkeys.monkeys.keys().collect();
+        names.sort();
+
+        let lines: Vec<String> = names
+            .into_iter()
+            .map(|name| format!("{name}: {}", monkeys.monkeys[name]))
+            .collect();
+
+        lines.join("\n")
+    }
+}
+
+/// Job of a monkey, the operation being on the numbers yelled by two other monkeys
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Job {
+    Number(i64),
+    Operation(String, Operation, String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// Jobs of the monkeys, by name
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Monkeys {
+    monkeys: HashMap<String, Job>,
+}
+
+impl Monkeys {
+    /// Parses the jobs, checking that `root` waits for two monkeys, that `humn` exists and that
+    /// every monkey waited for has a job
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let jobs: Vec<_> = lines(input)
+            .map(|line| {
+                let (name, expr) = line.split_once(line.text, ":")?;
+                let name = name.trim().to_string();
+                let mut expr = expr.split_whitespace();
+
+                let a = expr
+                    .next()
+                    .ok_or_else(|| line.error_at_end("a number or monkey name"))?;
+                if let Ok(number) = a.parse::<i64>() {
+                    return Ok((line, name, Job::Number(number), vec![]));
+                }
+
+                let op = expr
+                    .next()
+                    .ok_or_else(|| line.error_at_end("an operation"))?;
+                let b = expr
+                    .next()
+                    .ok_or_else(|| line.error_at_end("a second monkey name"))?;
+
+                let op = match op {
+                    "+" => Operation::Add,
+                    "-" => Operation::Sub,
+                    "*" => Operation::Mul,
+                    "/" => Operation::Div,
+                    _ => return Err(line.error(op, "`+`, `-`, `*` or `/`")),
+                };
+
+                let job = Job::Operation(a.to_string(), op, b.to_string());
+                Ok((line, name, job, vec![a, b]))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let monkeys: HashMap<_, _> = jobs
+            .iter()
+            .map(|(_, name, job, _)| (name.clone(), job.clone()))
+            .collect();
+
+        for (line, name, job, operands) in &jobs {
+            if let Some(operand) = operands
+                .iter()
+                .find(|&&operand| !monkeys.contains_key(operand))
+            {
+                return Err(line.error(operand, "the name of a monkey with a job"));
+            }
+            if name == "root" && matches!(job, Job::Number(_)) {
+                let (_, number) = line.text.split_once(':').unwrap_or_default();
+                return Err(line.error(number.trim_start(), "an operation for `root`"));
+            }
+        }
+        for name in ["root", "humn"] {
+            if !monkeys.contains_key(name) {
+                return Err(ParseError::end_of_input(
+                    input,
+                    format!("the job of `{name}`"),
+                ));
+            }
+        }
+
+        Ok(Self { monkeys })
+    }
+
+    fn transform_root_to_sub(&mut self) {
+        let root = self
+            .monkeys
+            .get_mut("root")
+            .expect("`root` should have a job");
+
+        if let Job::Operation(_, op, _) = root {
+            *op = Operation::Sub;
+        }
+    }
+
+    pub fn set_job(&mut self, monkey_name: &str, job: Job) {
+        self.monkeys.insert(monkey_name.to_string(), job);
+    }
+
+    fn set_value(&mut self, monkey_name: &str, value: i64) {
+        self.set_job(monkey_name, Job::Number(value));
+    }
+
+    /// Number yelled by the monkey, or `None` if it or a monkey it waits for is missing
+    pub fn yell(&self, monkey_name: &str) -> Option<i64> {
+        let value = match self.monkeys.get(monkey_name)? {
+            Job::Number(number) => *number,
+            Job::Operation(a, op, b) => {
+                let a = self.yell(a)?;
+                let b = self.yell(b)?;
+
+                match op {
+                    Operation::Add => a + b,
+                    Operation::Sub => a - b,
+                    Operation::Mul => a * b,
+                    Operation::Div => a / b,
+                }
+            }
+        };
+
+        Some(value)
+    }
+
+    /// Number that `humn` should yell for `root` to get the same number from both monkeys it
+    /// waits for.
+    ///
+    /// Panics if `root` or `humn` is missing (which the parser rules out).
+    pub fn human_number(&self) -> i64 {
+        let mut monkeys = self.clone();
+        monkeys.transform_root_to_sub();
+
+        let mut humn = monkeys.yell("humn").unwrap();
+        let mut prev_root = monkeys.yell("root").unwrap();
+        let mut steps = 1;
+        let target = 0;
+
+        // Using exponetial backoff to find `humn` value
+        loop {
+            let walk_humn = humn + steps;
+            monkeys.set_value("humn", walk_humn);
+            let root = monkeys.yell("root").unwrap();
+
+            if root == target {
+                return monkeys.yell("humn").unwrap();
+            }
+
+            // Passed by target
+            if (min(root, prev_root)..=max(root, prev_root)).contains(&target) {
+                // Change step direction
+                steps = -steps.signum();
+                humn = walk_humn;
+            } else {
+                steps *= 2;
+            }
+
+            prev_root = root;
+        }
+    }
+}
+
+impl Display for Job {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Job::Number(number) => write!(f, "{number}"),
+            Job::Operation(a, op, b) => {
+                let op = match op {
+                    Operation::Add => '+',
+                    Operation::Sub => '-',
+                    Operation::Mul => '*',
+                    Operation::Div => '/',
+                };
+
+                write!(f, "{a} {op} {b}")
+            }
+        }
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day21::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(152));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day21::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(301));
+    }
+
+    #[test]
+    fn invalid_operation() {
+        let error = Day21.parse("root: pppw % sjmn").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 12));
+        assert_eq!(error.expected, "`+`, `-`, `*` or `/`");
+    }
+
+    #[test]
+    fn missing_monkeys() {
+        let error = Day21
+            .parse("root: humn + four\nhumn: 2\nfour: 4\nfive: six * 2")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (4, 7));
+        assert_eq!(error.expected, "the name of a monkey with a job");
+
+        let error = Day21.parse("root: humn + four\nfour: 4").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 7));
+
+        let error = Day21.parse("humn: 2").unwrap_err();
+        assert_eq!(
+            (error.line, error.expected.as_str()),
+            (2, "the job of `root`")
+        );
+
+        let error = Day21.parse("root: 5\nhumn: 2").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.expected.as_str()),
+            (1, 7, "an operation for `root`")
+        );
+
+        let error = Day21.parse("root: four + four\nfour: 4").unwrap_err();
+        assert_eq!(error.expected, "the job of `humn`");
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day21, 0..20, 50) {
+            panic!("{error}");
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{wrap_row::WrapRow, Direction, Element, Point};
+
+type Point3 = geometry::Point3<isize>;
+
+const DIRECTIONS: [Direction; 4] = [
+    Direction::Right,
+    Direction::Down,
+    Direction::Left,
+    Direction::Up,
+];
+
+/// Faces of the cube which the map folds into
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cube {
+    side: isize,
+    faces: Vec<Face>,
+}
+
+/// Face of the cube, at a position in the net of faces drawn by the map. Its axes are the
+/// directions in space of the right and the bottom of the map, and of the outside of the cube.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Face {
+    net: Point,
+    right: Point3,
+    down: Point3,
+    normal: Point3,
+}
+
+impl Cube {
+    /// Folds the rows of the map, if they draw the net of a cube
+    pub fn fold(rows: &WrapRow<WrapRow<Element>>) -> Option<Self> {
+        let tiles: usize = rows.iter().map(|row| row.iter().count()).sum();
+        let side = (tiles / 6).isqrt();
+        if side == 0 || side * side * 6 != tiles {
+            return None;
+        }
+        let side = side as isize;
+
+        // With six squares of the net holding tiles, each of them is full
+        let mut nets: Vec<Point> = Vec::new();
+        for (y, row) in rows.iter().enumerate() {
+            for x in row.start()..row.start() + row.iter().count() {
+                let net = ((x as isize - 1) / side, y as isize / side).into();
+                if !nets.contains(&net) {
+                    nets.push(net);
+                }
+            }
+        }
+        if nets.len() != 6 {
+            return None;
+        }
+
+        // Rolls the first face over the net, turning each face it reaches around its edge
+        let mut faces = vec![Face {
+            net: nets[0],
+            right: (1, 0, 0).into(),
+            down: (0, 1, 0).into(),
+            normal: (0, 0, -1).into(),
+        }];
+        let mut i = 0;
+        while let Some(&face) = faces.get(i) {
+            for direction in DIRECTIONS {
+                let net = face.net + offset(direction);
+                if nets.contains(&net) && faces.iter().all(|face| face.net != net) {
+                    faces.push(face.roll(direction));
+                }
+            }
+            i += 1;
+        }
+
+        let mut sides: Vec<_> = faces.iter().map(|face| face.normal).collect();
+        sides.sort();
+        sides.dedup();
+        (sides.len() == 6).then_some(Self { side, faces })
+    }
+
+    /// Facing and position after walking a tile, going around the edge to the next face when
+    /// leaving the map
+    pub fn step(&self, cur: Point, direction: Direction) -> (Direction, Point) {
+        let next = cur + offset(direction);
+        if self.face(next).is_some() {
+            return (direction, next);
+        }
+
+        let from = self.face(cur).expect("the walker should be on the cube");
+        let forward = from.axis(direction);
+        let to = self
+            .faces
+            .iter()
+            .find(|face| face.normal == forward)
+            .expect("the cube should have a face on each side");
+
+        // Coordinates in space of the tiles, doubled to be centred on the cube, the faces lying
+        // at `side` from its centre and the middles of the tiles next to their edges at `side - 1`
+        let side = self.side;
+        let tile = cur - from.net * side - (1, 1).into();
+        let across = from.normal * side
+            + from.right * (2 * tile.x - side + 1)
+            + from.down * (2 * tile.y - side + 1)
+            + forward
+            - from.normal;
+        let x = (dot(across, to.right) + side - 1) / 2;
+        let y = (dot(across, to.down) + side - 1) / 2;
+
+        // Going away from the face which was left
+        let direction = DIRECTIONS
+            .into_iter()
+            .find(|direction| to.axis(*direction) == -from.normal)
+            .expect("the faces should be next to each other");
+
+        (direction, to.net * side + (x + 1, y + 1).into())
+    }
+
+    fn face(&self, point: Point) -> Option<&Face> {
+        if point.x < 1 || point.y < 1 {
+            return None;
+        }
+
+        let net = ((point.x - 1) / self.side, (point.y - 1) / self.side).into();
+        self.faces.iter().find(|face| face.net == net)
+    }
+}
+
+impl Face {
+    /// Face next to this one in the net, folded around their shared edge
+    fn roll(self, direction: Direction) -> Self {
+        let Face {
+            net,
+            right,
+            down,
+            normal,
+        } = self;
+        let net = net + offset(direction);
+
+        match direction {
+            Direction::Right => Face {
+                net,
+                right: -normal,
+                down,
+                normal: right,
+            },
+            Direction::Down => Face {
+                net,
+                right,
+                down: -normal,
+                normal: down,
+            },
+            Direction::Left => Face {
+                net,
+                right: normal,
+                down,
+                normal: -right,
+            },
+            Direction::Up => Face {
+                net,
+                right,
+                down: normal,
+                normal: -down,
+            },
+        }
+    }
+
+    fn axis(self, direction: Direction) -> Point3 {
+        match direction {
+            Direction::Right => self.right,
+            Direction::Down => self.down,
+            Direction::Left => -self.right,
+            Direction::Up => -self.down,
+        }
+    }
+}
+
+fn offset(direction: Direction) -> Point {
+    match direction {
+        Direction::Right => (1, 0),
+        Direction::Down => (0, 1),
+        Direction::Left => (-1, 0),
+        Direction::Up => (0, -1),
+    }
+    .into()
+}
+
+fn dot(a: Point3, b: Point3) -> isize {
+    a.x * b.x + a.y * b.y + a.z * b.z
+}
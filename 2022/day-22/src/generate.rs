@@ -0,0 +1,45 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day22;
+
+/// Side of the faces of the cube, as in the puzzle inputs
+const SIDE: usize = 50;
+
+impl Generate for Day22 {
+    /// Numbers of tiles to walk in the path
+    const SIZE: usize = 2000;
+
+    /// Map in the layout of the puzzle inputs or in the one of the example, with random walls
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        // Columns of faces in each row of faces
+        let layouts: [&[&[usize]]; 2] = [
+            &[&[1, 2], &[1], &[0, 1], &[0]],
+            &[&[2], &[0, 1, 2], &[2, 3]],
+        ];
+        let layout = *rng.pick(&layouts);
+
+        let mut lines = Vec::new();
+        for (face_row, faces) in layout.iter().enumerate() {
+            for y in 0..SIDE {
+                let start = faces[0] * SIDE;
+                let mut line = " ".repeat(start);
+
+                for x in start..(faces[faces.len() - 1] + 1) * SIDE {
+                    // The path starts on the first open tile of the first row
+                    let first = face_row == 0 && y == 0 && x == start;
+                    line.push(if !first && rng.chance(0.1) { '#' } else { '.' });
+                }
+
+                lines.push(line);
+            }
+        }
+
+        let mut path = rng.range(1..=50).to_string();
+        for _ in 1..size.max(1) {
+            path.push(*rng.pick(&['L', 'R']));
+            path += &rng.range(1..=50).to_string();
+        }
+
+        format!("{}\n\n{path}", lines.join("\n"))
+    }
+}
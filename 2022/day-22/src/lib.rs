@@ -0,0 +1,398 @@
+//! Day 22 of 2022: Monkey Map.
+//!
+//! The input is the map of the board, followed by the path to follow on it.
+//!
+//! ```
+//! use aoc_2022_day_22::{Day22, Problem};
+//! use aoc_core::Solution;
+//!
+//! let (mut map, path) = Day22.parse("...#\n.#..\n\n3R1").unwrap();
+//! for instruction in path {
+//!     map.apply(instruction, Problem::Part1);
+//! }
+//!
+//! // Stopped by the wall, then going down to the row 2 and the column 3
+//! assert_eq!(map.password(), 1000 * 2 + 4 * 3 + 1);
+//! ```
+
+use std::fmt::Debug;
+
+use aoc_core::{
+    parse::{lines, Line},
+    Answer, ParseError, Print, Solution,
+};
+use cube::Cube;
+use wrap_row::WrapRow;
+
+mod cube;
+mod generate;
+pub mod wrap_row;
+
+type Point = geometry::Point<isize>;
+
+/// Solution of the day, with the map and the path as input
+#[derive(Default)]
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = (Map, Vec<Instruction>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let lines: Vec<_> = lines(input).collect();
+        let blank = lines
+            .iter()
+            .position(|line| line.text.is_empty())
+            .unwrap_or(lines.len());
+        if blank == 0 {
+            return Err(ParseError::new(1, 1, "a map of `.` and `#`"));
+        }
+
+        let path = lines[blank..]
+            .iter()
+            .find(|line| !line.text.is_empty())
+            .ok_or_else(|| ParseError::end_of_input(input, "a path after an empty line"))?;
+
+        Ok((Map::parse(&lines[..blank])?, Instruction::parse(path)?))
+    }
+
+    fn part_one(&self, notes: &Self::Input) -> Answer {
+        let (map, instructions) = notes;
+        let mut map = map.clone();
+
+        for instruction in instructions {
+            map.apply(*instruction, Problem::Part1);
+        }
+
+        map.password().into()
+    }
+
+    fn part_two(&self, notes: &Self::Input) -> Answer {
+        let (map, instructions) = notes;
+        let mut map = map.clone();
+
+        for instruction in instructions {
+            map.apply(*instruction, Problem::Part2);
+        }
+
+        map.password().into()
+    }
+}
+
+impl Print for Day22 {
+    fn print(&self, notes: &Self::Input) -> String {
+        let (map, instructions) = notes;
+
+        let mut lines: Vec<String> = map
+            .rows
+            .iter()
+            .map(|row| {
+                let tiles = row.iter().map(|element| element.char());
+                " ".repeat(row.start() - 1).chars().chain(tiles).collect()
+            })
+            .collect();
+
+        let path: Vec<String> = instructions
+            .iter()
+            .map(|instruction| match instruction {
+                Instruction::Walk(units) => units.to_string(),
+                Instruction::Turn(TurnDirection::Right) => "R".to_owned(),
+                Instruction::Turn(TurnDirection::Left) => "L".to_owned(),
+            })
+            .collect();
+        lines.push(String::new());
+        lines.push(path.concat());
+
+        lines.join("\n")
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TurnDirection {
+    Right,
+    Left,
+}
+
+/// Step of the path
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    Walk(u32),
+    Turn(TurnDirection),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Direction {
+    Right,
+    Down,
+    Left,
+    Up,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Element {
+    Tile,
+    Wall,
+}
+
+/// How the map wraps around: on the same row or column, or around the faces of the cube which
+/// it folds into
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Problem {
+    Part1,
+    Part2,
+}
+
+/// Board of the monkeys, with the position and facing of the walker
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Map {
+    rows: WrapRow<WrapRow<Element>>,
+    cols: WrapRow<WrapRow<Element>>,
+    cube: Option<Cube>,
+    cur: Point,
+    direction: Direction,
+}
+
+impl Map {
+    fn parse(lines: &[Line]) -> Result<Self, ParseError> {
+        for line in lines {
+            let tiles = line.text.trim_start();
+            if tiles.is_empty() {
+                return Err(line.error_at_end("`.` or `#`"));
+            }
+
+            if let Some(i) = tiles.find(|c| c != '.' && c != '#') {
+                return Err(line.error(&tiles[i..], "`.` or `#`"));
+            }
+        }
+
+        let input: Vec<_> = lines.iter().map(|line| line.text.as_bytes()).collect();
+
+        let rows: Vec<_> = input
+            .iter()
+            .map(|line| {
+                let start = line.iter().position(|c| *c != b' ').expect("row");
+                let row: Vec<_> = line.iter().skip(start).map(|c| Element::from(*c)).collect();
+
+                WrapRow::new(start + 1, row)
+            })
+            .collect();
+        let rows = WrapRow::new(1, rows);
+
+        let cols_count = input.iter().map(|line| line.len()).max().unwrap_or(0);
+        let cols: Vec<_> = (0..cols_count)
+            .map(|col_idx| {
+                let col = input.iter().filter_map(|line| line.get(col_idx));
+
+                let start = col.clone().position(|c| *c != b' ').ok_or_else(|| {
+                    ParseError::new(lines[0].number, col_idx + 1, "a column with tiles")
+                })?;
+                let col: Vec<_> = col
+                    .skip(start)
+                    .take_while(|c| **c != b' ')
+                    .map(|c| Element::from(*c))
+                    .collect();
+
+                Ok(WrapRow::new(start + 1, col))
+            })
+            .collect::<Result<_, ParseError>>()?;
+
+        let cols = WrapRow::new(1, cols);
+
+        Ok(Self {
+            cur: (rows.get(1).start() as isize, 1).into(),
+            direction: Direction::Right,
+            cube: Cube::fold(&rows),
+            cols,
+            rows,
+        })
+    }
+
+    /// Turns or walks until a wall. Walking around the cube of part two panics if the map
+    /// doesn't fold into one.
+    pub fn apply(&mut self, instruction: Instruction, problem: Problem) {
+        match instruction {
+            Instruction::Turn(TurnDirection::Right) => {
+                self.direction = match self.direction {
+                    Direction::Right => Direction::Down,
+                    Direction::Down => Direction::Left,
+                    Direction::Left => Direction::Up,
+                    Direction::Up => Direction::Right,
+                }
+            }
+            Instruction::Turn(TurnDirection::Left) => {
+                self.direction = match self.direction {
+                    Direction::Right => Direction::Up,
+                    Direction::Up => Direction::Left,
+                    Direction::Left => Direction::Down,
+                    Direction::Down => Direction::Right,
+                }
+            }
+            Instruction::Walk(units) => {
+                for _ in 0..units {
+                    let (next_dir, next_pos) = match problem {
+                        Problem::Part1 => self.next_pos_part_1(),
+                        Problem::Part2 => self.next_pos_part_2(),
+                    };
+
+                    if matches!(self.get(next_pos), Element::Wall) {
+                        break;
+                    }
+
+                    self.cur = next_pos;
+                    self.direction = next_dir;
+                }
+            }
+        }
+    }
+
+    /// Password made of the row, column and facing of the walker
+    pub fn password(&self) -> isize {
+        self.cur.y * 1000 + self.cur.x * 4 + self.direction as isize
+    }
+
+    fn get(&self, point: Point) -> &Element {
+        self.rows.get(point.y).get(point.x)
+    }
+
+    fn next_pos_part_1(&self) -> (Direction, Point) {
+        let mov = match self.direction {
+            Direction::Right | Direction::Down => 1,
+            Direction::Left | Direction::Up => -1,
+        };
+
+        let mut cur = self.cur;
+
+        match self.direction {
+            Direction::Right | Direction::Left => {
+                let row = self.rows.get(cur.y);
+                let next_x = row.wrap(cur.x + mov) as isize;
+                cur.x = next_x;
+            }
+            Direction::Up | Direction::Down => {
+                let col = self.cols.get(cur.x);
+                let next_y = col.wrap(cur.y + mov) as isize;
+
+                cur.y = next_y;
+            }
+        };
+
+        (self.direction, cur)
+    }
+
+    fn next_pos_part_2(&self) -> (Direction, Point) {
+        let cube = self.cube.as_ref().expect("the map should fold into a cube");
+        cube.step(self.cur, self.direction)
+    }
+}
+
+impl Instruction {
+    fn parse(line: &Line) -> Result<Vec<Self>, ParseError> {
+        let mut instructions: Vec<Self> = Vec::new();
+        let mut rest = line.text.trim_end();
+
+        loop {
+            let turn_idx = rest
+                .find(|c: char| c.is_ascii_alphabetic())
+                .unwrap_or(rest.len());
+
+            let walk_units: u32 = line.parse(&rest[..turn_idx], "a number of tiles to walk")?;
+            let walk_instruction = Self::Walk(walk_units);
+            instructions.push(walk_instruction);
+
+            if turn_idx == rest.len() {
+                break;
+            }
+
+            let turn_direction = match rest.as_bytes()[turn_idx] {
+                b'R' => TurnDirection::Right,
+                b'L' => TurnDirection::Left,
+                _ => return Err(line.error(&rest[turn_idx..], "`R` or `L`")),
+            };
+            let turn_instruction = Self::Turn(turn_direction);
+            instructions.push(turn_instruction);
+
+            rest = &rest[turn_idx + 1..];
+        }
+
+        Ok(instructions)
+    }
+}
+
+impl From<u8> for Element {
+    fn from(value: u8) -> Self {
+        match value {
+            b'.' => Self::Tile,
+            b'#' => Self::Wall,
+            _ => panic!(
+                "invalid element character: '{}' (asci: {})",
+                char::from(value),
+                value
+            ),
+        }
+    }
+}
+
+impl Element {
+    fn char(self) -> char {
+        match self {
+            Element::Tile => '.',
+            Element::Wall => '#',
+        }
+    }
+}
+
+impl Debug for Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.char())
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day22::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(6032));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day22::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(5031));
+    }
+
+    #[test]
+    fn fold() {
+        let (map, _) = Day22.parse(include_str!("../input.test.txt")).unwrap();
+        assert!(map.cube.is_some());
+
+        // Tiles of six faces, which don't cover the sides of a cube
+        let (map, _) = Day22.parse("......\n\n1").unwrap();
+        assert!(map.cube.is_none());
+
+        let (map, _) = Day22.parse("...#\n.#..\n\n1").unwrap();
+        assert!(map.cube.is_none());
+    }
+
+    #[test]
+    fn invalid_notes() {
+        let error = Day22.parse("..#\n.x.\n\n10R5").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+
+        let error = Day22.parse("..#\n...\n\n10R5X2").unwrap_err();
+        assert_eq!((error.line, error.column), (4, 5));
+        assert_eq!(error.expected, "`R` or `L`");
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day22, 0..20, 50) {
+            panic!("{error}");
+        }
+    }
+}
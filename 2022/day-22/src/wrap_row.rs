@@ -1,4 +1,5 @@
-#[derive(Debug)]
+/// Row of values starting at the index `start`, its indexes wrapping around to stay in the row
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct WrapRow<T> {
     data: Vec<T>,
     start: usize,
@@ -24,4 +25,9 @@ impl<T> WrapRow<T> {
     pub fn start(&self) -> usize {
         self.start
     }
+
+    /// Values from the start, without wrapping
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
 }
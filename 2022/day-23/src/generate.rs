@@ -0,0 +1,27 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day23;
+
+impl Generate for Day23 {
+    /// Side of the square scan
+    const SIZE: usize = 72;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let side = size.max(1);
+        let mut rows: Vec<Vec<char>> = (0..side)
+            .map(|_| {
+                (0..side)
+                    .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+
+        // The scan needs at least one elf
+        if !rows.iter().flatten().any(|&tile| tile == '#') {
+            rows[rng.index(side)][rng.index(side)] = '#';
+        }
+
+        let rows: Vec<String> = rows.into_iter().map(String::from_iter).collect();
+        rows.join("\n")
+    }
+}
@@ -0,0 +1,345 @@
+//! Day 23 of 2022: Unstable Diffusion.
+//!
+//! The input is the scan of the positions of the elves (`#`) in the grove.
+//!
+//! ```
+//! use aoc_2022_day_23::Day23;
+//! use aoc_core::Solution;
+//!
+//! let mut elves = Day23.parse(".....\n..##.\n..#..\n.....\n..##.\n.....").unwrap();
+//! for _ in 0..3 {
+//!     elves.do_round();
+//! }
+//!
+//! // The 5 elves end up spread out on 6 rows of 5 tiles, where they stop moving
+//! assert_eq!(elves.empty_ground_tiles(), 25);
+//! assert_eq!(elves.positions().count(), 5);
+//! ```
+
+mod generate;
+
+use std::{
+    collections::{HashMap, HashSet},
+    io::Write,
+};
+
+use animation::{Animate, Canvas, Color, Glyph};
+use aoc_core::{parse::lines, Answer, ParseError, Part, Print, Solution};
+use geometry::Point;
+use grid::Grid;
+
+/// Solution of the day, with the positions of the elves as input
+#[derive(Default)]
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Elves;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Elves::parse(input)
+    }
+
+    fn part_one(&self, elves: &Self::Input) -> Answer {
+        let mut elves = elves.clone();
+
+        for _ in 0..10 {
+            elves.do_round();
+        }
+
+        elves.empty_ground_tiles().into()
+    }
+
+    /// The positions of the elves reach a fixed point, the round leaving them unchanged being the
+    /// first in which no elf moves
+    fn part_two(&self, elves: &Self::Input) -> Answer {
+        let mut elves = elves.clone();
+
+        let cycle = cycle::fixed_point(&mut elves, Elves::do_round, Elves::fingerprint);
+
+        cycle.end().into()
+    }
+}
+
+impl Print for Day23 {
+    fn print(&self, elves: &Self::Input) -> String {
+        // The parsed positions start at 0, from the bottom left corner
+        let max = elves.elves.iter().fold(Point::new(0, 0), |max, elf| Point {
+            x: max.x.max(elf.x),
+            y: max.y.max(elf.y),
+        });
+
+        let mut grid = Grid::with_bounds(Point::new(0, 0), max, '.');
+        for elf in &elves.elves {
+            grid[*elf] = '#';
+        }
+
+        let rows = grid.display_y_up().to_string();
+        rows.trim_end().to_owned()
+    }
+}
+
+impl Animate for Day23 {
+    fn animate<W: Write>(&self, elves: &Self::Input, part: Part, canvas: &mut Canvas<W>) {
+        let rounds = match part {
+            Part::One => Some(10),
+            Part::Two => None,
+        };
+
+        // Runs the rounds once to find the area covered by the elves through the animation
+        let mut spread = elves.clone();
+        let (mut min, mut max) = spread.bounds();
+        let mut round = 0;
+        while rounds.is_none_or(|rounds| round < rounds) && spread.moving_round() {
+            let bounds = spread.bounds();
+            (min, max) = Point::min_max([min, max, bounds.0, bounds.1]).unwrap();
+            round += 1;
+        }
+
+        let mut state = (elves.clone(), 0);
+        canvas.set_y_up(true);
+        canvas.run(
+            &mut state,
+            |(elves, round)| {
+                if rounds.is_some_and(|rounds| *round >= rounds) || !elves.moving_round() {
+                    return false;
+                }
+
+                *round += 1;
+                true
+            },
+            |canvas, (elves, round)| {
+                let mut frame = Grid::with_bounds(min, max, Glyph::new('.', Color::Gray));
+                for elf in &elves.elves {
+                    frame[*elf] = Glyph::new('#', Color::Green);
+                }
+
+                let status = format!(
+                    "Round: {round}, empty ground tiles: {}",
+                    elves.empty_ground_tiles()
+                );
+                canvas.draw(&frame, status);
+            },
+        );
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Step {
+    dir: Direction,
+    checks: Vec<Direction>,
+}
+
+/// Positions of the elves, with the order in which they consider the directions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elves {
+    elves: HashSet<Point>,
+    steps: Vec<Step>,
+    start_step_idx: usize,
+}
+
+impl Elves {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        for line in lines(input) {
+            if let Some(i) = line.text.find(|c| c != '.' && c != '#') {
+                return Err(line.error(&line.text[i..], "`.` or `#`"));
+            }
+        }
+
+        let elves: HashSet<_> = input
+            .lines()
+            .rev()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.as_bytes()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &c)| c == b'#')
+                    .map(move |(x, _)| Point {
+                        x: x as i32,
+                        y: y as i32,
+                    })
+            })
+            .collect();
+
+        if elves.is_empty() {
+            return Err(ParseError::end_of_input(input, "an elf (`#`)"));
+        }
+
+        Ok(Self {
+            elves,
+            steps: vec![
+                Step {
+                    dir: Direction::N,
+                    checks: vec![Direction::N, Direction::NE, Direction::NW],
+                },
+                Step {
+                    dir: Direction::S,
+                    checks: vec![Direction::S, Direction::SE, Direction::SW],
+                },
+                Step {
+                    dir: Direction::W,
+                    checks: vec![Direction::W, Direction::NW, Direction::SW],
+                },
+                Step {
+                    dir: Direction::E,
+                    checks: vec![Direction::E, Direction::NE, Direction::SE],
+                },
+            ],
+            start_step_idx: 0,
+        })
+    }
+
+    /// Positions of the elves, the rows going up from the bottom of the scan
+    pub fn positions(&self) -> impl Iterator<Item = Point> + '_ {
+        self.elves.iter().copied()
+    }
+
+    /// Moves the elves which propose a position that no other elf proposes
+    pub fn do_round(&mut self) {
+        let mut movements = HashMap::with_capacity(self.elves.len());
+
+        for (cur_pos, proposed) in self
+            .elves
+            .iter()
+            .map(|&elf| (elf, self.propose_next_pos(elf)))
+        {
+            if let Some(prev_pos) = movements.remove(&proposed) {
+                movements.insert(cur_pos, cur_pos);
+                movements.insert(prev_pos, prev_pos);
+            } else {
+                movements.insert(proposed, cur_pos);
+            }
+        }
+
+        self.elves = movements.keys().copied().collect();
+        self.start_step_idx = (1 + self.start_step_idx) % self.steps.len();
+    }
+
+    /// Does a round, returning whether any elf moved
+    fn moving_round(&mut self) -> bool {
+        let before = self.fingerprint();
+        self.do_round();
+
+        before != self.fingerprint()
+    }
+
+    /// Hash of the positions of the elves, to compare them without a copy
+    fn fingerprint(&self) -> u64 {
+        cycle::hash_unordered(&self.elves)
+    }
+
+    fn bounds(&self) -> (Point, Point) {
+        Point::min_max(self.elves.iter().copied()).expect("should contain elves")
+    }
+
+    fn propose_next_pos(&self, elf: Point) -> Point {
+        if !self.has_neighboor(elf) {
+            return elf;
+        }
+
+        for step in self.possible_steps() {
+            let proposable = step
+                .checks
+                .iter()
+                .map(|&p| elf + p.into())
+                .all(|p| !self.elves.contains(&p));
+
+            if proposable {
+                return elf + step.dir.into();
+            }
+        }
+
+        elf
+    }
+
+    /// Number of the tiles without an elf in the smallest rectangle containing them all
+    pub fn empty_ground_tiles(&self) -> usize {
+        let (min, max) = self.bounds();
+
+        (min.y..=max.y)
+            .flat_map(|y| (min.x..=max.x).map(move |x| Point { x, y }))
+            .filter(|p| !self.elves.contains(p))
+            .count()
+    }
+
+    fn has_neighboor(&self, elf: Point) -> bool {
+        elf.neighbours8()
+            .any(|neighboor| self.elves.contains(&neighboor))
+    }
+
+    fn possible_steps(&self) -> impl Iterator<Item = &Step> {
+        let len = self.steps.len();
+        (0..len).map(move |i| &self.steps[(i + self.start_step_idx) % len])
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(val: Direction) -> Self {
+        match val {
+            Direction::N => Point { x: 0, y: 1 },
+            Direction::NE => Point { x: 1, y: 1 },
+            Direction::E => Point { x: 1, y: 0 },
+            Direction::SE => Point { x: 1, y: -1 },
+            Direction::S => Point { x: 0, y: -1 },
+            Direction::SW => Point { x: -1, y: -1 },
+            Direction::W => Point { x: -1, y: 0 },
+            Direction::NW => Point { x: -1, y: 1 },
+        }
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day23::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(110));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day23::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(20));
+    }
+
+    #[test]
+    fn animation() {
+        let day = Day23::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        let options = animation::Options {
+            fps: 0,
+            steps_per_frame: 1,
+        };
+        let mut canvas = Canvas::with_writer(Vec::new(), options);
+
+        day.animate(&input, Part::One, &mut canvas);
+
+        let output = String::from_utf8(canvas.into_writer()).unwrap();
+        assert!(output.ends_with("Round: 10, empty ground tiles: 110"));
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day23, 0..20, 20) {
+            panic!("{error}");
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use aoc_core::{Generate, Rng, Solution};
+
+use crate::{Day24, Map};
+
+impl Generate for Day24 {
+    /// Rows of the valley, which is three times wider than high
+    const SIZE: usize = 35;
+
+    /// Valley with blizzards on half of its ground. The columns of the entrance and of the exit
+    /// have no vertical blizzards, which would blow out of the valley, and neither does a valley
+    /// of a single row, in which they would never move out of the way. The small valleys often
+    /// have no way through, and are drawn again until they have one.
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let height = size.max(1);
+        let width = (height * 3).max(3);
+
+        loop {
+            let valley = valley(rng, width, height);
+            let map = self.parse(&valley).expect("the valley should be valid");
+            if round_trip(&map).is_some() {
+                return valley;
+            }
+        }
+    }
+}
+
+fn valley(rng: &mut Rng, width: usize, height: usize) -> String {
+    let mut lines = vec![format!("#.{}", "#".repeat(width))];
+    for _ in 0..height {
+        let row: String = (0..width)
+            .map(|x| {
+                let directions: &[char] = if x == 0 || x == width - 1 || height == 1 {
+                    &['<', '>']
+                } else {
+                    &['<', '>', '^', 'v']
+                };
+
+                if rng.chance(0.5) {
+                    *rng.pick(directions)
+                } else {
+                    '.'
+                }
+            })
+            .collect();
+
+        lines.push(format!("#{row}#"));
+    }
+    lines.push(format!("{}.#", "#".repeat(width)));
+
+    lines.join("\n")
+}
+
+/// Minutes to go to the exit, back to the entrance and to the exit again, as in part two
+fn round_trip(map: &Map) -> Option<usize> {
+    let there = map.shortest_way(map.start(), map.end(), 0)?;
+    let back = map.shortest_way(map.end(), map.start(), there)?;
+    let again = map.shortest_way(map.start(), map.end(), there + back)?;
+
+    Some(there + back + again)
+}
@@ -0,0 +1,409 @@
+//! Day 24 of 2022: Blizzard Basin.
+//!
+//! The input is the map of the valley, with its walls (`#`) and the blizzards blowing in it.
+//!
+//! ```
+//! use aoc_2022_day_24::Day24;
+//! use aoc_core::Solution;
+//!
+//! let map = Day24
+//!     .parse("#.######\n#>>.<^<#\n#.<..<<#\n#>v.><>#\n#<^v^^>#\n######.#")
+//!     .unwrap();
+//!
+//! assert_eq!(map.shortest_way(map.start(), map.end(), 0), Some(18));
+//! // Going back for the snacks
+//! assert_eq!(map.shortest_way(map.end(), map.start(), 18), Some(23));
+//! ```
+
+mod generate;
+
+use std::{
+    collections::{HashMap, HashSet},
+    io::Write,
+};
+
+use animation::{Animate, Canvas, Color, Glyph};
+use aoc_core::{parse::lines, Answer, ParseError, Part, Print, Solution};
+use geometry::Point;
+use grid::Grid;
+
+/// Solution of the day, with the map of the valley as input
+#[derive(Default)]
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Map;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Map::parse(input)
+    }
+
+    fn part_one(&self, map: &Self::Input) -> Answer {
+        let result = map
+            .shortest_way(map.start, map.end, 0)
+            .expect("could not find shortest way");
+
+        result.into()
+    }
+
+    fn part_two(&self, map: &Self::Input) -> Answer {
+        let first_trip = map
+            .shortest_way(map.start, map.end, 0)
+            .expect("could not find shortest way");
+
+        let second_trip = map
+            .shortest_way(map.end, map.start, first_trip)
+            .expect("could not find the way back");
+
+        let third_trip = map
+            .shortest_way(map.start, map.end, first_trip + second_trip)
+            .expect("could not find the way to the end again");
+
+        (first_trip + second_trip + third_trip).into()
+    }
+}
+
+impl Print for Day24 {
+    fn print(&self, map: &Self::Input) -> String {
+        let mut grid = Grid::with_bounds(Point::new(0, 0), map.max, '.');
+        for wall in &map.walls {
+            grid[*wall] = '#';
+        }
+        for blizzard in &map.blizzards {
+            grid[blizzard.pos] = match (blizzard.dir.x, blizzard.dir.y) {
+                (1, _) => '>',
+                (-1, _) => '<',
+                (_, 1) => '^',
+                _ => 'v',
+            };
+        }
+
+        let rows = grid.display_y_up().to_string();
+        rows.trim_end().to_owned()
+    }
+}
+
+impl Animate for Day24 {
+    fn animate<W: Write>(&self, map: &Self::Input, part: Part, canvas: &mut Canvas<W>) {
+        let trips = match part {
+            Part::One => 1,
+            Part::Two => 3,
+        };
+
+        let mut state = Trip {
+            map: map.clone(),
+            positions: HashSet::from([map.start]),
+            number: 1,
+            minutes: 0,
+        };
+
+        canvas.set_y_up(true);
+        canvas.run(
+            &mut state,
+            |trip| {
+                if trip.positions.contains(&trip.map.end) {
+                    if trip.number == trips {
+                        return false;
+                    }
+
+                    // Goes back the other way
+                    (trip.map.end, trip.map.start) = (trip.map.start, trip.map.end);
+                    trip.positions = HashSet::from([trip.map.start]);
+                    trip.number += 1;
+                    return true;
+                }
+
+                if trip.positions.is_empty() {
+                    return false;
+                }
+
+                trip.positions = trip.map.step(&trip.positions);
+                trip.minutes += 1;
+                true
+            },
+            |canvas, trip| {
+                let map = &trip.map;
+                let mut frame =
+                    Grid::with_bounds((0, 0).into(), map.max, Glyph::new('.', Color::Gray));
+
+                for wall in &map.walls {
+                    frame[*wall] = Glyph::new('#', Color::White);
+                }
+
+                let mut blizzards: HashMap<Point, Vec<Point>> = HashMap::new();
+                for blizzard in &map.blizzards {
+                    blizzards
+                        .entry(blizzard.pos)
+                        .or_default()
+                        .push(blizzard.dir);
+                }
+
+                for (pos, dirs) in &blizzards {
+                    let char = match dirs[..] {
+                        [Point { x: 1, y: 0 }] => '>',
+                        [Point { x: -1, y: 0 }] => '<',
+                        [Point { x: 0, y: 1 }] => '^',
+                        [Point { x: 0, y: -1 }] => 'v',
+                        _ => char::from_digit(dirs.len() as u32, 10).unwrap_or('*'),
+                    };
+                    frame[*pos] = Glyph::new(char, Color::Cyan);
+                }
+
+                for pos in &trip.positions {
+                    if !blizzards.contains_key(pos) {
+                        frame[*pos] = Glyph::new('E', Color::Yellow);
+                    }
+                }
+
+                let status = format!("Minute: {}, trip: {} of {trips}", trip.minutes, trip.number);
+                canvas.draw(&frame, status);
+            },
+        );
+    }
+}
+
+/// State of the animation: the expedition positions which can be reached during a trip
+struct Trip {
+    map: Map,
+    positions: HashSet<Point>,
+    number: usize,
+    minutes: usize,
+}
+
+/// Valley with the blizzards at their starting positions, the rows going up from the bottom
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Map {
+    blizzards: Vec<Blizzard>,
+    walls: HashSet<Point>,
+    start: Point,
+    end: Point,
+    max: Point,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Blizzard {
+    pos: Point,
+    dir: Point,
+}
+
+impl Map {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let first = lines(input)
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input, "a map"))?;
+        let width = first.text.len();
+
+        for line in lines(input) {
+            if let Some(i) = line.text.find(|c| !"#.<>^v".contains(c)) {
+                return Err(line.error(&line.text[i..], "`#`, `.` or a blizzard"));
+            }
+
+            if line.text.len() != width {
+                return Err(line.error_at_end(format!("a line of {} cells", width)));
+            }
+        }
+
+        let points = input.lines().rev().enumerate().flat_map(|(y, line)| {
+            line.as_bytes().iter().enumerate().map(move |(x, char)| {
+                (
+                    Point {
+                        x: x as i32,
+                        y: y as i32,
+                    },
+                    char,
+                )
+            })
+        });
+
+        let blizzards: Vec<_> = points
+            .clone()
+            .filter_map(|(pos, char)| {
+                let dir = match char {
+                    b'>' => (1, 0).into(),
+                    b'<' => (-1, 0).into(),
+                    b'^' => (0, 1).into(),
+                    b'v' => (0, -1).into(),
+                    _ => return None,
+                };
+
+                Some(Blizzard { pos, dir })
+            })
+            .collect();
+
+        let walls: HashSet<_> = points
+            .filter(|(_, char)| **char == b'#')
+            .map(|(pos, _)| pos)
+            .collect();
+
+        let width = width as i32;
+        let height = input.lines().count() as i32;
+
+        Ok(Self {
+            blizzards,
+            walls,
+            start: (1, height - 1).into(),
+            end: (width - 2, 0).into(),
+            max: (width - 1, height - 1).into(),
+        })
+    }
+
+    /// Entrance of the valley, in its top row
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    /// Exit of the valley, in its bottom row
+    pub fn end(&self) -> Point {
+        self.end
+    }
+
+    /// Minutes to go from `from` to `to` when leaving after `minute` minutes, searching the
+    /// positions at each minute (modulo the period of the blizzards)
+    pub fn shortest_way(&self, from: Point, to: Point, minute: usize) -> Option<usize> {
+        // Directions of the blizzards starting in each cell, as a bit per direction
+        let mut blizzards = Grid::with_bounds((0, 0).into(), self.max, 0u8);
+        for blizzard in &self.blizzards {
+            blizzards[blizzard.pos] |= direction_bit(blizzard.dir);
+        }
+        let blizzards = &blizzards;
+
+        let inner = self.max - (1, 1).into();
+        // The blizzards are back at their starting positions after this many minutes
+        let period = lcm(inner.x as usize, inner.y as usize);
+
+        let search = search::bfs(
+            [(from, minute % period)],
+            |&(pos, minute)| {
+                let next = (minute + 1) % period;
+
+                self.moves(pos)
+                    .filter(move |&pos| !self.blizzard_at(blizzards, pos, next))
+                    .map(move |pos| (pos, next))
+            },
+            |&(pos, _)| pos == to,
+        );
+
+        search.goal_cost()
+    }
+
+    /// Whether one of the starting `blizzards` is at `pos` after `minute` minutes
+    fn blizzard_at(&self, blizzards: &Grid<u8>, pos: Point, minute: usize) -> bool {
+        let inner = self.max - (1, 1).into();
+        if !(1..=inner.x).contains(&pos.x) || !(1..=inner.y).contains(&pos.y) {
+            return false;
+        }
+
+        [(1, 0), (-1, 0), (0, 1), (0, -1)].into_iter().any(|dir| {
+            let dir: Point = dir.into();
+            let start =
+                (pos - (1, 1).into() - dir * minute as i32).rem_euclid(inner) + (1, 1).into();
+
+            blizzards[start] & direction_bit(dir) != 0
+        })
+    }
+
+    /// Positions which can be reached in a minute from `pos`, including itself, ignoring the
+    /// blizzards
+    fn moves(&self, pos: Point) -> impl Iterator<Item = Point> + '_ {
+        [
+            Point { x: 0, y: 0 },
+            Point { x: 1, y: 0 },
+            Point { x: -1, y: 0 },
+            Point { x: 0, y: 1 },
+            Point { x: 0, y: -1 },
+        ]
+        .into_iter()
+        .map(move |dir| pos + dir)
+        .filter(|new_pos| !self.walls.contains(new_pos))
+        .filter(|new_pos| {
+            (0..=self.max.x).contains(&new_pos.x) && (0..=self.max.y).contains(&new_pos.y)
+        })
+    }
+
+    /// Spends a minute, returning the positions which can be reached from `positions`
+    fn step(&mut self, positions: &HashSet<Point>) -> HashSet<Point> {
+        let blizzards_pos: HashSet<Point> = self.blizzards.iter().map(|b| b.pos).collect();
+
+        let positions = positions
+            .iter()
+            .filter(|pos| !blizzards_pos.contains(pos))
+            .flat_map(|&pos| self.moves(pos))
+            .collect();
+
+        self.blizzards = self.move_blizzards();
+        positions
+    }
+
+    fn move_blizzards(&self) -> Vec<Blizzard> {
+        self.blizzards
+            .iter()
+            .map(|blizzard| Blizzard {
+                pos: (blizzard.pos - (1, 1).into() + blizzard.dir)
+                    .rem_euclid(self.max - (1, 1).into())
+                    + (1, 1).into(),
+                dir: blizzard.dir,
+            })
+            .collect()
+    }
+}
+
+fn direction_bit(dir: Point) -> u8 {
+    match (dir.x, dir.y) {
+        (1, _) => 1,
+        (-1, _) => 2,
+        (_, 1) => 4,
+        _ => 8,
+    }
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        (x, y) = (y, x % y);
+    }
+
+    a / x * b
+}
+
+// --- TESTS ---
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day24::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(18));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day24::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(54));
+    }
+
+    #[test]
+    fn animation() {
+        let day = Day24::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        let options = animation::Options {
+            fps: 0,
+            steps_per_frame: 1,
+        };
+        let mut canvas = Canvas::with_writer(Vec::new(), options);
+
+        day.animate(&input, Part::Two, &mut canvas);
+
+        let output = String::from_utf8(canvas.into_writer()).unwrap();
+        assert!(output.ends_with("Minute: 54, trip: 3 of 3"));
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day24, 0..20, 10) {
+            panic!("{error}");
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use aoc_core::{Generate, Rng};
+
+use crate::{Day25, SNAFU};
+
+impl Generate for Day25 {
+    /// Fuel requirements
+    const SIZE: usize = 120;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let numbers: Vec<String> = (0..size.max(1))
+            .map(|_| {
+                let digits = rng.range(1..=20) as u32;
+                let number = rng.range(1..=5_i64.pow(digits) / 2);
+
+                SNAFU::from_decimal(number as isize).0
+            })
+            .collect();
+
+        numbers.join("\n")
+    }
+}
@@ -0,0 +1,200 @@
+//! Day 25 of 2022: Full of Hot Air.
+//!
+//! The input is the list of the fuel requirements, in SNAFU: base 5 with the digits `=` (-2),
+//! `-` (-1), `0`, `1` and `2`.
+//!
+//! ```
+//! use aoc_2022_day_25::SNAFU;
+//!
+//! let snafu: SNAFU = "1=-0-2".parse().unwrap();
+//! assert_eq!(snafu.to_decimal(), 1747);
+//! assert_eq!(SNAFU::from_decimal(2022).to_string(), "1=11-2");
+//! ```
+
+mod generate;
+
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use aoc_core::{
+    parse::{lines, Line},
+    Answer, ParseError, Print, Solution,
+};
+
+/// Solution of the day, with the SNAFU numbers as input
+#[derive(Default)]
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<SNAFU>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        lines(input).map(|line| SNAFU::parse(&line)).collect()
+    }
+
+    fn part_one(&self, numbers: &Self::Input) -> Answer {
+        let fuel_requirement: isize = numbers.iter().map(SNAFU::to_decimal).sum();
+
+        SNAFU::from_decimal(fuel_requirement).0.into()
+    }
+
+    fn part_two(&self, _numbers: &Self::Input) -> Answer {
+        Answer::Unimplemented
+    }
+}
+
+impl Print for Day25 {
+    fn print(&self, numbers: &Self::Input) -> String {
+        let lines: Vec<&str> = numbers.iter().map(|number| number.0.as_str()).collect();
+
+        lines.join("\n")
+    }
+}
+
+/// Number in SNAFU, made of valid digits
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct SNAFU(String);
+
+impl SNAFU {
+    fn parse(line: &Line) -> Result<Self, ParseError> {
+        let snafu = line.text.trim();
+        if snafu.is_empty() {
+            return Err(line.error_at_end("a SNAFU number"));
+        }
+
+        if let Some(i) = snafu.find(|c| !"=-012".contains(c)) {
+            return Err(line.error(&snafu[i..], "a SNAFU digit"));
+        }
+
+        Ok(SNAFU(snafu.to_string()))
+    }
+
+    pub fn from_decimal(mut decimal: isize) -> Self {
+        let mut snafu = String::new();
+
+        loop {
+            let reminder = decimal % 5;
+
+            let digit = match reminder {
+                0..=2 => reminder.to_string(),
+                3 => "=".to_string(),
+                4 => "-".to_string(),
+                _ => panic!("invalid reminder"),
+            };
+
+            snafu = digit + &snafu;
+
+            if reminder > 2 {
+                decimal += 5;
+            }
+
+            decimal /= 5;
+
+            if decimal == 0 {
+                break;
+            }
+        }
+
+        SNAFU(snafu)
+    }
+
+    pub fn to_decimal(&self) -> isize {
+        self.0
+            .as_bytes()
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, digit)| {
+                SNAFU::digit_to_decimal(digit).expect("invalid SNAFU digit") * 5_isize.pow(i as u32)
+            })
+            .sum()
+    }
+
+    fn digit_to_decimal(digit: &u8) -> Option<isize> {
+        let decimal = match digit {
+            b'=' => -2,
+            b'-' => -1,
+            b'0' => 0,
+            b'1' => 1,
+            b'2' => 2,
+            _ => return None,
+        };
+
+        Some(decimal)
+    }
+}
+
+impl FromStr for SNAFU {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let line = lines(text)
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(text, "a SNAFU number"))?;
+
+        SNAFU::parse(&line)
+    }
+}
+
+impl Display for SNAFU {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day25::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Text("2=-1=0".to_owned()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day25::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Unimplemented);
+    }
+
+    #[test]
+    fn snafu() {
+        assert_eq!(SNAFU::from_decimal(0).0, "0");
+        assert_eq!(SNAFU::from_decimal(1).0, "1");
+        assert_eq!(SNAFU::from_decimal(2).0, "2");
+        assert_eq!(SNAFU::from_decimal(3).0, "1=");
+        assert_eq!(SNAFU::from_decimal(4).0, "1-");
+        assert_eq!(SNAFU::from_decimal(5).0, "10");
+
+        let snafu = SNAFU::from_decimal(314159265);
+        assert_eq!(snafu.0, "1121-1110-1=0");
+        assert_eq!(snafu.to_decimal(), 314159265);
+
+        let snafu = SNAFU::from_decimal(12345);
+        assert_eq!(snafu.0, "1-0---0");
+        assert_eq!(snafu.to_decimal(), 12345);
+
+        let snafu = SNAFU::from_decimal(2022);
+        assert_eq!(snafu.0, "1=11-2");
+        assert_eq!(snafu.to_decimal(), 2022);
+
+        let snafu = SNAFU::from_decimal(20);
+        assert_eq!(snafu.0, "1-0");
+        assert_eq!(snafu.to_decimal(), 20);
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day25, 0..20, 50) {
+            panic!("{error}");
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day3;
+
+impl Generate for Day3 {
+    /// Rucksacks, rounded up to a multiple of three
+    const SIZE: usize = 300;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+        let mut rucksacks = Vec::new();
+
+        for _ in 0..size.max(1).div_ceil(3) {
+            let badge = *rng.pick(&items);
+            // Every other item is missing from one of the elves, so the badge is the only item
+            // the three of them carry
+            let missing: Vec<usize> = items.iter().map(|_| rng.index(3)).collect();
+
+            for elf in 0..3 {
+                let carried: Vec<char> = items
+                    .iter()
+                    .zip(&missing)
+                    .filter(|(item, missing)| **item != badge && **missing != elf)
+                    .map(|(item, _)| *item)
+                    .collect();
+
+                let shared = if rng.chance(0.2) {
+                    badge
+                } else {
+                    *rng.pick(&carried)
+                };
+
+                // The other items are only in one of the compartments
+                let (mut first_items, mut second_items) = (Vec::new(), Vec::new());
+                for &item in carried
+                    .iter()
+                    .chain([&badge])
+                    .filter(|item| **item != shared)
+                {
+                    if rng.chance(0.5) {
+                        first_items.push(item);
+                    } else {
+                        second_items.push(item);
+                    }
+                }
+
+                let len = rng.range(4..=16) as usize;
+                let mut first = fill(rng, &first_items, shared, len);
+                let mut second = fill(rng, &second_items, shared, len);
+
+                if shared != badge {
+                    if first_items.contains(&badge) {
+                        first[0] = badge;
+                    } else {
+                        second[0] = badge;
+                    }
+                }
+
+                rng.shuffle(&mut first);
+                rng.shuffle(&mut second);
+                rucksacks.push(first.into_iter().chain(second).collect::<String>());
+            }
+        }
+
+        rucksacks.join("\n")
+    }
+}
+
+/// Compartment of `len` items, all from `items` but the last one which is `shared`
+fn fill(rng: &mut Rng, items: &[char], shared: char, len: usize) -> Vec<char> {
+    let mut compartment: Vec<char> = (1..len)
+        .map(|_| {
+            if items.is_empty() {
+                shared
+            } else {
+                *rng.pick(items)
+            }
+        })
+        .collect();
+    compartment.push(shared);
+
+    compartment
+}
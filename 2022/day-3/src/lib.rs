@@ -0,0 +1,124 @@
+//! Day 3 of 2022: Rucksack Reorganization.
+//!
+//! The input is the list of the items of each rucksack, as letters.
+//!
+//! ```
+//! use aoc_2022_day_3::{get_rucksack_duplicate, to_priority};
+//!
+//! let item = get_rucksack_duplicate("vJrwpWtwJgWrhcsFMMfFFhFp").unwrap();
+//! assert_eq!((item, to_priority(&item)), ('p', 16));
+//! ```
+
+mod generate;
+
+use std::collections::HashSet;
+
+use aoc_core::{parse::lines, Answer, ParseError, Print, Solution};
+
+/// Solution of the day, with the rucksacks as input
+#[derive(Default)]
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        lines(input)
+            .map(
+                |line| match line.text.find(|c: char| !c.is_ascii_alphabetic()) {
+                    Some(i) => Err(line.error(&line.text[i..], "an item (a letter)")),
+                    None => Ok(line.text.to_owned()),
+                },
+            )
+            .collect()
+    }
+
+    fn part_one(&self, rucksacks: &Self::Input) -> Answer {
+        rucksacks
+            .iter()
+            .filter_map(|line| get_rucksack_duplicate(line))
+            .map(|c| to_priority(&c))
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part_two(&self, rucksacks: &Self::Input) -> Answer {
+        rucksacks
+            .chunks(3)
+            .filter_map(get_group_duplicate)
+            .map(|c| to_priority(&c))
+            .sum::<usize>()
+            .into()
+    }
+}
+
+impl Print for Day3 {
+    fn print(&self, rucksacks: &Self::Input) -> String {
+        rucksacks.join("\n")
+    }
+}
+
+/// Priority of an item: 1 to 26 for `a` to `z`, 27 to 52 for `A` to `Z`
+pub fn to_priority(c: &char) -> usize {
+    let n = *c as u8;
+    let result = if n <= b'Z' {
+        n - b'A' + 27
+    } else {
+        n - b'a' + 1
+    };
+
+    result as usize
+}
+
+/// Item in both compartments (halves) of a rucksack
+pub fn get_rucksack_duplicate(rucksack: &str) -> Option<char> {
+    let (first_rucksack, second_rucksack) = rucksack.split_at(rucksack.len() / 2);
+    let first_set: HashSet<char> = HashSet::from_iter(first_rucksack.chars());
+    let second_set = HashSet::from_iter(second_rucksack.chars());
+
+    first_set.intersection(&second_set).next().cloned()
+}
+
+/// Item in every rucksack of a group, its badge
+pub fn get_group_duplicate(rucksacks: &[String]) -> Option<char> {
+    if rucksacks.is_empty() {
+        return None;
+    }
+
+    let mut duplicates: HashSet<char> = HashSet::from_iter(rucksacks.first().unwrap().chars());
+
+    for rucksack in rucksacks.iter().skip(1) {
+        let set = HashSet::from_iter(rucksack.chars().collect::<Vec<char>>());
+        duplicates = HashSet::from_iter(duplicates.intersection(&set).copied());
+    }
+
+    duplicates.into_iter().next()
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day3::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(157));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day3::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(70));
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day3, 0..20, 30) {
+            panic!("{error}");
+        }
+    }
+}
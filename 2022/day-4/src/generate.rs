@@ -0,0 +1,23 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day4;
+
+impl Generate for Day4 {
+    /// Pairs of elves
+    const SIZE: usize = 1000;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let mut range = || {
+            let start = rng.range(1..=99);
+            let end = rng.range(start..=99);
+
+            format!("{start}-{end}")
+        };
+
+        let pairs: Vec<String> = (0..size.max(1))
+            .map(|_| format!("{},{}", range(), range()))
+            .collect();
+
+        pairs.join("\n")
+    }
+}
@@ -0,0 +1,128 @@
+//! Day 4 of 2022: Camp Cleanup.
+//!
+//! The input is the list of the pairs of elves, with the sections assigned to each of them.
+//!
+//! ```
+//! use aoc_2022_day_4::Sections;
+//!
+//! let (first, second) = (Sections::from(2..=8), Sections::from(3..=7));
+//! assert!(first.is_superset(&second));
+//! assert!(!second.is_disjoint(&Sections::from(7..=9)));
+//! ```
+
+mod generate;
+
+use aoc_core::{
+    parse::{lines, Line},
+    Answer, ParseError, Print, Solution,
+};
+use intervals::IntervalSet;
+
+/// Solution of the day, with the pairs of assignments as input
+#[derive(Default)]
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<(Sections, Sections)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        map_to_ranges(input)
+    }
+
+    fn part_one(&self, ranges: &Self::Input) -> Answer {
+        ranges
+            .iter()
+            .filter(|(sections1, sections2)| {
+                sections1.is_superset(sections2) || sections2.is_superset(sections1)
+            })
+            .count()
+            .into()
+    }
+
+    fn part_two(&self, ranges: &Self::Input) -> Answer {
+        ranges
+            .iter()
+            .filter(|(sections1, sections2)| !sections1.is_disjoint(sections2))
+            .count()
+            .into()
+    }
+}
+
+impl Print for Day4 {
+    fn print(&self, ranges: &Self::Input) -> String {
+        let lines: Vec<String> = ranges
+            .iter()
+            .map(|(first, second)| [first, second].map(print_sections).join(","))
+            .collect();
+
+        lines.join("\n")
+    }
+}
+
+fn map_to_ranges(input: &str) -> Result<Vec<(Sections, Sections)>, ParseError> {
+    lines(input)
+        .map(|line| {
+            let (first, second) = line.split_once(line.text, ",")?;
+            Ok((
+                parse_sections(&line, first)?,
+                parse_sections(&line, second)?,
+            ))
+        })
+        .collect()
+}
+
+/// Sections assigned to an elf, a single range
+pub type Sections = IntervalSet<usize>;
+
+fn parse_sections(line: &Line, range: &str) -> Result<Sections, ParseError> {
+    let (first, second) = line.split_once(range, "-")?;
+    let start: usize = line.parse(first, "a section number")?;
+    let end: usize = line.parse(second, "a section number")?;
+
+    if end < start {
+        return Err(line.error(second, "a section number not below the first one"));
+    }
+
+    Ok(Sections::from(start..=end))
+}
+
+fn print_sections(sections: &Sections) -> String {
+    let range = sections.span().expect("sections should not be empty");
+
+    format!("{}-{}", range.start(), range.end())
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day4::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day4::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(4));
+    }
+
+    #[test]
+    fn invalid_sections() {
+        let error = Day4.parse("2-4,8-6").expect_err("input should be invalid");
+        assert_eq!((error.line, error.column), (1, 7));
+        assert_eq!(error.expected, "a section number not below the first one");
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day4, 0..20, 50) {
+            panic!("{error}");
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day5;
+
+/// The stacks are numbered by a single digit
+const STACKS: usize = 9;
+
+impl Generate for Day5 {
+    /// Moves
+    const SIZE: usize = 500;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let mut stacks: Vec<usize> = (0..STACKS).map(|_| rng.range(0..=8) as usize).collect();
+        stacks[rng.index(STACKS)] += 1;
+
+        let height = *stacks.iter().max().unwrap();
+        let mut lines: Vec<String> = (0..height)
+            .rev()
+            .map(|level| {
+                let crates: Vec<String> = stacks
+                    .iter()
+                    .map(|&stack| {
+                        if level < stack {
+                            format!("[{}]", (b'A' + rng.index(26) as u8) as char)
+                        } else {
+                            "   ".to_owned()
+                        }
+                    })
+                    .collect();
+
+                crates.join(" ")
+            })
+            .collect();
+
+        let numbers: Vec<String> = (1..=STACKS).map(|n| format!(" {n} ")).collect();
+        lines.push(numbers.join(" "));
+        lines.push(String::new());
+
+        for _ in 0..size.max(1) {
+            let non_empty: Vec<usize> = (0..STACKS).filter(|&i| stacks[i] > 0).collect();
+            let from = *rng.pick(&non_empty);
+            let to = (from + 1 + rng.index(STACKS - 1)) % STACKS;
+            let amount = rng.range(1..=stacks[from] as i64) as usize;
+
+            stacks[from] -= amount;
+            stacks[to] += amount;
+            lines.push(format!("move {amount} from {} to {}", from + 1, to + 1));
+        }
+
+        lines.join("\n")
+    }
+}
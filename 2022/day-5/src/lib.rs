@@ -0,0 +1,247 @@
+//! Day 5 of 2022: Supply Stacks.
+//!
+//! The input is the stacks of crates, from bottom to top, with the instructions of the crane.
+//!
+//! ```
+//! use aoc_2022_day_5::{top_crates, Instruction};
+//!
+//! let mut stacks = vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']];
+//! Instruction { amount: 2, from: 1, to: 0 }.apply_part_one(&mut stacks);
+//! assert_eq!(top_crates(&stacks), "CMP");
+//! ```
+
+mod generate;
+
+use std::{
+    cmp::max,
+    fmt::{self, Display},
+    iter,
+};
+
+use aoc_core::{parse::lines, Answer, ParseError, Print, Solution};
+use regex::Regex;
+
+/// Solution of the day, with the stacks and the instructions as input
+#[derive(Default)]
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (Vec<Vec<char>>, Vec<Instruction>);
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_stacks_and_instructions(input)
+    }
+
+    fn part_one(&self, (stacks, instructions): &Self::Input) -> Answer {
+        let mut stacks = stacks.clone();
+
+        for instruction in instructions {
+            instruction.apply_part_one(&mut stacks);
+        }
+
+        top_crates(&stacks).into()
+    }
+
+    fn part_two(&self, (stacks, instructions): &Self::Input) -> Answer {
+        let mut stacks = stacks.clone();
+
+        for instruction in instructions {
+            instruction.apply_part_two(&mut stacks);
+        }
+
+        top_crates(&stacks).into()
+    }
+}
+
+impl Print for Day5 {
+    fn print(&self, (stacks, instructions): &Self::Input) -> String {
+        let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+
+        let mut lines: Vec<String> = (0..height)
+            .rev()
+            .map(|level| {
+                let crates: Vec<String> = stacks
+                    .iter()
+                    .map(|stack| match stack.get(level) {
+                        Some(c) => format!("[{c}]"),
+                        None => "   ".to_owned(),
+                    })
+                    .collect();
+
+                crates.join(" ")
+            })
+            .collect();
+
+        let labels: Vec<String> = (1..=stacks.len()).map(|n| format!(" {n} ")).collect();
+        lines.push(labels.join(" "));
+        lines.push(String::new());
+
+        lines.extend(instructions.iter().map(Instruction::to_string));
+        lines.join("\n")
+    }
+}
+
+/// Crates at the top of each stack, skipping the empty ones
+pub fn top_crates(stacks: &[Vec<char>]) -> String {
+    stacks
+        .iter()
+        .filter_map(|stack| stack.last())
+        .cloned()
+        .collect()
+}
+
+fn parse_stacks_and_instructions(
+    input: &str,
+) -> Result<(Vec<Vec<char>>, Vec<Instruction>), ParseError> {
+    let lines: Vec<_> = lines(input).collect();
+    let Some(blank_line_idx) = lines.iter().position(|line| line.text.is_empty()) else {
+        return Err(ParseError::end_of_input(
+            input,
+            "a blank line separating the stacks and the instructions",
+        ));
+    };
+
+    if blank_line_idx == 0 {
+        return Err(ParseError::new(
+            1,
+            1,
+            "the stacks before the first blank line",
+        ));
+    }
+
+    let stacks_label_idx = blank_line_idx - 1;
+    let stacks_label = lines[stacks_label_idx].text;
+    let stacks_idxs: Vec<usize> = stacks_label
+        .chars()
+        .map(|c| c as u8)
+        .enumerate()
+        .filter(|(_, c)| *c >= b'1' && *c <= b'9')
+        .map(|(i, _)| i)
+        .collect();
+
+    let mut stacks: Vec<Vec<char>> = iter::repeat_with(Vec::new)
+        .take(stacks_idxs.len())
+        .collect();
+
+    for line in lines
+        .iter()
+        .take(stacks_label_idx)
+        .rev()
+        .map(|line| line.text.as_bytes())
+    {
+        for (stack_idx, line_idx) in stacks_idxs.iter().enumerate() {
+            let stack_char = line.get(*line_idx).copied().unwrap_or(b' ');
+            if stack_char != b' ' {
+                stacks[stack_idx].push(char::from(stack_char));
+            }
+        }
+    }
+
+    let instruction_regex = Regex::new(r"\D*(\d+)\D*(\d+)\D*(\d+)").expect("Invalid regex");
+    let instructions = lines
+        .iter()
+        .skip(blank_line_idx + 1)
+        .map(|line| {
+            let group = instruction_regex
+                .captures(line.text)
+                .ok_or_else(|| line.error(line.text, "`move <amount> from <stack> to <stack>`"))?;
+
+            let stack =
+                |i| {
+                    let number = &line.text[group.get(i).unwrap().range()];
+                    match line.parse::<usize>(number, "a stack number")? {
+                        n @ 1.. if n <= stacks.len() => Ok(n - 1),
+                        _ => Err(line
+                            .error(number, format!("a stack number from 1 to {}", stacks.len()))),
+                    }
+                };
+
+            Ok(Instruction {
+                amount: line.parse(
+                    &line.text[group.get(1).unwrap().range()],
+                    "an amount of crates",
+                )?,
+                from: stack(2)?,
+                to: stack(3)?,
+            })
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    Ok((stacks, instructions))
+}
+
+/// Move of crates between two stacks
+#[derive(Debug, PartialEq, Eq)]
+pub struct Instruction {
+    /// Number of crates moved
+    pub amount: usize,
+    /// Index of the stack (from 0) the crates are taken from
+    pub from: usize,
+    /// Index of the stack (from 0) the crates are put on
+    pub to: usize,
+}
+
+impl Instruction {
+    /// Moves the crates one at a time, which reverses their order
+    pub fn apply_part_one(&self, stacks: &mut [Vec<char>]) {
+        self.apply(stacks, true)
+    }
+
+    /// Moves the crates all at once
+    pub fn apply_part_two(&self, stacks: &mut [Vec<char>]) {
+        self.apply(stacks, false)
+    }
+
+    fn apply(&self, stacks: &mut [Vec<char>], reverse: bool) {
+        let (remain, moved) =
+            stacks[self.from].split_at(max(0, stacks[self.from].len() - self.amount));
+
+        let mut new: Vec<char> = moved.to_vec();
+        if reverse {
+            new.reverse();
+        }
+
+        stacks[self.from] = remain.to_vec();
+        stacks[self.to].append(&mut new);
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.amount,
+            self.from + 1,
+            self.to + 1
+        )
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day5::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Text("CMZ".to_owned()));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day5::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Text("MCD".to_owned()));
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day5, 0..20, 40) {
+            panic!("{error}");
+        }
+    }
+}
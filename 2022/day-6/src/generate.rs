@@ -0,0 +1,52 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day6;
+
+/// Characters of the start-of-message marker
+const MESSAGE_MARKER: usize = 14;
+
+impl Generate for Day6 {
+    /// Characters of the datastream
+    const SIZE: usize = 4096;
+
+    /// The start-of-message marker ends the stream: the characters before its last one are taken
+    /// from 13 letters only
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let mut letters: Vec<char> = ('a'..='z').collect();
+        rng.shuffle(&mut letters);
+        let mut used = letters[..MESSAGE_MARKER - 1].to_vec();
+
+        // Starts with a start-of-packet marker
+        let len = size.max(MESSAGE_MARKER + 4) - MESSAGE_MARKER;
+        let mut stream: String = used[..4].iter().collect();
+        stream.extend((4..len).map(|_| *rng.pick(&used)));
+
+        rng.shuffle(&mut used);
+        stream.extend(used);
+        stream.push(letters[MESSAGE_MARKER - 1]);
+
+        stream
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use aoc_core::{Answer, Solution};
+
+    use super::*;
+
+    #[test]
+    fn marker_at_the_end() {
+        let day = Day6;
+
+        for seed in 0..20 {
+            let input = day.generate(&mut Rng::new(seed), 100);
+            assert_eq!(input.len(), 100);
+
+            let input = day.parse(&input).unwrap();
+            assert_eq!(day.part_two(&input), Answer::Number(100));
+        }
+    }
+}
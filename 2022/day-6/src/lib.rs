@@ -0,0 +1,97 @@
+//! Day 6 of 2022: Tuning Trouble.
+//!
+//! The input is the datastream received by the device.
+//!
+//! ```
+//! use aoc_2022_day_6::distinct_window;
+//!
+//! assert_eq!(distinct_window("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 4), ("jpqm", 7));
+//! ```
+
+mod generate;
+
+use std::str;
+
+use aoc_core::{parse::lines, Answer, ParseError, Print, Solution};
+
+/// Solution of the day, with the datastream as input
+#[derive(Default)]
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let line = lines(input)
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input, "a datastream"))?;
+
+        Ok(line.text.trim().to_owned())
+    }
+
+    fn part_one(&self, datastream: &Self::Input) -> Answer {
+        let (_, result) = distinct_window(datastream, 4);
+        result.into()
+    }
+
+    fn part_two(&self, datastream: &Self::Input) -> Answer {
+        let (_, result) = distinct_window(datastream, 14);
+        result.into()
+    }
+}
+
+impl Print for Day6 {
+    fn print(&self, datastream: &Self::Input) -> String {
+        datastream.clone()
+    }
+}
+
+/// First window of `size` different characters in `string`, with the number of characters read
+/// until its end (or the last window and the length of `string` if there is none)
+pub fn distinct_window(string: &str, size: usize) -> (&str, usize) {
+    let datastream = string.as_bytes();
+
+    let mut start = 0;
+    let mut window = &datastream[start..0];
+
+    for end in 1..datastream.len() {
+        if let Some(i) = window.iter().position(|c| c == &datastream[end]) {
+            start += i + 1;
+        };
+
+        window = &datastream[start..end + 1];
+        if window.len() == size {
+            return (str::from_utf8(window).unwrap(), end + 1);
+        }
+    }
+
+    (str::from_utf8(window).unwrap(), datastream.len())
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day6::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(7));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day6::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(19));
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day6, 0..20, 100) {
+            panic!("{error}");
+        }
+    }
+}
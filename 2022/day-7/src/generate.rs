@@ -0,0 +1,84 @@
+use std::collections::HashSet;
+
+use aoc_core::{Generate, Rng};
+
+use crate::Day7;
+
+/// Space used on the disk, between the 40000000 that must be left unused and the 70000000 of the
+/// disk, so a directory has to be deleted
+const USED_SPACE: u64 = 55_000_000;
+
+impl Generate for Day7 {
+    /// Directories
+    const SIZE: usize = 200;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let dirs = size.max(1);
+        let mut children: Vec<Vec<usize>> = vec![Vec::new(); dirs];
+        for dir in 1..dirs {
+            children[rng.index(dir)].push(dir);
+        }
+
+        let mut files: Vec<Vec<u64>> = (0..dirs)
+            .map(|_| {
+                (0..rng.range(0..=5))
+                    .map(|_| rng.range(1..=400_000) as u64)
+                    .collect()
+            })
+            .collect();
+        files[0].push(rng.range(1..=400_000) as u64);
+
+        // Scales the files to fill the disk
+        let total: u64 = files.iter().flatten().sum();
+        for size in files.iter_mut().flatten() {
+            *size = (*size * USED_SPACE / total).max(1);
+        }
+
+        let mut output = vec!["$ cd /".to_owned()];
+        list(rng, 0, &children, &files, &mut output);
+
+        output.join("\n")
+    }
+}
+
+/// Lists `dir` and its subdirectories, in which it moves with `cd`
+fn list(
+    rng: &mut Rng,
+    dir: usize,
+    children: &[Vec<usize>],
+    files: &[Vec<u64>],
+    output: &mut Vec<String>,
+) {
+    let mut names = HashSet::new();
+    let mut name = |rng: &mut Rng| loop {
+        let len = rng.range(1..=8);
+        let name: String = (0..len)
+            .map(|_| (b'a' + rng.index(26) as u8) as char)
+            .collect();
+
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+
+    let dir_names: Vec<String> = children[dir].iter().map(|_| name(rng)).collect();
+
+    output.push("$ ls".to_owned());
+    for dir_name in &dir_names {
+        output.push(format!("dir {dir_name}"));
+    }
+    for size in &files[dir] {
+        let mut file_name = name(rng);
+        if rng.chance(0.5) {
+            file_name += *rng.pick(&[".txt", ".dat", ".lst", ".log"]);
+        }
+
+        output.push(format!("{size} {file_name}"));
+    }
+
+    for (&child, dir_name) in children[dir].iter().zip(&dir_names) {
+        output.push(format!("$ cd {dir_name}"));
+        list(rng, child, children, files, output);
+        output.push("$ cd ..".to_owned());
+    }
+}
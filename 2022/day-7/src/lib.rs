@@ -0,0 +1,354 @@
+//! Day 7 of 2022: No Space Left On Device.
+//!
+//! The input is the filesystem explored by the commands of the terminal output.
+//!
+//! ```
+//! use aoc_2022_day_7::{File, Filesystem};
+//!
+//! let mut fs = Filesystem::new();
+//! fs.new_directory("a");
+//! fs.cd("a");
+//! fs.new_file(File { name: "b.txt".to_owned(), size: 100 });
+//! fs.cd("/");
+//! fs.new_file(File { name: "c.txt".to_owned(), size: 50 });
+//!
+//! assert_eq!(fs.total_size(), 150);
+//! assert_eq!(fs.directory_sizes(), [100]);
+//! ```
+
+mod generate;
+
+use std::{
+    cell::RefCell,
+    fmt::{self, Error, Formatter},
+    rc::Rc,
+};
+
+use aoc_core::{
+    parse::{lines, Line},
+    Answer, ParseError, Print, Solution,
+};
+
+/// Solution of the day, with the explored filesystem as input
+#[derive(Default)]
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Filesystem;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_fs(input)
+    }
+
+    fn part_one(&self, fs: &Self::Input) -> Answer {
+        const MAX_SIZE: usize = 100000;
+
+        fs.directory_sizes()
+            .into_iter()
+            .filter(|&size| size < MAX_SIZE)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part_two(&self, fs: &Self::Input) -> Answer {
+        let unused_space_required = 40000000;
+
+        let total_size = fs.total_size();
+        // Nothing has to be deleted when there is already enough unused space
+        let Some(min_size) = total_size.checked_sub(unused_space_required) else {
+            return 0.into();
+        };
+
+        let solution = fs
+            .directory_sizes()
+            .into_iter()
+            .filter(|&size| size > min_size)
+            .min();
+
+        solution.unwrap_or(total_size).into()
+    }
+}
+
+impl Print for Day7 {
+    /// Prints the commands listing every directory, from the root
+    fn print(&self, fs: &Self::Input) -> String {
+        let mut lines = vec!["$ cd /".to_owned()];
+        fs.root.borrow().print(&mut lines);
+
+        lines.join("\n")
+    }
+}
+
+fn parse_fs(input: &str) -> Result<Filesystem, ParseError> {
+    let mut fs = Filesystem::new();
+
+    for line in lines(input) {
+        if let Some(command) = Command::from(line.text) {
+            match command {
+                Command::Cd(dir_name) => fs.cd(&dir_name),
+                Command::Ls => (),
+            }
+        } else if line.text.starts_with('$') {
+            return Err(line.error(line.text, "`$ cd <dir>` or `$ ls`"));
+        } else {
+            match parse_ls_line(&line)? {
+                LsResult::File(file) => fs.new_file(file),
+                LsResult::DirName(dir_name) => {
+                    fs.new_directory(&dir_name);
+                }
+            }
+        }
+    }
+
+    Ok(fs)
+}
+
+/// Tree of directories and files, with a current directory in which they are created
+#[derive(Debug)]
+pub struct Filesystem {
+    root: DirectoryRef,
+    cur_dir: DirectoryRef,
+}
+
+/// Filesystems are equal when they have the same tree, wherever their current directories are
+impl PartialEq for Filesystem {
+    fn eq(&self, other: &Self) -> bool {
+        self.root == other.root
+    }
+}
+
+impl Default for Filesystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Filesystem {
+    /// Empty filesystem, the current directory being the root
+    pub fn new() -> Self {
+        let root = Rc::new(RefCell::new(Directory::new("/".to_owned(), None)));
+        Filesystem {
+            cur_dir: root.clone(),
+            root,
+        }
+    }
+
+    /// Moves to a directory of the current one, to its parent with `..` or to the root with `/`
+    pub fn cd(&mut self, dest: &str) {
+        let new_dir = match dest {
+            ".." => self.cur_dir.borrow().parent.clone(),
+            "/" => Some(self.root.clone()),
+            _ => self
+                .cur_dir
+                .borrow()
+                .dirs
+                .iter()
+                .find(|dir| dir.borrow().name == dest)
+                .cloned(),
+        };
+
+        if let Some(dir) = new_dir {
+            self.cur_dir = dir
+        }
+    }
+
+    /// Adds a file to the current directory
+    pub fn new_file(&self, file: File) {
+        self.cur_dir.borrow_mut().files.push(file);
+    }
+
+    /// Adds an empty directory to the current directory
+    pub fn new_directory(&mut self, name: &str) {
+        let new_dir = Rc::new(RefCell::new(Directory::new(
+            name.to_owned(),
+            Some(self.cur_dir.clone()),
+        )));
+        self.cur_dir.borrow_mut().dirs.push(new_dir);
+    }
+
+    /// Size of all the files
+    pub fn total_size(&self) -> usize {
+        self.root.borrow().size()
+    }
+
+    /// Total size of the files of each directory and of its subdirectories, except the root
+    pub fn directory_sizes(&self) -> Vec<usize> {
+        let mut sizes = Vec::new();
+        self.root
+            .borrow()
+            .foreach_child(&mut |child| sizes.push(child.size()));
+
+        sizes
+    }
+}
+
+enum LsResult {
+    File(File),
+    DirName(String),
+}
+
+fn parse_ls_line(line: &Line) -> Result<LsResult, ParseError> {
+    let (first, name) = line.split_once(line.text, " ")?;
+
+    Ok(match first {
+        "dir" => LsResult::DirName(name.to_owned()),
+        size => LsResult::File(File {
+            name: name.to_owned(),
+            size: line.parse(size, "`dir` or a file size")?,
+        }),
+    })
+}
+
+enum Command {
+    Ls,
+    Cd(String),
+}
+
+impl Command {
+    fn from(line: &str) -> Option<Command> {
+        if !line.starts_with('$') {
+            return None;
+        }
+
+        let mut args = line
+            .split_terminator(&[' ', '$'][..])
+            .filter(|str| !str.is_empty());
+        let cmd = args.next()?;
+
+        match cmd {
+            "ls" => Some(Command::Ls),
+            "cd" => args.next().map(|dir| Command::Cd(dir.to_owned())),
+            _ => None,
+        }
+    }
+}
+
+/// File listed by `ls`
+#[derive(Debug, PartialEq)]
+pub struct File {
+    pub name: String,
+    pub size: usize,
+}
+
+impl Sizeable for File {
+    fn size(&self) -> usize {
+        self.size
+    }
+}
+
+type DirectoryRef = Rc<RefCell<Directory>>;
+
+struct Directory {
+    name: String,
+    files: Vec<File>,
+    dirs: Vec<DirectoryRef>,
+    parent: Option<DirectoryRef>,
+}
+
+impl Sizeable for Directory {
+    fn size(&self) -> usize {
+        self.files.iter().map(|file| file.size()).sum::<usize>()
+            + self
+                .dirs
+                .iter()
+                .map(|dir| dir.borrow().size())
+                .sum::<usize>()
+    }
+}
+
+impl Directory {
+    fn new(name: String, parent: Option<Rc<RefCell<Directory>>>) -> Self {
+        Directory {
+            name,
+            parent,
+            files: vec![],
+            dirs: vec![],
+        }
+    }
+
+    /// Adds the commands listing the directory and its subdirectories to `lines`
+    fn print(&self, lines: &mut Vec<String>) {
+        lines.push("$ ls".to_owned());
+        lines.extend(
+            self.dirs
+                .iter()
+                .map(|dir| format!("dir {}", dir.borrow().name)),
+        );
+        lines.extend(
+            self.files
+                .iter()
+                .map(|file| format!("{} {}", file.size, file.name)),
+        );
+
+        for dir in &self.dirs {
+            lines.push(format!("$ cd {}", dir.borrow().name));
+            dir.borrow().print(lines);
+            lines.push("$ cd ..".to_owned());
+        }
+    }
+
+    fn foreach_child<F: FnMut(&Directory)>(&self, f: &mut F) {
+        for child in &self.dirs {
+            f(&child.borrow());
+            child.borrow().foreach_child(f);
+        }
+    }
+}
+
+impl PartialEq for Directory {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && self.files == other.files && self.dirs == other.dirs
+    }
+}
+
+impl fmt::Debug for Directory {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> Result<(), Error> {
+        fmt.debug_struct("Directory")
+            .field("name", &self.name)
+            .field("files", &self.files)
+            .field("dirs", &self.dirs);
+
+        Ok(())
+    }
+}
+
+trait Sizeable {
+    fn size(&self) -> usize;
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day7::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(95437));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day7::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(24933642));
+    }
+
+    #[test]
+    fn enough_space() {
+        let day = Day7::example();
+        assert_eq!(day.part_two(&day.parse("").unwrap()), Answer::Number(0));
+
+        let input = day.parse("$ cd /\n$ ls\n1000 a.txt").unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(0));
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day7, 0..20, 30) {
+            panic!("{error}");
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day8;
+
+impl Generate for Day8 {
+    /// Side of the square of trees
+    const SIZE: usize = 99;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        // Part two skips the trees on the edges
+        let side = size.max(3);
+        let rows: Vec<String> = (0..side)
+            .map(|_| {
+                (0..side)
+                    .map(|_| (b'0' + rng.index(10) as u8) as char)
+                    .collect()
+            })
+            .collect();
+
+        rows.join("\n")
+    }
+}
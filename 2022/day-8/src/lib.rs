@@ -0,0 +1,266 @@
+//! Day 8 of 2022: Treetop Tree House.
+//!
+//! The input is the grid of the heights of the trees, from 0 to 9.
+//!
+//! ```
+//! use aoc_2022_day_8::{get_blocked_trees, get_scenic_scores, Day8};
+//! use aoc_core::Solution;
+//! use geometry::Point;
+//!
+//! let trees = Day8.parse("30373\n25512\n65332\n33549\n35390").unwrap();
+//! assert_eq!(get_blocked_trees(&trees).values().filter(|b| !**b).count(), 21);
+//! assert_eq!(get_scenic_scores(&trees)[Point::new(2, 3)], 8);
+//! ```
+
+mod generate;
+
+use aoc_core::{parse::lines, Answer, ParseError, Part, Print, Solution};
+use geometry::Point;
+use grid::Grid;
+use itertools::Either;
+use raster::{Image, Render, Rgb};
+
+/// Solution of the day, with the heights of the trees as input
+#[derive(Default)]
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Grid<u8>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_trees(input)
+    }
+
+    fn part_one(&self, trees: &Self::Input) -> Answer {
+        let blocked = get_blocked_trees(trees);
+
+        blocked.values().filter(|b| !**b).count().into()
+    }
+
+    fn part_two(&self, trees: &Self::Input) -> Answer {
+        let scenic_scores = get_scenic_scores(trees);
+
+        scenic_scores
+            .values()
+            .copied()
+            .max()
+            .expect("should contain max value")
+            .into()
+    }
+}
+
+impl Print for Day8 {
+    fn print(&self, trees: &Self::Input) -> String {
+        trees.to_string().trim_end().to_owned()
+    }
+}
+
+impl Render for Day8 {
+    /// Part one shows the visible trees in green, part two the scenic scores as a heatmap (on a
+    /// logarithmic scale, as a few trees have much larger scores than the others)
+    fn render(&self, trees: &Self::Input, part: Part) -> Image {
+        match part {
+            Part::One => {
+                let blocked = get_blocked_trees(trees);
+                let mut image = Image::from_grid(trees, |&height| Rgb::gray(20 + height * 12));
+
+                for (point, _) in blocked.iter().filter(|(_, blocked)| !**blocked) {
+                    let height = trees[point];
+                    image.set_cell(trees, point, Rgb(30, 100 + height * 15, 40));
+                }
+
+                image
+            }
+            Part::Two => {
+                let scenic_scores = get_scenic_scores(trees);
+                let max = scenic_scores.values().copied().max().unwrap_or(0);
+                let scale = (max as f64).ln_1p();
+
+                Image::from_grid(&scenic_scores, |&score| {
+                    Rgb::heat((score as f64).ln_1p() / scale)
+                })
+            }
+        }
+    }
+}
+
+/// Whether each tree is hidden by taller or equal trees from every side of the grid
+pub fn get_blocked_trees(trees: &Grid<u8>) -> Grid<bool> {
+    let mut blocked = Grid::new(trees.width(), trees.height(), true);
+
+    scan_blocked(trees, &mut blocked, ScanDirection::TopLeft);
+    scan_blocked(trees, &mut blocked, ScanDirection::BottomRight);
+
+    blocked
+}
+
+enum ScanDirection {
+    TopLeft,
+    BottomRight,
+}
+
+fn scan_blocked(trees: &Grid<u8>, blocked: &mut Grid<bool>, direction: ScanDirection) {
+    let (rows, cols) = (trees.height() as i32, trees.width() as i32);
+
+    let row_indices: Either<_, _> = match direction {
+        ScanDirection::TopLeft => Either::Left(0..rows),
+        ScanDirection::BottomRight => Either::Right((0..rows).rev()),
+    };
+    let col_indices: Either<_, _> = match direction {
+        ScanDirection::TopLeft => Either::Left(0..cols),
+        ScanDirection::BottomRight => Either::Right((0..rows).rev()),
+    };
+    let rows_bound = match direction {
+        ScanDirection::TopLeft => 0,
+        ScanDirection::BottomRight => rows - 1,
+    };
+    let cols_bound = match direction {
+        ScanDirection::TopLeft => 0,
+        ScanDirection::BottomRight => cols - 1,
+    };
+
+    let mut top = vec![0; cols as usize];
+    let mut left = vec![0; rows as usize];
+
+    for i in row_indices {
+        for j in col_indices.clone() {
+            let blocking_top = top.get_mut(j as usize).unwrap();
+            let blocking_left = left.get_mut(i as usize).unwrap();
+
+            let cur_height = &trees[Point::new(j, i)];
+            let blocked = &mut blocked[Point::new(j, i)];
+
+            if cur_height > blocking_top || i == rows_bound {
+                *blocked = false;
+                *blocking_top = *cur_height;
+            }
+
+            if cur_height > blocking_left || j == cols_bound {
+                *blocked = false;
+                *blocking_left = *cur_height;
+            }
+        }
+    }
+}
+
+/// Product of the viewing distances from each tree in the four directions, 0 on the edges
+pub fn get_scenic_scores(trees: &Grid<u8>) -> Grid<usize> {
+    let mut scenic_scores = Grid::new(trees.width(), trees.height(), 0);
+    let (rows, cols) = (trees.height() as i32, trees.width() as i32);
+
+    for i in 1..rows - 1 {
+        for j in 1..cols - 1 {
+            let cur_height = &trees[Point::new(j, i)];
+
+            let mut top_scenic = 0;
+            for i1 in (0..i).rev() {
+                let top_height = &trees[Point::new(j, i1)];
+                top_scenic += 1;
+                if cur_height <= top_height {
+                    break;
+                }
+            }
+
+            let mut left_scenic = 0;
+            for j1 in (0..j).rev() {
+                let left_height = &trees[Point::new(j1, i)];
+                left_scenic += 1;
+                if cur_height <= left_height {
+                    break;
+                }
+            }
+
+            let mut bottom_scenic = 0;
+            for i1 in (i + 1)..rows {
+                let bottom_height = &trees[Point::new(j, i1)];
+                bottom_scenic += 1;
+                if cur_height <= bottom_height {
+                    break;
+                }
+            }
+
+            let mut right_scenic = 0;
+            for j1 in (j + 1)..cols {
+                let right_height = &trees[Point::new(j1, i)];
+                right_scenic += 1;
+                if cur_height <= right_height {
+                    break;
+                }
+            }
+
+            let scenic_score = &mut scenic_scores[Point::new(j, i)];
+            *scenic_score = top_scenic * left_scenic * bottom_scenic * right_scenic;
+        }
+    }
+
+    scenic_scores
+}
+
+fn parse_trees(input: &str) -> Result<Grid<u8>, ParseError> {
+    let lines: Vec<_> = lines(input).collect();
+    let first_line = lines
+        .first()
+        .ok_or_else(|| ParseError::end_of_input(input, "a line of trees"))?;
+
+    // Not using `Grid::parse` because the last line of the input may be incomplete
+    let mut grid = Grid::new(first_line.text.len(), lines.len(), 0);
+
+    for (y, line) in lines.iter().enumerate() {
+        for (x, (i, c)) in line.text.char_indices().enumerate() {
+            let tree = grid
+                .get_mut(Point::new(x as i32, y as i32))
+                .ok_or_else(|| line.error(&line.text[i..], "the end of the line"))?;
+
+            *tree = c
+                .to_digit(10)
+                .ok_or_else(|| line.error(&line.text[i..], "a tree height (a digit)"))?
+                as u8;
+        }
+    }
+
+    Ok(grid)
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day8::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(21));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day8::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(8));
+    }
+
+    #[test]
+    fn render() {
+        let day = Day8::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+
+        let visible = day.render(&input, Part::One);
+        assert_eq!((visible.width(), visible.height()), (5, 5));
+        assert_eq!(visible.get(0, 0), Rgb(30, 145, 40));
+        assert_eq!(visible.get(3, 1), Rgb::gray(32));
+
+        // The best spot is the tree of height 5 in the middle of the fourth row
+        let scenic = day.render(&input, Part::Two);
+        assert_eq!(scenic.get(2, 3), Rgb::heat(1.0));
+        assert_eq!(scenic.get(0, 0), Rgb::heat(0.0));
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day8, 0..20, 20) {
+            panic!("{error}");
+        }
+    }
+}
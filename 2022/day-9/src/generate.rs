@@ -0,0 +1,16 @@
+use aoc_core::{Generate, Rng};
+
+use crate::Day9;
+
+impl Generate for Day9 {
+    /// Motions of the head
+    const SIZE: usize = 2000;
+
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let motions: Vec<String> = (0..size.max(1))
+            .map(|_| format!("{} {}", rng.pick(&['R', 'L', 'U', 'D']), rng.range(1..=20)))
+            .collect();
+
+        motions.join("\n")
+    }
+}
@@ -0,0 +1,385 @@
+//! Day 9 of 2022: Rope Bridge.
+//!
+//! The input is the list of the moves of the head of the rope.
+//!
+//! ```
+//! use std::num::NonZeroUsize;
+//!
+//! use aoc_2022_day_9::{Direction, Instruction, Rope};
+//!
+//! let mut rope = Rope::new(NonZeroUsize::new(2).unwrap());
+//! rope.exec_instruction(Instruction { direction: Direction::Right, count: 4 });
+//! rope.exec_instruction(Instruction { direction: Direction::Up, count: 4 });
+//! assert_eq!(rope.tail_visited().len(), 7);
+//! ```
+
+mod generate;
+
+use std::{collections::HashSet, fmt::Display, io::Write, iter, num::NonZeroUsize};
+
+use animation::{Animate, Canvas, Color, Glyph, Paint};
+use aoc_core::{
+    parse::{lines, Line},
+    Answer, ParseError, Part, Print, Solution,
+};
+use geometry::Point;
+use grid::Grid;
+
+/// Solution of the day, with the moves of the head as input
+#[derive(Default)]
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Instruction::parse(input)
+    }
+
+    fn part_one(&self, instructions: &Self::Input) -> Answer {
+        let mut rope = Rope::new(NonZeroUsize::new(2).unwrap());
+
+        for instruction in instructions {
+            rope.exec_instruction(*instruction);
+        }
+
+        rope.tail_visited.len().into()
+    }
+
+    fn part_two(&self, instructions: &Self::Input) -> Answer {
+        let mut rope = Rope::new(NonZeroUsize::new(10).unwrap());
+
+        for instruction in instructions {
+            rope.exec_instruction(*instruction);
+        }
+
+        rope.tail_visited.len().into()
+    }
+}
+
+impl Print for Day9 {
+    fn print(&self, instructions: &Self::Input) -> String {
+        let lines: Vec<String> = instructions.iter().map(ToString::to_string).collect();
+
+        lines.join("\n")
+    }
+}
+
+impl Animate for Day9 {
+    fn animate<W: Write>(&self, instructions: &Self::Input, part: Part, canvas: &mut Canvas<W>) {
+        let knots = match part {
+            Part::One => 2,
+            Part::Two => 10,
+        };
+        let mut rope = Rope::new(NonZeroUsize::new(knots).unwrap());
+
+        let mut moves = instructions
+            .iter()
+            .flat_map(|instruction| iter::repeat_n(instruction.direction, instruction.count as _));
+
+        // The knots follow the head, so its path holds the whole animation
+        let mut head = Point::ZERO;
+        let path = moves.clone().map(|direction| {
+            head += Point::from(direction);
+            head
+        });
+        let (min, max) = Point::min_max(path.chain([Point::ZERO])).unwrap_or_default();
+
+        canvas.set_y_up(true);
+        canvas.run(
+            &mut rope,
+            |rope| match moves.next() {
+                Some(direction) => {
+                    rope.step(direction);
+                    true
+                }
+                None => false,
+            },
+            |canvas, rope| {
+                let status = format!("Visited by the tail: {}", rope.tail_visited.len());
+                canvas.draw(&rope.frame(min, max), status);
+            },
+        );
+    }
+}
+
+/// Direction of a move of the head, up being towards positive y
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+    Right,
+    Left,
+    Up,
+    Down,
+}
+
+impl Direction {
+    fn parse(letter: &str) -> Option<Direction> {
+        let direction = match letter {
+            "R" => Direction::Right,
+            "L" => Direction::Left,
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            _ => return None,
+        };
+
+        Some(direction)
+    }
+}
+
+/// Moves of the head by `count` steps in a direction
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    pub direction: Direction,
+    pub count: u32,
+}
+
+impl Instruction {
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        lines(input)
+            .map(|line| Instruction::from_line(&line))
+            .collect()
+    }
+
+    fn from_line(line: &Line) -> Result<Instruction, ParseError> {
+        let (dir_letter, count) = line.split_once(line.text, " ")?;
+
+        Ok(Instruction {
+            direction: Direction::parse(dir_letter)
+                .ok_or_else(|| line.error(dir_letter, "`R`, `L`, `U` or `D`"))?,
+            count: line.parse(count, "a number of steps")?,
+        })
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let letter = match self.direction {
+            Direction::Right => "R",
+            Direction::Left => "L",
+            Direction::Up => "U",
+            Direction::Down => "D",
+        };
+
+        write!(f, "{letter} {}", self.count)
+    }
+}
+
+impl From<Direction> for Point {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Right => Point { x: 1, y: 0 },
+            Direction::Left => Point { x: -1, y: 0 },
+            Direction::Up => Point { x: 0, y: 1 },
+            Direction::Down => Point { x: 0, y: -1 },
+        }
+    }
+}
+
+/// Knots of a rope, starting at the origin, with the positions visited by its tail
+#[derive(Default)]
+pub struct Rope {
+    nodes: Vec<Point>,
+    tail_visited: HashSet<Point>,
+}
+
+impl Display for Rope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let y: Vec<_> = self.nodes.iter().map(|node| node.y).collect();
+        let x: Vec<_> = self.nodes.iter().map(|node| node.x).collect();
+
+        let min_y: i32 = *y.iter().min().unwrap();
+        let max_y: i32 = *y.iter().max().unwrap();
+        let dim_y = max_y - min_y + 2;
+
+        let min_x: i32 = *x.iter().min().unwrap();
+        let max_x: i32 = *x.iter().max().unwrap();
+        let dim_x = max_x - min_x + 2;
+
+        let mut grid: Vec<Vec<String>> =
+            vec![vec![".".to_string(); dim_x as usize]; dim_y as usize];
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            let row = (node.y - min_y) as usize;
+            let col = (node.x - min_x) as usize;
+            grid[row][col] = i.to_string();
+        }
+
+        for row in grid.iter().rev() {
+            let row = row.join("");
+            writeln!(f, "{row}").unwrap();
+        }
+
+        Ok(())
+    }
+}
+
+impl Rope {
+    pub fn new(nodes: NonZeroUsize) -> Rope {
+        Rope {
+            nodes: vec![Point { x: 0, y: 0 }; nodes.get()],
+            ..Default::default()
+        }
+    }
+
+    pub fn exec_instruction(&mut self, instruction: Instruction) {
+        for _ in 0..instruction.count {
+            self.step(instruction.direction);
+        }
+    }
+
+    /// Moves the head by one step, each knot then following the previous one
+    pub fn step(&mut self, direction: Direction) {
+        self.move_head(direction);
+        self.tail_visited.insert(self.tail());
+    }
+
+    /// Knots and positions visited by the tail between `min` and `max`
+    fn frame(&self, min: Point, max: Point) -> Grid<Cell> {
+        let mut frame = Grid::with_bounds(min, max, Cell::Empty);
+
+        for visited in &self.tail_visited {
+            frame[*visited] = Cell::Visited;
+        }
+        frame[Point::ZERO] = Cell::Start;
+
+        // Draws the head last, as it is above the other knots
+        for (i, knot) in self.nodes.iter().enumerate().rev() {
+            frame[*knot] = if i == 0 {
+                Cell::Head
+            } else if i == self.nodes.len() - 1 {
+                Cell::Tail
+            } else {
+                Cell::Knot(i as u8)
+            };
+        }
+
+        frame
+    }
+
+    /// Positions of the knots, from the head to the tail
+    pub fn knots(&self) -> &[Point] {
+        &self.nodes
+    }
+
+    pub fn tail(&self) -> Point {
+        *self.nodes.last().unwrap()
+    }
+
+    /// Positions of the tail after each step
+    pub fn tail_visited(&self) -> &HashSet<Point> {
+        &self.tail_visited
+    }
+
+    fn move_head(&mut self, direction: Direction) {
+        self.nodes[0] += Point::from(direction);
+
+        for i in 1..self.nodes.len() {
+            let head = self.nodes[i - 1];
+            let tail = &mut self.nodes[i];
+
+            let diff = head - *tail;
+            let (dx, dy) = match (diff.x, diff.y) {
+                // Copied from https://fasterthanli.me/series/advent-of-code-2022/part-9#part-2-solution-in-your-browser
+                // overlapping
+                (0, 0) => (0, 0),
+                // touching up/left/down/right
+                (0, 1) | (1, 0) | (0, -1) | (-1, 0) => (0, 0),
+                // touching diagonally
+                (1, 1) | (1, -1) | (-1, 1) | (-1, -1) => (0, 0),
+                // need to move up/left/down/right
+                (0, 2) => (0, 1),
+                (0, -2) => (0, -1),
+                (2, 0) => (1, 0),
+                (-2, 0) => (-1, 0),
+                // need to move to the right diagonally
+                (2, 1) => (1, 1),
+                (2, -1) => (1, -1),
+                // need to move to the left diagonally
+                (-2, 1) => (-1, 1),
+                (-2, -1) => (-1, -1),
+                // need to move up/down diagonally
+                (1, 2) => (1, 1),
+                (-1, 2) => (-1, 1),
+                (1, -2) => (1, -1),
+                (-1, -2) => (-1, -1),
+                // 🆕 need to move diagonally
+                (-2, -2) => (-1, -1),
+                (-2, 2) => (-1, 1),
+                (2, -2) => (1, -1),
+                (2, 2) => (1, 1),
+                _ => panic!("unhandled case: tail - head = {diff:?}"),
+            };
+
+            *tail += Point { x: dx, y: dy };
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Cell {
+    Empty,
+    Visited,
+    Start,
+    Head,
+    Knot(u8),
+    Tail,
+}
+
+impl Paint for Cell {
+    fn glyph(&self) -> Glyph {
+        match self {
+            Cell::Empty => Glyph::new('.', Color::Gray),
+            Cell::Visited => Glyph::new('#', Color::Blue),
+            Cell::Start => Glyph::new('s', Color::White),
+            Cell::Head => Glyph::new('H', Color::Red),
+            Cell::Knot(i) => Glyph::new(char::from(b'0' + i), Color::Yellow),
+            Cell::Tail => Glyph::new('T', Color::Green),
+        }
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = Day9::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Number(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = Day9::example();
+        let input = day.parse(include_str!("../input-2.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Number(36));
+    }
+
+    #[test]
+    fn animation() {
+        let day = Day9::example();
+        let input = day.parse(include_str!("../input-2.test.txt")).unwrap();
+        let mut canvas = Canvas::with_writer(
+            Vec::new(),
+            animation::Options {
+                fps: 0,
+                steps_per_frame: 1,
+            },
+        );
+
+        day.animate(&input, Part::Two, &mut canvas);
+
+        let output = String::from_utf8(canvas.into_writer()).unwrap();
+        assert!(output.ends_with("Visited by the tail: 36"));
+    }
+
+    #[test]
+    fn round_trip() {
+        if let Err(error) = aoc_core::print::round_trip(&Day9, 0..20, 50) {
+            panic!("{error}");
+        }
+    }
+}
@@ -0,0 +1,407 @@
+use std::{
+    fmt::{Display, Write as _},
+    io::{self, Stdout, Write},
+    thread,
+    time::{Duration, Instant},
+};
+
+use geometry::Point;
+use grid::Grid;
+
+use crate::{
+    glyph::{Color, Glyph, Paint},
+    record::Recorder,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// Maximum frames drawn per second, or 0 to draw them as fast as possible
+    pub fps: u32,
+    /// Simulation steps run between two frames
+    pub steps_per_frame: u32,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            fps: 30,
+            steps_per_frame: 1,
+        }
+    }
+}
+
+/// Terminal canvas which draws grids as animation frames.
+///
+/// Only the cells which changed since the previous frame are redrawn, unless the bounds of the grid
+/// change. A status line is drawn below the grid. The frames can also be saved by a [`Recorder`].
+///
+/// Once writing a frame fails (eg. with a broken pipe when the output is closed), nothing more is
+/// drawn and [`Canvas::finish`] returns the error.
+pub struct Canvas<W: Write = Stdout> {
+    out: W,
+    options: Options,
+    /// Draws the rows from the largest y, for grids where y points up
+    y_up: bool,
+    previous: Option<Grid<Glyph>>,
+    last_frame: Option<Instant>,
+    frames: usize,
+    recorder: Option<Box<dyn Recorder>>,
+    /// First error writing or recording a frame
+    error: Option<io::Error>,
+}
+
+impl Canvas {
+    pub fn new(options: Options) -> Self {
+        Self::with_writer(io::stdout(), options)
+    }
+}
+
+impl<W: Write> Canvas<W> {
+    pub fn with_writer(out: W, options: Options) -> Self {
+        Self {
+            out,
+            options,
+            y_up: false,
+            previous: None,
+            last_frame: None,
+            frames: 0,
+            recorder: None,
+            error: None,
+        }
+    }
+
+    pub fn set_y_up(&mut self, y_up: bool) {
+        self.y_up = y_up;
+    }
+
+    /// Passes the frames drawn from now on to `recorder`
+    pub fn record(&mut self, recorder: impl Recorder + 'static) {
+        self.recorder = Some(Box::new(recorder));
+    }
+
+    /// Frames drawn so far
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Whether writing or recording a frame failed, after which nothing more is drawn
+    pub fn failed(&self) -> bool {
+        self.error.is_some()
+    }
+
+    pub fn into_writer(self) -> W {
+        self.out
+    }
+
+    /// Runs `step` until it returns false or drawing fails, calling `draw` before the first step,
+    /// every `steps_per_frame` steps and after the last one. Returns the number of steps run.
+    pub fn run<S>(
+        &mut self,
+        state: &mut S,
+        mut step: impl FnMut(&mut S) -> bool,
+        mut draw: impl FnMut(&mut Self, &S),
+    ) -> usize {
+        let steps_per_frame = self.options.steps_per_frame.max(1) as usize;
+        let mut steps = 0;
+
+        draw(self, state);
+        while !self.failed() && step(state) {
+            steps += 1;
+
+            if steps % steps_per_frame == 0 {
+                draw(self, state);
+            }
+        }
+
+        if steps % steps_per_frame != 0 {
+            draw(self, state);
+        }
+
+        steps
+    }
+
+    /// Draws `frame` with `status` below it, waiting for the frame rate first
+    pub fn draw<T: Paint>(&mut self, frame: &Grid<T>, status: impl Display) {
+        if self.failed() {
+            return;
+        }
+        self.wait();
+
+        let glyphs = frame.map(Paint::glyph);
+        let mut escapes = String::new();
+        let mut color = None;
+
+        match &self.previous {
+            Some(previous) if previous.min() == glyphs.min() && previous.max() == glyphs.max() => {
+                for (point, glyph) in glyphs.iter() {
+                    if previous[point] != *glyph {
+                        self.move_to(&mut escapes, &glyphs, point);
+                        paint(&mut escapes, &mut color, *glyph);
+                    }
+                }
+            }
+            _ => {
+                escapes.push_str("\x1b[?25l\x1b[2J");
+                for point in glyphs.points() {
+                    if point.x == glyphs.min().x {
+                        self.move_to(&mut escapes, &glyphs, point);
+                    }
+                    paint(&mut escapes, &mut color, glyphs[point]);
+                }
+            }
+        }
+
+        let status = status.to_string();
+        let _ = write!(
+            escapes,
+            "\x1b[{};1H\x1b[0m\x1b[K{status}",
+            glyphs.height() + 2
+        );
+
+        let written = self
+            .out
+            .write_all(escapes.as_bytes())
+            .and_then(|_| self.out.flush())
+            .and_then(|_| match &mut self.recorder {
+                Some(recorder) => recorder.record(&screen(&glyphs, self.y_up), &status, &escapes),
+                None => Ok(()),
+            });
+        if let Err(error) = written {
+            self.error = Some(error);
+            return;
+        }
+
+        self.previous = Some(glyphs);
+        self.frames += 1;
+    }
+
+    /// Moves the cursor below the last frame and shows it again, returning the error which
+    /// stopped the drawing if any
+    pub fn finish(&mut self) -> io::Result<()> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+
+        let row = self.previous.as_ref().map_or(1, |frame| frame.height() + 3);
+        let escapes = format!("\x1b[0m\x1b[{row};1H\x1b[?25h");
+
+        self.out.write_all(escapes.as_bytes())?;
+        self.out.flush()?;
+
+        match &mut self.recorder {
+            Some(recorder) => recorder.finish(&escapes),
+            None => Ok(()),
+        }
+    }
+
+    fn move_to(&self, escapes: &mut String, frame: &Grid<Glyph>, point: Point) {
+        let row = if self.y_up {
+            frame.max().y - point.y
+        } else {
+            point.y - frame.min().y
+        };
+        let column = point.x - frame.min().x;
+
+        let _ = write!(escapes, "\x1b[{};{}H", row + 1, column + 1);
+    }
+
+    fn wait(&mut self) {
+        if let (Some(last_frame), fps @ 1..) = (self.last_frame, self.options.fps) {
+            let interval = Duration::from_secs(1) / fps;
+            if let Some(left) = interval.checked_sub(last_frame.elapsed()) {
+                thread::sleep(left);
+            }
+        }
+
+        self.last_frame = Some(Instant::now());
+    }
+}
+
+/// Glyphs of `frame` in the order they are drawn, the top row at y = 0
+fn screen(frame: &Grid<Glyph>, y_up: bool) -> Grid<Glyph> {
+    let mut screen = Grid::new(frame.width(), frame.height(), Glyph::plain(' '));
+
+    for (point, glyph) in frame.iter() {
+        let y = if y_up {
+            frame.max().y - point.y
+        } else {
+            point.y - frame.min().y
+        };
+        screen[Point::new(point.x - frame.min().x, y)] = *glyph;
+    }
+
+    screen
+}
+
+/// Writes `glyph`, changing the colour only if it differs from the `current` one
+fn paint(escapes: &mut String, current: &mut Option<Color>, glyph: Glyph) {
+    if *current != Some(glyph.color) {
+        let _ = write!(escapes, "{}", glyph.color);
+        *current = Some(glyph.color);
+    }
+
+    escapes.push(glyph.char);
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+
+    fn canvas() -> Canvas<Vec<u8>> {
+        Canvas::with_writer(
+            Vec::new(),
+            Options {
+                fps: 0,
+                steps_per_frame: 1,
+            },
+        )
+    }
+
+    fn output(canvas: &mut Canvas<Vec<u8>>) -> String {
+        String::from_utf8(std::mem::take(&mut canvas.out)).unwrap()
+    }
+
+    #[test]
+    fn frame_diff() {
+        let mut canvas = canvas();
+        let mut grid = Grid::new(3, 2, '.');
+
+        canvas.draw(&grid, "start");
+        let first = output(&mut canvas);
+        assert!(first.contains("\x1b[2J"));
+        assert!(first.contains("\x1b[1;1H\x1b[39m..."));
+        assert!(first.ends_with("\x1b[4;1H\x1b[0m\x1b[Kstart"));
+
+        grid[Point::new(2, 1)] = '#';
+        canvas.draw(&grid, "");
+        assert_eq!(
+            output(&mut canvas),
+            "\x1b[2;3H\x1b[39m#\x1b[4;1H\x1b[0m\x1b[K"
+        );
+
+        canvas.draw(&grid, "");
+        assert_eq!(output(&mut canvas), "\x1b[4;1H\x1b[0m\x1b[K");
+
+        // New bounds redraw everything
+        canvas.draw(&Grid::new(2, 2, '.'), "");
+        assert!(output(&mut canvas).contains("\x1b[2J"));
+        assert_eq!(canvas.frames(), 4);
+    }
+
+    #[test]
+    fn y_up() {
+        let mut canvas = canvas();
+        canvas.set_y_up(true);
+
+        let mut grid = Grid::new(1, 3, Glyph::plain('.'));
+        canvas.draw(&grid, "");
+        output(&mut canvas);
+
+        grid[Point::new(0, 0)] = Glyph::new('o', Color::Red);
+        canvas.draw(&grid, "");
+        assert!(output(&mut canvas).starts_with("\x1b[3;1H\x1b[31mo"));
+    }
+
+    #[test]
+    fn run() {
+        let mut canvas = Canvas::with_writer(
+            Vec::new(),
+            Options {
+                fps: 0,
+                steps_per_frame: 3,
+            },
+        );
+
+        let mut count = 0;
+        let steps = canvas.run(
+            &mut count,
+            |count| {
+                *count += 1;
+                *count <= 7
+            },
+            |canvas, count| canvas.draw(&Grid::new(1, 1, '.'), count),
+        );
+
+        assert_eq!(steps, 7);
+        // Before the first step, after steps 3 and 6, and after the last one
+        assert_eq!(canvas.frames(), 4);
+    }
+
+    #[test]
+    fn broken_pipe() {
+        /// Output closed after a number of bytes
+        struct Closing(usize);
+
+        impl Write for Closing {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                match self.0.checked_sub(buf.len()) {
+                    Some(left) => {
+                        self.0 = left;
+                        Ok(buf.len())
+                    }
+                    None => Err(io::ErrorKind::BrokenPipe.into()),
+                }
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let options = Options {
+            fps: 0,
+            steps_per_frame: 1,
+        };
+        let mut canvas = Canvas::with_writer(Closing(100), options);
+
+        let steps = canvas.run(
+            &mut 0,
+            |count| {
+                *count += 1;
+                *count < 1000
+            },
+            |canvas, count| canvas.draw(&Grid::new(3, 3, '.'), count),
+        );
+
+        assert!(canvas.failed());
+        assert!(steps < 1000);
+        assert_eq!(
+            canvas.finish().unwrap_err().kind(),
+            io::ErrorKind::BrokenPipe
+        );
+    }
+
+    #[test]
+    fn record() {
+        struct Frames(Rc<RefCell<Vec<String>>>);
+
+        impl Recorder for Frames {
+            fn record(&mut self, screen: &Grid<Glyph>, status: &str, _: &str) -> io::Result<()> {
+                let rows = screen.map(|glyph| glyph.char).to_string();
+                self.0.borrow_mut().push(format!("{rows}{status}"));
+                Ok(())
+            }
+
+            fn finish(&mut self, _: &str) -> io::Result<()> {
+                self.0.borrow_mut().push("end".to_owned());
+                Ok(())
+            }
+        }
+
+        let frames = Rc::new(RefCell::new(Vec::new()));
+        let mut canvas = canvas();
+        canvas.set_y_up(true);
+        canvas.record(Frames(frames.clone()));
+
+        let mut grid = Grid::with_bounds(Point::new(2, 5), Point::new(3, 6), '.');
+        grid[Point::new(2, 6)] = '#';
+        canvas.draw(&grid, "top left");
+        canvas.finish().unwrap();
+
+        assert_eq!(*frames.borrow(), ["#.\n..\ntop left", "end"]);
+    }
+}
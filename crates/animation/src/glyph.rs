@@ -0,0 +1,71 @@
+use std::fmt::{self, Display};
+
+/// Foreground colour of a glyph, written with ANSI escapes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    /// Colour of the terminal text
+    Default,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+    Rgb(u8, u8, u8),
+}
+
+/// Character drawn for a cell, with its colour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyph {
+    pub char: char,
+    pub color: Color,
+}
+
+/// Palette of a cell type: the glyph drawn for each of its values.
+pub trait Paint {
+    fn glyph(&self) -> Glyph;
+}
+
+impl Glyph {
+    pub const fn new(char: char, color: Color) -> Self {
+        Self { char, color }
+    }
+
+    pub const fn plain(char: char) -> Self {
+        Self::new(char, Color::Default)
+    }
+}
+
+impl Paint for Glyph {
+    fn glyph(&self) -> Glyph {
+        *self
+    }
+}
+
+impl Paint for char {
+    fn glyph(&self) -> Glyph {
+        Glyph::plain(*self)
+    }
+}
+
+/// Escape which selects the colour
+impl Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let code = match self {
+            Color::Default => 39,
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 97,
+            Color::Gray => 90,
+            Color::Rgb(r, g, b) => return write!(f, "\x1b[38;2;{r};{g};{b}m"),
+        };
+
+        write!(f, "\x1b[{code}m")
+    }
+}
@@ -0,0 +1,19 @@
+pub mod canvas;
+pub mod glyph;
+pub mod record;
+pub mod replay;
+
+use std::io::Write;
+
+use aoc_core::{Part, Solution};
+
+pub use canvas::{Canvas, Options};
+pub use glyph::{Color, Glyph, Paint};
+pub use record::{Asciicast, Recorder};
+pub use replay::{Replay, ReplayRecorder};
+
+/// Solution whose simulation can be watched while it runs.
+pub trait Animate: Solution {
+    /// Solves `part` of `input`, drawing its simulation steps on `canvas`
+    fn animate<W: Write>(&self, input: &Self::Input, part: Part, canvas: &mut Canvas<W>);
+}
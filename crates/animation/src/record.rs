@@ -0,0 +1,122 @@
+use std::{
+    fmt::Write as _,
+    io::{self, Write},
+};
+
+use grid::Grid;
+
+use crate::glyph::Glyph;
+
+/// Receives the frames drawn by a canvas, to save them for a later playback.
+pub trait Recorder {
+    /// Records a frame: its glyphs in screen order (the top row at y = 0), the status line drawn
+    /// below them and the escapes which were written to draw the frame in the terminal
+    fn record(&mut self, screen: &Grid<Glyph>, status: &str, escapes: &str) -> io::Result<()>;
+
+    /// Ends the recording, `escapes` restore the terminal after the last frame
+    fn finish(&mut self, escapes: &str) -> io::Result<()>;
+}
+
+/// Recorder writing an [asciicast v2](https://docs.asciinema.org/manual/asciicast/v2/) file,
+/// which can be played with `asciinema play`.
+///
+/// Frames are timestamped at the given frame rate instead of the time taken to simulate them.
+pub struct Asciicast<W: Write> {
+    out: W,
+    fps: u32,
+    frames: u32,
+}
+
+impl<W: Write> Asciicast<W> {
+    pub fn new(out: W, fps: u32) -> Self {
+        Self {
+            out,
+            fps: fps.max(1),
+            frames: 0,
+        }
+    }
+
+    pub fn into_writer(self) -> W {
+        self.out
+    }
+
+    fn event(&mut self, escapes: &str) -> io::Result<()> {
+        let time = self.frames as f64 / self.fps as f64;
+        writeln!(self.out, "[{time:.6}, \"o\", {}]", json_string(escapes))
+    }
+}
+
+impl<W: Write> Recorder for Asciicast<W> {
+    fn record(&mut self, screen: &Grid<Glyph>, status: &str, escapes: &str) -> io::Result<()> {
+        if self.frames == 0 {
+            // The terminal holds the frame, an empty row and the status line
+            let width = screen.width().max(status.chars().count());
+            let height = screen.height() + 2;
+
+            writeln!(
+                self.out,
+                "{{\"version\": 2, \"width\": {width}, \"height\": {height}}}"
+            )?;
+        }
+
+        self.event(escapes)?;
+        self.frames += 1;
+
+        Ok(())
+    }
+
+    fn finish(&mut self, escapes: &str) -> io::Result<()> {
+        if self.frames > 0 {
+            self.event(escapes)?;
+        }
+
+        self.out.flush()
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+
+    for char in text.chars() {
+        match char {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            char if char.is_control() => {
+                let _ = write!(json, "\\u{:04x}", char as u32);
+            }
+            char => json.push(char),
+        }
+    }
+
+    json.push('"');
+    json
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn asciicast() {
+        let mut cast = Asciicast::new(Vec::new(), 4);
+        let screen = Grid::new(3, 2, Glyph::plain('.'));
+
+        cast.record(&screen, "status", "\x1b[2J...").unwrap();
+        cast.record(&screen, "status", "\"#\"").unwrap();
+        cast.finish("\x1b[?25h").unwrap();
+
+        let output = String::from_utf8(cast.into_writer()).unwrap();
+        assert_eq!(
+            output,
+            "{\"version\": 2, \"width\": 6, \"height\": 4}\n\
+             [0.000000, \"o\", \"\\u001b[2J...\"]\n\
+             [0.250000, \"o\", \"\\\"#\\\"\"]\n\
+             [0.500000, \"o\", \"\\u001b[?25h\"]\n"
+        );
+    }
+}
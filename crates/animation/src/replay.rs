@@ -0,0 +1,366 @@
+//! Compact text format of the recorded frames, played back by a canvas.
+//!
+//! ```text
+//! replay <fps>
+//! frame <width> <height> <status>
+//! <cells of each row, from the top>
+//! diff <status>
+//! <y> <x> <cells changed from x>
+//! ```
+//!
+//! A `frame` is drawn in full, the following `diff`s only list the runs of cells which changed
+//! since the previous frame. Cells are written as their characters, preceded by `{<colour>}`
+//! whenever the colour changes (`{{` stands for `{`). The colours are `d` (default), `r`, `g`, `y`,
+//! `b`, `m`, `c`, `w`, `k` (gray) or `#rrggbb`.
+
+use std::{
+    fmt::Write as _,
+    io::{self, Write},
+};
+
+use aoc_core::{
+    parse::{lines, Line},
+    ParseError,
+};
+use geometry::Point;
+use grid::Grid;
+
+use crate::{
+    canvas::Canvas,
+    glyph::{Color, Glyph},
+    record::Recorder,
+};
+
+/// Frames parsed from a replay.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replay {
+    /// Frame rate of the recording
+    pub fps: u32,
+    pub frames: Vec<Frame>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    /// Glyphs in screen order, the top row at y = 0
+    pub screen: Grid<Glyph>,
+    pub status: String,
+}
+
+impl Replay {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut lines = lines(input).peekable();
+
+        let header = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(input, "`replay`"))?;
+        let fps = header.strip_prefix(header.text, "replay ")?;
+        let fps = header.parse(fps, "a frame rate")?;
+
+        let mut frames: Vec<Frame> = Vec::new();
+        while let Some(line) = lines.next() {
+            if let Some(rest) = line.text.strip_prefix("frame ") {
+                let (width, rest) = line.split_once(rest, " ")?;
+                let (height, status) = rest.split_once(' ').unwrap_or((rest, ""));
+                let width = line.parse(width, "a width")?;
+                let height = line.parse(height, "a height")?;
+
+                let mut screen = Grid::new(width, height, Glyph::plain(' '));
+                for y in 0..height {
+                    let row = lines
+                        .next()
+                        .ok_or_else(|| ParseError::end_of_input(input, "a row of cells"))?;
+                    let cells = decode(&row, row.text)?;
+                    if cells.len() != width {
+                        return Err(row.error_at_end(format!("a row of {width} cells")));
+                    }
+
+                    for (x, glyph) in cells.into_iter().enumerate() {
+                        screen[Point::new(x as i32, y as i32)] = glyph;
+                    }
+                }
+
+                frames.push(Frame {
+                    screen,
+                    status: status.to_owned(),
+                });
+            } else if let Some(status) = line.text.strip_prefix("diff ") {
+                let previous = frames
+                    .last()
+                    .ok_or_else(|| line.error(line.text, "`frame`"))?;
+                let mut screen = previous.screen.clone();
+
+                while let Some(run) =
+                    lines.next_if(|run| run.text.starts_with(|c: char| c.is_ascii_digit()))
+                {
+                    let (y, rest) = run.split_once(run.text, " ")?;
+                    let (x, cells) = run.split_once(rest, " ")?;
+                    let y: i32 = run.parse(y, "a row")?;
+                    let x: i32 = run.parse(x, "a column")?;
+
+                    for (i, glyph) in decode(&run, cells)?.into_iter().enumerate() {
+                        let cell = screen
+                            .get_mut(Point::new(x + i as i32, y))
+                            .ok_or_else(|| run.error(run.text, "cells inside of the frame"))?;
+                        *cell = glyph;
+                    }
+                }
+
+                frames.push(Frame {
+                    screen,
+                    status: status.to_owned(),
+                });
+            } else {
+                return Err(line.error(line.text, "`frame` or `diff`"));
+            }
+        }
+
+        Ok(Self { fps, frames })
+    }
+
+    /// Draws the frames on `canvas`
+    pub fn play<W: Write>(&self, canvas: &mut Canvas<W>) {
+        for frame in &self.frames {
+            canvas.draw(&frame.screen, &frame.status);
+        }
+    }
+}
+
+/// Recorder writing the frames as a replay.
+pub struct ReplayRecorder<W: Write> {
+    out: W,
+    fps: u32,
+    previous: Option<Grid<Glyph>>,
+}
+
+impl<W: Write> ReplayRecorder<W> {
+    pub fn new(out: W, fps: u32) -> Self {
+        Self {
+            out,
+            fps,
+            previous: None,
+        }
+    }
+
+    pub fn into_writer(self) -> W {
+        self.out
+    }
+}
+
+impl<W: Write> Recorder for ReplayRecorder<W> {
+    fn record(&mut self, screen: &Grid<Glyph>, status: &str, _escapes: &str) -> io::Result<()> {
+        let mut text = String::new();
+
+        match &self.previous {
+            None => {
+                let _ = writeln!(text, "replay {}", self.fps);
+                encode_frame(&mut text, screen, status);
+            }
+            Some(previous)
+                if previous.width() != screen.width() || previous.height() != screen.height() =>
+            {
+                encode_frame(&mut text, screen, status);
+            }
+            Some(previous) => {
+                let _ = writeln!(text, "diff {status}");
+
+                for y in 0..screen.height() as i32 {
+                    let mut x = 0;
+                    while x < screen.width() as i32 {
+                        let changed = |x| {
+                            let point = Point::new(x, y);
+                            previous[point] != screen[point]
+                        };
+
+                        if !changed(x) {
+                            x += 1;
+                            continue;
+                        }
+
+                        let start = x;
+                        while x < screen.width() as i32 && changed(x) {
+                            x += 1;
+                        }
+
+                        let run = (start..x).map(|x| screen[Point::new(x, y)]);
+                        let _ = write!(text, "{y} {start} ");
+                        encode(&mut text, run);
+                        text.push('\n');
+                    }
+                }
+            }
+        }
+
+        self.previous = Some(screen.clone());
+        self.out.write_all(text.as_bytes())
+    }
+
+    fn finish(&mut self, _escapes: &str) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+fn encode_frame(text: &mut String, screen: &Grid<Glyph>, status: &str) {
+    let _ = writeln!(
+        text,
+        "frame {} {} {status}",
+        screen.width(),
+        screen.height()
+    );
+
+    for y in 0..screen.height() as i32 {
+        encode(
+            text,
+            (0..screen.width() as i32).map(|x| screen[Point::new(x, y)]),
+        );
+        text.push('\n');
+    }
+}
+
+fn encode(text: &mut String, glyphs: impl Iterator<Item = Glyph>) {
+    let mut color = None;
+
+    for glyph in glyphs {
+        if color != Some(glyph.color) {
+            let _ = match glyph.color {
+                Color::Rgb(r, g, b) => write!(text, "{{#{r:02x}{g:02x}{b:02x}}}"),
+                color => write!(text, "{{{}}}", color_code(color)),
+            };
+            color = Some(glyph.color);
+        }
+
+        match glyph.char {
+            '{' => text.push_str("{{"),
+            char => text.push(char),
+        }
+    }
+}
+
+fn decode(line: &Line, cells: &str) -> Result<Vec<Glyph>, ParseError> {
+    let mut glyphs = Vec::new();
+    let mut color = None;
+    let mut rest = cells;
+
+    while let Some(char) = rest.chars().next() {
+        let (char, after) = match rest.strip_prefix('{') {
+            Some(after) if after.starts_with('{') => ('{', &after[1..]),
+            Some(after) => {
+                let (code, after) = line.split_once(after, "}")?;
+                color = Some(parse_color(code).ok_or_else(|| line.error(code, "a colour"))?);
+                rest = after;
+                continue;
+            }
+            None => (char, &rest[char.len_utf8()..]),
+        };
+
+        let color = color.ok_or_else(|| line.error(rest, "a colour"))?;
+        glyphs.push(Glyph::new(char, color));
+        rest = after;
+    }
+
+    Ok(glyphs)
+}
+
+fn color_code(color: Color) -> char {
+    match color {
+        Color::Default => 'd',
+        Color::Red => 'r',
+        Color::Green => 'g',
+        Color::Yellow => 'y',
+        Color::Blue => 'b',
+        Color::Magenta => 'm',
+        Color::Cyan => 'c',
+        Color::White => 'w',
+        Color::Gray => 'k',
+        Color::Rgb(..) => '#',
+    }
+}
+
+fn parse_color(code: &str) -> Option<Color> {
+    let color = match code {
+        "d" => Color::Default,
+        "r" => Color::Red,
+        "g" => Color::Green,
+        "y" => Color::Yellow,
+        "b" => Color::Blue,
+        "m" => Color::Magenta,
+        "c" => Color::Cyan,
+        "w" => Color::White,
+        "k" => Color::Gray,
+        _ => {
+            let hex = code.strip_prefix('#').filter(|hex| hex.len() == 6)?;
+            let channel = |i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok();
+
+            Color::Rgb(channel(0)?, channel(2)?, channel(4)?)
+        }
+    };
+
+    Some(color)
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut recorder = ReplayRecorder::new(Vec::new(), 12);
+
+        let mut screen = Grid::new(3, 2, Glyph::new('.', Color::Gray));
+        recorder.record(&screen, "Step: 0", "").unwrap();
+
+        screen[Point::new(1, 0)] = Glyph::new('{', Color::Rgb(255, 128, 0));
+        screen[Point::new(2, 0)] = Glyph::new('#', Color::Default);
+        recorder.record(&screen, "Step: 1", "").unwrap();
+
+        let wide = Grid::new(4, 1, Glyph::plain('o'));
+        recorder.record(&wide, "", "").unwrap();
+
+        let text = String::from_utf8(recorder.into_writer()).unwrap();
+        assert_eq!(
+            text,
+            "replay 12\n\
+             frame 3 2 Step: 0\n\
+             {k}...\n\
+             {k}...\n\
+             diff Step: 1\n\
+             0 1 {#ff8000}{{{d}#\n\
+             frame 4 1 \n\
+             {d}oooo\n"
+        );
+
+        let replay = Replay::parse(&text).unwrap();
+        assert_eq!(replay.fps, 12);
+        assert_eq!(replay.frames.len(), 3);
+        assert_eq!(replay.frames[1].screen, screen);
+        assert_eq!(replay.frames[1].status, "Step: 1");
+        assert_eq!(replay.frames[2].screen, wide);
+    }
+
+    #[test]
+    fn invalid_replay() {
+        let error = |input| {
+            let error = Replay::parse(input).unwrap_err();
+            (error.line, error.column, error.expected)
+        };
+
+        assert_eq!(error(""), (1, 1, "`replay`".to_owned()));
+        assert_eq!(
+            error("replay 30\ndiff Step: 1\n"),
+            (2, 1, "`frame`".to_owned())
+        );
+        assert_eq!(
+            error("replay 30\nframe 2 1 \n{d}...\n"),
+            (3, 7, "a row of 2 cells".to_owned())
+        );
+        assert_eq!(
+            error("replay 30\nframe 2 1 \n{x}..\n"),
+            (3, 2, "a colour".to_owned())
+        );
+        assert_eq!(
+            error("replay 30\nframe 2 1 \n{d}..\ndiff \n0 2 {d}#\n"),
+            (5, 1, "cells inside of the frame".to_owned())
+        );
+    }
+}
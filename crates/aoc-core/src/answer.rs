@@ -0,0 +1,78 @@
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// Multi-line answer which must be read by a human (eg. letters drawn by day 10's CRT)
+    Drawing(String),
+    Unimplemented,
+}
+
+impl Answer {
+    pub fn is_multiline(&self) -> bool {
+        match self {
+            Answer::Drawing(drawing) => drawing.contains('\n'),
+            _ => false,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) | Answer::Drawing(text) => write!(f, "{text}"),
+            Answer::Unimplemented => write!(f, "NOT IMPLEMENTED"),
+        }
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(number: $number) -> Self {
+                    Answer::Number(i64::try_from(number).expect("answer should fit in an i64"))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Answer::from(24000usize), Answer::Number(24000));
+        assert_eq!(Answer::from(-3i32), Answer::Number(-3));
+        assert_eq!(Answer::from("CMZ"), Answer::Text("CMZ".to_owned()));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Answer::Number(1514285714288).to_string(), "1514285714288");
+        assert_eq!(Answer::Text("2=-1=0".to_owned()).to_string(), "2=-1=0");
+        assert_eq!(Answer::Drawing("#.\n.#".to_owned()).to_string(), "#.\n.#");
+        assert_eq!(Answer::Unimplemented.to_string(), "NOT IMPLEMENTED");
+
+        assert!(Answer::Drawing("#.\n.#".to_owned()).is_multiline());
+        assert!(!Answer::Text("#.".to_owned()).is_multiline());
+    }
+}
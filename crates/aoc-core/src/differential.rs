@@ -0,0 +1,178 @@
+use std::{
+    fmt::{self, Display},
+    ops::Range,
+};
+
+use crate::{
+    answer::Answer,
+    generate::{Generate, Rng},
+    solution::{Part, Solution},
+};
+
+/// Another implementation of a part, such as the brute-force counterpart of an optimized solution.
+pub struct Solver<S: Solution> {
+    pub name: &'static str,
+    pub part: Part,
+    pub solve: fn(&S, &S::Input) -> Answer,
+}
+
+/// Solution with alternative solvers, which should give the same answers as its parts on any
+/// generated input.
+pub trait Alternatives: Generate {
+    fn alternatives() -> Vec<Solver<Self>>;
+}
+
+/// Answer of an alternative solver which differs from the one of the solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub solver: &'static str,
+    pub part: Part,
+    /// Generator parameters of the input
+    pub seed: u64,
+    pub size: usize,
+    pub expected: Answer,
+    pub actual: Answer,
+}
+
+impl Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let part = match self.part {
+            Part::One => 1,
+            Part::Two => 2,
+        };
+
+        write!(
+            f,
+            "`{}` answers {} instead of {} to part {part} of the input generated with --seed {} \
+             --size {}",
+            self.solver, self.actual, self.expected, self.seed, self.size
+        )
+    }
+}
+
+/// Compares the answers of the alternative solvers with the ones of `solution`, on the inputs of
+/// `size` generated from each of the `seeds`.
+///
+/// Panics if a generated input cannot be parsed, which is a bug of the generator.
+pub fn compare<S: Alternatives>(
+    solution: &S,
+    seeds: Range<u64>,
+    size: usize,
+) -> Result<(), Disagreement> {
+    let alternatives = S::alternatives();
+
+    for seed in seeds {
+        let text = solution.generate(&mut Rng::new(seed), size);
+        let input = solution
+            .parse(&text)
+            .unwrap_or_else(|error| panic!("invalid generated input\n{}", error.render(&text)));
+
+        for part in [Part::One, Part::Two] {
+            let solvers: Vec<_> = alternatives
+                .iter()
+                .filter(|solver| solver.part == part)
+                .collect();
+            if solvers.is_empty() {
+                continue;
+            }
+
+            let expected = match part {
+                Part::One => solution.part_one(&input),
+                Part::Two => solution.part_two(&input),
+            };
+
+            for solver in solvers {
+                let actual = (solver.solve)(solution, &input);
+
+                if actual != expected {
+                    return Err(Disagreement {
+                        solver: solver.name,
+                        part,
+                        seed,
+                        size,
+                        expected,
+                        actual,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::parse::{lines, ParseError};
+
+    /// Sums numbers, with a correct and a wrong alternative for part one
+    #[derive(Default)]
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+
+        fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+            lines(input)
+                .map(|line| line.parse(line.text, "a number"))
+                .collect()
+        }
+
+        fn part_one(&self, numbers: &Self::Input) -> Answer {
+            numbers.iter().sum::<i64>().into()
+        }
+
+        fn part_two(&self, _numbers: &Self::Input) -> Answer {
+            Answer::Unimplemented
+        }
+    }
+
+    impl Generate for Sum {
+        const SIZE: usize = 10;
+
+        fn generate(&self, rng: &mut Rng, size: usize) -> String {
+            let numbers: Vec<_> = (0..size).map(|_| rng.range(0..=9).to_string()).collect();
+            numbers.join("\n")
+        }
+    }
+
+    impl Alternatives for Sum {
+        fn alternatives() -> Vec<Solver<Self>> {
+            vec![
+                Solver {
+                    name: "reversed",
+                    part: Part::One,
+                    solve: |_, numbers| numbers.iter().rev().sum::<i64>().into(),
+                },
+                Solver {
+                    name: "skip first",
+                    part: Part::One,
+                    solve: |_, numbers| numbers.iter().skip(1).sum::<i64>().into(),
+                },
+            ]
+        }
+    }
+
+    #[test]
+    fn disagreement() {
+        let disagreement = compare(&Sum, 0..10, 5).unwrap_err();
+
+        assert_eq!(disagreement.solver, "skip first");
+        assert_eq!(disagreement.part, Part::One);
+        // The first number of the input of seed 0 is not 0
+        assert_eq!(disagreement.seed, 0);
+        assert_eq!(
+            disagreement.to_string(),
+            format!(
+                "`skip first` answers {} instead of {} to part 1 of the input generated with \
+                 --seed 0 --size 5",
+                disagreement.actual, disagreement.expected
+            )
+        );
+
+        assert_eq!(compare(&Sum, 0..10, 0), Ok(()));
+    }
+}
@@ -0,0 +1,115 @@
+use std::ops::RangeInclusive;
+
+use crate::solution::Solution;
+
+/// Solution which can generate random valid inputs, to stress-test it on inputs larger than the
+/// puzzle ones.
+pub trait Generate: Solution {
+    /// Size of the real puzzle inputs, used when no size is given
+    const SIZE: usize;
+
+    /// Generates an input which the solution can parse and solve. What `size` counts depends on
+    /// the day (lines, side of a grid, ...), and the same `rng` seed gives the same input.
+    fn generate(&self, rng: &mut Rng, size: usize) -> String;
+}
+
+/// Seeded pseudo-random number generator (SplitMix64), so generated inputs can be reproduced.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Number in `range`, which should not be empty
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "empty range {start}..={end}");
+
+        let len = end.abs_diff(start).wrapping_add(1);
+        if len == 0 {
+            // The range covers every i64
+            return self.next_u64() as i64;
+        }
+
+        start.wrapping_add((self.next_u64() % len) as i64)
+    }
+
+    /// Index in a collection of `len` elements
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0..=len as i64 - 1) as usize
+    }
+
+    /// True with a probability of `probability`
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn seeded() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(numbers(7), numbers(7));
+        assert_ne!(numbers(7), numbers(8));
+        // Reference values of SplitMix64
+        assert_eq!(
+            numbers(1234567)[..2],
+            [6457827717110365317, 3203168211198807973]
+        );
+    }
+
+    #[test]
+    fn range() {
+        let mut rng = Rng::new(0);
+
+        for _ in 0..1000 {
+            assert!((-3..=4).contains(&rng.range(-3..=4)));
+            assert_eq!(rng.range(9..=9), 9);
+        }
+        rng.range(i64::MIN..=i64::MAX);
+
+        let mut seen = [false; 6];
+        for _ in 0..100 {
+            seen[rng.index(6)] = true;
+        }
+        assert!(seen.iter().all(|seen| *seen));
+
+        let mut items: Vec<_> = (0..20).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
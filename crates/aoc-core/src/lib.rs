@@ -0,0 +1,12 @@
+pub mod answer;
+pub mod differential;
+pub mod generate;
+pub mod parse;
+pub mod print;
+pub mod solution;
+
+pub use answer::Answer;
+pub use generate::{Generate, Rng};
+pub use parse::ParseError;
+pub use print::Print;
+pub use solution::{Part, Solution};
@@ -0,0 +1,197 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+/// Malformed input, located by its line and column (both starting at 1).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Year and day, set by the runner as the parsers do not know which day they belong to
+    pub day: Option<(u16, u8)>,
+    pub line: usize,
+    pub column: usize,
+    /// What should have been found at `line` and `column` (eg. "an integer" or "`,`")
+    pub expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>) -> Self {
+        Self {
+            day: None,
+            line,
+            column,
+            expected: expected.into(),
+        }
+    }
+
+    /// Error located at the start of `fragment`, which should be a slice of `input`
+    pub fn at(input: &str, fragment: &str, expected: impl Into<String>) -> Self {
+        let before = &input[..offset(input, fragment)];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        Self::new(line, column, expected)
+    }
+
+    /// Error located right after the last line of `input`
+    pub fn end_of_input(input: &str, expected: impl Into<String>) -> Self {
+        Self::new(input.lines().count() + 1, 1, expected)
+    }
+
+    pub fn with_day(self, year: u16, day: u8) -> Self {
+        Self {
+            day: Some((year, day)),
+            ..self
+        }
+    }
+
+    /// Renders the error followed by the offending line of `input`, with its column highlighted
+    pub fn render(&self, input: &str) -> String {
+        let mut rendered = format!("error: {self}");
+
+        if let Some(line) = input.lines().nth(self.line - 1) {
+            let number = self.line.to_string();
+            let padding = " ".repeat(number.len());
+            let marker = " ".repeat(self.column - 1);
+
+            rendered += &format!("\n{padding} |\n{number} | {line}\n{padding} | {marker}^");
+        }
+
+        rendered
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((year, day)) = self.day {
+            write!(f, "day {day} of {year}, ")?;
+        }
+
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Line of an input, used to build errors pointing inside of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// Starting at 1
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Lines of `input` with their numbers
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text,
+    })
+}
+
+impl<'a> Line<'a> {
+    /// Error located at the start of `fragment`, which should be a slice of this line
+    pub fn error(&self, fragment: &str, expected: impl Into<String>) -> ParseError {
+        let column = self.text[..offset(self.text, fragment)].chars().count() + 1;
+
+        ParseError::new(self.number, column, expected)
+    }
+
+    /// Error located right after the end of this line
+    pub fn error_at_end(&self, expected: impl Into<String>) -> ParseError {
+        self.error(&self.text[self.text.len()..], expected)
+    }
+
+    /// Parses `fragment` (a slice of this line), failing with an error pointing at it
+    pub fn parse<T: FromStr>(&self, fragment: &str, expected: &str) -> Result<T, ParseError> {
+        fragment
+            .trim()
+            .parse()
+            .map_err(|_| self.error(fragment, expected))
+    }
+
+    /// Splits `rest` (a slice of this line) around the first `delimiter`
+    pub fn split_once(
+        &self,
+        rest: &'a str,
+        delimiter: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        rest.split_once(delimiter)
+            .ok_or_else(|| self.error_at_end(format!("`{}`", delimiter.trim())))
+    }
+
+    /// Removes `prefix` from `rest` (a slice of this line)
+    pub fn strip_prefix(&self, rest: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        rest.strip_prefix(prefix)
+            .ok_or_else(|| self.error(rest, format!("`{}`", prefix.trim())))
+    }
+}
+
+/// Byte offset of `fragment` inside of `text`, clamped to the bounds of `text`
+fn offset(text: &str, fragment: &str) -> usize {
+    let offset = (fragment.as_ptr() as usize).saturating_sub(text.as_ptr() as usize);
+    let offset = offset.min(text.len());
+
+    (0..=offset)
+        .rev()
+        .find(|i| text.is_char_boundary(*i))
+        .unwrap_or(0)
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INPUT: &str = "498,4 -> 498,6\n503,4 -> 502,x";
+
+    #[test]
+    fn locate() {
+        let x = &INPUT[INPUT.rfind('x').unwrap()..];
+        assert_eq!(
+            ParseError::at(INPUT, x, "an integer"),
+            ParseError::new(2, 14, "an integer")
+        );
+        assert_eq!(ParseError::at(INPUT, INPUT, "").column, 1);
+        assert_eq!(ParseError::end_of_input(INPUT, "").line, 3);
+
+        let line = lines(INPUT).nth(1).unwrap();
+        assert_eq!(line.number, 2);
+
+        let (_, y) = line.split_once(line.text, "502,").unwrap();
+        assert_eq!(
+            line.parse::<i32>(y, "an integer"),
+            Err(line.error(x, "an integer"))
+        );
+        assert_eq!(line.parse::<i32>(&line.text[..3], "an integer"), Ok(503));
+        assert_eq!(line.split_once(y, ",").unwrap_err().column, 15);
+        assert_eq!(line.strip_prefix(y, "y").unwrap_err().column, 14);
+    }
+
+    #[test]
+    fn render() {
+        let error = ParseError::new(2, 14, "an integer").with_day(2022, 14);
+
+        assert_eq!(
+            error.to_string(),
+            "day 14 of 2022, line 2, column 14: expected an integer"
+        );
+        assert_eq!(
+            error.render(INPUT),
+            [
+                "error: day 14 of 2022, line 2, column 14: expected an integer",
+                "  |",
+                "2 | 503,4 -> 502,x",
+                "  |              ^",
+            ]
+            .join("\n")
+        );
+    }
+}
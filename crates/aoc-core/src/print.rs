@@ -0,0 +1,155 @@
+use std::{
+    fmt::{self, Debug, Display},
+    ops::Range,
+};
+
+use crate::{
+    generate::{Generate, Rng},
+    parse::ParseError,
+    solution::Solution,
+};
+
+/// Solution whose parsed input can be written back as text.
+pub trait Print: Solution {
+    /// Canonical text of `input`, in the format of the puzzle inputs, which parses back to an
+    /// equal input
+    fn print(&self, input: &Self::Input) -> String;
+}
+
+/// Parsed input whose printed text does not parse back to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundTripError {
+    /// Generator parameters of the input
+    pub seed: u64,
+    pub size: usize,
+    pub printed: String,
+    /// Error of the parser on the printed text, or `None` if it parses to a different input
+    pub error: Option<ParseError>,
+}
+
+impl Display for RoundTripError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the input generated with --seed {} --size {} ",
+            self.seed, self.size
+        )?;
+
+        match &self.error {
+            Some(error) => write!(
+                f,
+                "is printed as a text which cannot be parsed:\n{}",
+                error.render(&self.printed)
+            ),
+            None => write!(
+                f,
+                "is printed as a text which parses to another input:\n{}",
+                self.printed
+            ),
+        }
+    }
+}
+
+/// Checks that the inputs of `size` generated from each of the `seeds` are parsed back from their
+/// printed text.
+///
+/// Panics if a generated input cannot be parsed, which is a bug of the generator.
+pub fn round_trip<S>(solution: &S, seeds: Range<u64>, size: usize) -> Result<(), RoundTripError>
+where
+    S: Print + Generate,
+    S::Input: PartialEq + Debug,
+{
+    for seed in seeds {
+        let text = solution.generate(&mut Rng::new(seed), size);
+        let input = solution
+            .parse(&text)
+            .unwrap_or_else(|error| panic!("invalid generated input\n{}", error.render(&text)));
+
+        let printed = solution.print(&input);
+        let error = match solution.parse(&printed) {
+            Ok(parsed) if parsed == input => continue,
+            Ok(_) => None,
+            Err(error) => Some(error),
+        };
+
+        return Err(RoundTripError {
+            seed,
+            size,
+            printed,
+            error,
+        });
+    }
+
+    Ok(())
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        answer::Answer,
+        parse::{lines, ParseError},
+    };
+
+    /// Pairs of numbers, printed with a given separator
+    #[derive(Default)]
+    struct Pairs {
+        separator: &'static str,
+    }
+
+    impl Solution for Pairs {
+        type Input = Vec<(u8, u8)>;
+
+        fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+            lines(input)
+                .map(|line| {
+                    let (a, b) = line.split_once(line.text, ",")?;
+                    Ok((line.parse(a, "a number")?, line.parse(b, "a number")?))
+                })
+                .collect()
+        }
+
+        fn part_one(&self, _pairs: &Self::Input) -> Answer {
+            Answer::Unimplemented
+        }
+
+        fn part_two(&self, _pairs: &Self::Input) -> Answer {
+            Answer::Unimplemented
+        }
+    }
+
+    impl Generate for Pairs {
+        const SIZE: usize = 10;
+
+        fn generate(&self, rng: &mut Rng, size: usize) -> String {
+            let pairs: Vec<_> = (0..size)
+                .map(|_| format!("{},{}", rng.range(0..=9), rng.range(0..=9)))
+                .collect();
+            pairs.join("\n")
+        }
+    }
+
+    impl Print for Pairs {
+        fn print(&self, pairs: &Self::Input) -> String {
+            let lines: Vec<_> = pairs
+                .iter()
+                .map(|(a, b)| format!("{a}{}{b}", self.separator))
+                .collect();
+            lines.join("\n")
+        }
+    }
+
+    #[test]
+    fn printed_inputs() {
+        assert_eq!(round_trip(&Pairs { separator: "," }, 0..10, 5), Ok(()));
+
+        let error = round_trip(&Pairs { separator: ";" }, 0..10, 1).unwrap_err();
+        assert_eq!(error.seed, 0);
+        assert_eq!(
+            error.error.map(|error| error.expected),
+            Some("`,`".to_owned())
+        );
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{answer::Answer, parse::ParseError};
+
+/// Solution of a single day.
+///
+/// Parsing is a separate step so both parts can share the parsed input and so its cost can be
+/// measured on its own. A malformed input is reported with a [`ParseError`] instead of a panic.
+/// Puzzle parameters which are not part of the input (eg. the row scanned by day 15) are fields of
+/// the implementing type: `Default` must hold the values for the real input and
+/// [`Solution::example`] the ones used by the puzzle examples.
+pub trait Solution: Default {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(&self, input: &Self::Input) -> Answer;
+
+    fn part_two(&self, input: &Self::Input) -> Answer;
+
+    fn example() -> Self {
+        Self::default()
+    }
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
@@ -0,0 +1,357 @@
+use std::{
+    any::Any,
+    fmt::{self, Display},
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{Answer, Part};
+
+use crate::{days::Day, input::Input};
+
+/// Result of a part of a day run in a batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved {
+        answer: Answer,
+        time: Duration,
+    },
+    /// The part panicked, with this message
+    Panicked(String),
+    /// Not run, as only the other part was asked for or the input could not be parsed
+    Skipped,
+}
+
+/// Answers and timings of a day run in a batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    /// Time spent parsing the input, or the reason it could not be read or parsed
+    pub parse: Result<Duration, String>,
+    pub part_one: Outcome,
+    pub part_two: Outcome,
+}
+
+/// Runs `days` on `jobs` threads, each thread taking the next day to run when it is done with
+/// one. A day which cannot be parsed or panics does not stop the others.
+///
+/// The reports are sorted by year and day.
+pub fn run(days: &[&Day], part: Option<Part>, input: &Input, jobs: usize) -> Vec<Report> {
+    let next = AtomicUsize::new(0);
+    let reports = Mutex::new(Vec::with_capacity(days.len()));
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            scope.spawn(|| {
+                while let Some(day) = days.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let report = run_day(day, part, input);
+                    reports.lock().unwrap().push(report);
+                }
+            });
+        }
+    });
+
+    let mut reports = reports.into_inner().unwrap();
+    reports.sort_by_key(|report| (report.year, report.day));
+    reports
+}
+
+fn run_day(day: &Day, part: Option<Part>, input: &Input) -> Report {
+    let mut report = Report {
+        year: day.year,
+        day: day.number,
+        parse: Err(String::new()),
+        part_one: Outcome::Skipped,
+        part_two: Outcome::Skipped,
+    };
+
+    let text = match input.read(day) {
+        Ok(text) => text,
+        Err(message) => {
+            report.parse = Err(message);
+            return report;
+        }
+    };
+
+    let start = Instant::now();
+    let parsed = match catch(|| (day.prepare)(&text, input.example)) {
+        Ok(Ok(parsed)) => parsed,
+        Ok(Err(error)) => {
+            report.parse = Err(error.with_day(day.year, day.number).render(&text));
+            return report;
+        }
+        Err(message) => {
+            report.parse = Err(format!("panicked while parsing: {message}"));
+            return report;
+        }
+    };
+    report.parse = Ok(start.elapsed());
+
+    if part != Some(Part::Two) {
+        report.part_one = solve(|| parsed.part_one());
+    }
+
+    if part != Some(Part::One) {
+        report.part_two = solve(|| parsed.part_two());
+    }
+
+    report
+}
+
+fn solve(part: impl FnOnce() -> Answer) -> Outcome {
+    let start = Instant::now();
+
+    match catch(part) {
+        Ok(answer) => Outcome::Solved {
+            answer,
+            time: start.elapsed(),
+        },
+        Err(message) => Outcome::Panicked(message),
+    }
+}
+
+/// Runs `f`, returning the message of its panic if it panics
+fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    // The state touched by `f` is dropped with the day when it panics
+    panic::catch_unwind(AssertUnwindSafe(f)).map_err(|payload| panic_message(&*payload))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_owned()
+    }
+}
+
+impl Report {
+    pub fn failed(&self) -> bool {
+        self.parse.is_err() || self.outcomes().iter().any(|(_, outcome)| outcome.failed())
+    }
+
+    /// Time spent parsing and solving the day
+    pub fn total(&self) -> Duration {
+        let parts = self
+            .outcomes()
+            .into_iter()
+            .map(|(_, outcome)| match outcome {
+                Outcome::Solved { time, .. } => *time,
+                Outcome::Panicked(_) | Outcome::Skipped => Duration::ZERO,
+            });
+
+        self.parse.clone().unwrap_or_default() + parts.sum::<Duration>()
+    }
+
+    fn outcomes(&self) -> [(&'static str, &Outcome); 2] {
+        [("one", &self.part_one), ("two", &self.part_two)]
+    }
+}
+
+impl Outcome {
+    fn failed(&self) -> bool {
+        matches!(self, Outcome::Panicked(_))
+    }
+
+    /// Answer and time columns of the summary table
+    fn cells(&self, parsed: bool) -> (String, String) {
+        match self {
+            Outcome::Solved { answer, .. } if answer.is_multiline() => {
+                ("(below)".to_owned(), self.time())
+            }
+            Outcome::Solved { answer, .. } => (answer.to_string(), self.time()),
+            Outcome::Panicked(_) => ("PANIC".to_owned(), "-".to_owned()),
+            Outcome::Skipped if parsed => ("-".to_owned(), "-".to_owned()),
+            Outcome::Skipped => ("ERROR".to_owned(), "-".to_owned()),
+        }
+    }
+
+    fn time(&self) -> String {
+        match self {
+            Outcome::Solved { time, .. } => format!("{time:.2?}"),
+            Outcome::Panicked(_) | Outcome::Skipped => "-".to_owned(),
+        }
+    }
+}
+
+/// Table with a row per day run in a batch, followed by the multi-line answers, the failures and
+/// a summary
+pub struct Summary<'a> {
+    pub reports: &'a [Report],
+    pub jobs: usize,
+    /// Time from the start of the first day to the end of the last one
+    pub wall: Duration,
+}
+
+impl Summary<'_> {
+    pub fn passed(&self) -> bool {
+        !self.reports.iter().any(Report::failed)
+    }
+}
+
+impl Display for Summary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows: Vec<_> = self
+            .reports
+            .iter()
+            .map(|report| {
+                let parsed = report.parse.is_ok();
+                let (one, one_time) = report.part_one.cells(parsed);
+                let (two, two_time) = report.part_two.cells(parsed);
+                let total = format!("{:.2?}", report.total());
+
+                let (year, day) = (report.year.to_string(), report.day.to_string());
+
+                [year, day, one, one_time, two, two_time, total]
+            })
+            .collect();
+
+        let header = [
+            "Year", "Day", "Part one", "Time", "Part two", "Time", "Total",
+        ];
+        let widths: Vec<usize> = (0..header.len())
+            .map(|column| {
+                rows.iter()
+                    .map(|row| row[column].chars().count())
+                    .chain([header[column].len()])
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        for row in [header.map(String::from)].iter().chain(&rows) {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:width$}"))
+                .collect();
+            writeln!(f, "{}", cells.join("  ").trim_end())?;
+        }
+
+        for report in self.reports {
+            let day = format!("Day {} of {}", report.day, report.year);
+
+            if let Err(message) = &report.parse {
+                writeln!(f, "\n{day}: {message}")?;
+            }
+
+            for (name, outcome) in report.outcomes() {
+                match outcome {
+                    Outcome::Solved { answer, .. } if answer.is_multiline() => {
+                        writeln!(f, "\n{day}, part {name}:\n{answer}")?
+                    }
+                    Outcome::Panicked(message) => {
+                        writeln!(f, "\n{day}, part {name} panicked: {message}")?
+                    }
+                    Outcome::Solved { .. } | Outcome::Skipped => (),
+                }
+            }
+        }
+
+        let failed = self.reports.iter().filter(|report| report.failed()).count();
+        let solving: Duration = self.reports.iter().map(Report::total).sum();
+        let days = match self.reports.len() {
+            1 => "1 day".to_owned(),
+            len => format!("{len} days"),
+        };
+
+        write!(
+            f,
+            "\n{days} in {:.2?} with {} jobs ({:.2?} of solving), {failed} failed",
+            self.wall, self.jobs, solving
+        )
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        days::{Parsed, DAYS},
+        input::Source,
+    };
+
+    struct Panicking;
+
+    impl Parsed for Panicking {
+        fn part_one(&self) -> Answer {
+            Answer::Number(1)
+        }
+
+        fn part_two(&self) -> Answer {
+            panic!("no part two")
+        }
+    }
+
+    fn example() -> Input {
+        Input {
+            source: Source::Example(None),
+            example: true,
+        }
+    }
+
+    #[test]
+    fn sorted_reports() {
+        let days: Vec<_> = DAYS.iter().take(6).rev().collect();
+        let reports = run(&days, Some(Part::One), &example(), 3);
+
+        let numbers: Vec<_> = reports.iter().map(|report| report.day).collect();
+        assert_eq!(numbers, [1, 2, 3, 4, 5, 6]);
+        assert!(matches!(
+            &reports[0].part_one,
+            Outcome::Solved { answer, .. } if *answer == Answer::Number(24000)
+        ));
+        assert_eq!(reports[0].part_two, Outcome::Skipped);
+        assert!(reports.iter().all(|report| !report.failed()));
+    }
+
+    #[test]
+    fn isolated_failures() {
+        let panicking = Day {
+            prepare: |_, _| Ok(Box::new(Panicking)),
+            ..*Day::find(2022, 2).unwrap()
+        };
+        let days = [
+            Day::find(2022, 1).unwrap(),
+            &panicking,
+            Day::find(2022, 3).unwrap(),
+        ];
+
+        let reports = run(&days, None, &example(), 2);
+        assert!(!reports[0].failed());
+        assert!(!reports[2].failed());
+        assert_eq!(
+            reports[1].part_two,
+            Outcome::Panicked("no part two".to_owned())
+        );
+        assert!(matches!(reports[1].part_one, Outcome::Solved { .. }));
+
+        let missing = Input {
+            source: Source::Example(Some("missing".to_owned())),
+            example: true,
+        };
+        let reports = run(&days[..1], None, &missing, 4);
+        assert!(reports[0].parse.is_err());
+
+        let summary = Summary {
+            reports: &reports,
+            jobs: 4,
+            wall: Duration::ZERO,
+        };
+        assert!(!summary.passed());
+        let summary = summary.to_string();
+        assert!(summary
+            .starts_with("Year  Day  Part one  Time  Part two  Time  Total\n2022  1    ERROR"));
+        assert!(summary.contains("\nDay 1 of 2022: cannot read"));
+        assert!(summary.ends_with("\n\n1 day in 0.00ns with 4 jobs (0.00ns of solving), 1 failed"));
+    }
+}
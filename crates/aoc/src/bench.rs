@@ -0,0 +1,227 @@
+use std::{
+    fmt::{self, Display},
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use aoc_core::ParseError;
+
+use crate::days::Day;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    /// Runs which are not measured, to warm up caches and the allocator
+    pub warmup: u32,
+    pub iterations: u32,
+    pub json: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            iterations: 10,
+            json: false,
+        }
+    }
+}
+
+/// Summary of the measured durations of a step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    /// Population standard deviation
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Self {
+                mean: Duration::ZERO,
+                median: Duration::ZERO,
+                stddev: Duration::ZERO,
+            };
+        }
+
+        let nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        let len = nanos.len() as f64;
+
+        let mean = nanos.iter().sum::<f64>() / len;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / len;
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        Self {
+            mean: Duration::from_nanos(mean.round() as u64),
+            median,
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+
+    fn to_json(self) -> String {
+        format!(
+            r#"{{"mean_ns":{},"median_ns":{},"stddev_ns":{}}}"#,
+            self.mean.as_nanos(),
+            self.median.as_nanos(),
+            self.stddev.as_nanos()
+        )
+    }
+}
+
+/// Timings of a day, measured separately for the parsing and each part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub iterations: u32,
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+impl Report {
+    pub fn to_json(&self) -> String {
+        format!(
+            r#"{{"year":{},"day":{},"iterations":{},"parse":{},"part_one":{},"part_two":{}}}"#,
+            self.year,
+            self.day,
+            self.iterations,
+            self.parse.to_json(),
+            self.part_one.to_json(),
+            self.part_two.to_json()
+        )
+    }
+}
+
+/// Parses and solves `input` `warmup + iterations` times, measuring only the last `iterations`
+pub fn bench(
+    day: &Day,
+    input: &str,
+    example: bool,
+    options: Options,
+) -> Result<Report, ParseError> {
+    let mut parse = Vec::new();
+    let mut part_one = Vec::new();
+    let mut part_two = Vec::new();
+
+    for run in 0..options.warmup + options.iterations {
+        let start = Instant::now();
+        let parsed = (day.prepare)(black_box(input), example)?;
+        let parsed_at = Instant::now();
+        black_box(parsed.part_one());
+        let part_one_at = Instant::now();
+        black_box(parsed.part_two());
+        let part_two_at = Instant::now();
+
+        if run >= options.warmup {
+            parse.push(parsed_at - start);
+            part_one.push(part_one_at - parsed_at);
+            part_two.push(part_two_at - part_one_at);
+        }
+    }
+
+    Ok(Report {
+        year: day.year,
+        day: day.number,
+        iterations: options.iterations,
+        parse: Stats::new(&parse),
+        part_one: Stats::new(&part_one),
+        part_two: Stats::new(&part_two),
+    })
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "mean {:.2?} ± {:.2?}, median {:.2?}",
+            self.mean, self.stddev, self.median
+        )
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Day {} of {} ({} iterations)",
+            self.day, self.year, self.iterations
+        )?;
+        writeln!(f, "Parse:    {}", self.parse)?;
+        writeln!(f, "Part one: {}", self.part_one)?;
+        write!(f, "Part two: {}", self.part_two)
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn millis(samples: &[u64]) -> Vec<Duration> {
+        samples
+            .iter()
+            .map(|ms| Duration::from_millis(*ms))
+            .collect()
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::new(&millis(&[2, 4, 4, 4, 5, 5, 7, 9]));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.median, Duration::from_nanos(4_500_000));
+        assert_eq!(stats.stddev, Duration::from_millis(2));
+
+        let stats = Stats::new(&millis(&[3, 1, 2]));
+        assert_eq!(stats.median, Duration::from_millis(2));
+
+        assert_eq!(Stats::new(&[]).mean, Duration::ZERO);
+    }
+
+    #[test]
+    fn json() {
+        let stats = Stats::new(&millis(&[1]));
+        let report = Report {
+            year: 2022,
+            day: 3,
+            iterations: 1,
+            parse: stats,
+            part_one: stats,
+            part_two: stats,
+        };
+
+        let stats = r#"{"mean_ns":1000000,"median_ns":1000000,"stddev_ns":0}"#;
+        assert_eq!(
+            report.to_json(),
+            format!(
+                r#"{{"year":2022,"day":3,"iterations":1,"parse":{stats},"part_one":{stats},"part_two":{stats}}}"#
+            )
+        );
+    }
+
+    #[test]
+    fn bench_example() {
+        let day = Day::find(2022, 1).unwrap();
+        let input = include_str!("../../../2022/day-1/input.test.txt");
+        let options = Options {
+            warmup: 0,
+            iterations: 3,
+            json: false,
+        };
+
+        let report = bench(day, input, true, options).unwrap();
+        assert_eq!(report.day, 1);
+        assert_eq!(report.iterations, 3);
+        assert!(bench(day, "1\nx", true, options).is_err());
+    }
+}
@@ -0,0 +1,177 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::fetch::Fetcher;
+
+/// Per-user store of the puzzle inputs, as `<dir>/<year>/day-<day>.txt` files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cache {
+    pub dir: PathBuf,
+}
+
+/// Whether `Cache::fetch` downloaded an input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    AlreadyCached,
+}
+
+impl Cache {
+    /// Cache of the current user: `$AOC_CACHE_DIR`, or the `aoc` directory of `$XDG_CACHE_HOME`,
+    /// `~/.cache` or `%LOCALAPPDATA%`
+    pub fn user() -> Result<Self, String> {
+        let var = |name| env::var_os(name).filter(|value| !value.is_empty());
+
+        let dir = if let Some(dir) = var("AOC_CACHE_DIR") {
+            PathBuf::from(dir)
+        } else if let Some(cache) = var("XDG_CACHE_HOME") {
+            Path::new(&cache).join("aoc")
+        } else if let Some(home) = var("HOME") {
+            Path::new(&home).join(".cache/aoc")
+        } else if let Some(local) = var("LOCALAPPDATA") {
+            Path::new(&local).join("aoc")
+        } else {
+            return Err("cannot find the cache directory, set AOC_CACHE_DIR".to_owned());
+        };
+
+        Ok(Self { dir })
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day-{day}.txt"))
+    }
+
+    /// Input of `day`, or `None` if it is not cached
+    pub fn read(&self, year: u16, day: u8) -> Result<Option<String>, String> {
+        let path = self.path(year, day);
+
+        match fs::read_to_string(&path) {
+            Ok(input) => Ok(Some(input)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(format!("cannot read '{}' ({err})", path.display())),
+        }
+    }
+
+    /// Downloads the input of `day` with `fetcher`, unless it is already cached and not `force`
+    pub fn fetch(
+        &self,
+        year: u16,
+        day: u8,
+        fetcher: &dyn Fetcher,
+        force: bool,
+    ) -> Result<Fetched, String> {
+        let path = self.path(year, day);
+        if !force && path.exists() {
+            return Ok(Fetched::AlreadyCached);
+        }
+
+        let input = fetcher.fetch(year, day)?;
+        if input.trim().is_empty() {
+            return Err(format!("the input of day {day} of {year} is empty"));
+        }
+
+        // Written next to its final path then renamed, so an interrupted download is not cached
+        let dir = path
+            .parent()
+            .expect("cached inputs should be in a year directory");
+        let partial = path.with_extension("txt.partial");
+        fs::create_dir_all(dir)
+            .and_then(|()| fs::write(&partial, input))
+            .and_then(|()| fs::rename(&partial, &path))
+            .map_err(|err| format!("cannot write '{}' ({err})", path.display()))?;
+
+        Ok(Fetched::Downloaded)
+    }
+
+    /// Session token of the website: `$AOC_SESSION`, or the content of the `session` file of the
+    /// cache
+    pub fn session(&self) -> Result<String, String> {
+        // Both may end with a new line, which is not part of the token
+        let token = |session: String| Some(session.trim().to_owned()).filter(|s| !s.is_empty());
+
+        if let Some(session) = env::var("AOC_SESSION").ok().and_then(token) {
+            return Ok(session);
+        }
+
+        let path = self.dir.join("session");
+        match fs::read_to_string(&path).ok().and_then(token) {
+            Some(session) => Ok(session),
+            None => Err(format!(
+                "no session token, set AOC_SESSION or write it to '{}'",
+                path.display()
+            )),
+        }
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::fetch::Directory;
+
+    /// Counts the inputs it is asked for
+    struct Counting<'a> {
+        inner: &'a dyn Fetcher,
+        count: Cell<u32>,
+    }
+
+    impl Fetcher for Counting<'_> {
+        fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+            self.count.set(self.count.get() + 1);
+            self.inner.fetch(year, day)
+        }
+    }
+
+    #[test]
+    fn fetch() {
+        let root = env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let server = root.join("server");
+        fs::create_dir_all(server.join("2022")).unwrap();
+        fs::write(server.join("2022/day-1.txt"), "1000\n2000\n").unwrap();
+        fs::write(server.join("2022/day-2.txt"), "\n").unwrap();
+
+        let cache = Cache {
+            dir: root.join("cache"),
+        };
+        let fetcher = Counting {
+            inner: &Directory { root: server },
+            count: Cell::new(0),
+        };
+
+        assert_eq!(cache.read(2022, 1), Ok(None));
+        assert_eq!(
+            cache.fetch(2022, 1, &fetcher, false),
+            Ok(Fetched::Downloaded)
+        );
+        assert_eq!(
+            cache.fetch(2022, 1, &fetcher, false),
+            Ok(Fetched::AlreadyCached)
+        );
+        assert_eq!(fetcher.count.get(), 1);
+        assert_eq!(cache.read(2022, 1), Ok(Some("1000\n2000\n".to_owned())));
+        assert!(cache.path(2022, 1).ends_with("cache/2022/day-1.txt"));
+
+        assert_eq!(
+            cache.fetch(2022, 1, &fetcher, true),
+            Ok(Fetched::Downloaded)
+        );
+        assert_eq!(fetcher.count.get(), 2);
+
+        // Nothing is cached when the download fails
+        assert!(cache.fetch(2022, 2, &fetcher, false).is_err());
+        assert!(cache.fetch(2022, 3, &fetcher, false).is_err());
+        assert_eq!(cache.read(2022, 2), Ok(None));
+        assert_eq!(cache.read(2022, 3), Ok(None));
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,417 @@
+use std::{
+    fmt::{self, Display},
+    fs, io,
+};
+
+use aoc_core::{parse::lines, Answer, ParseError, Part};
+
+use crate::{
+    days::Day,
+    input::{Input, Source},
+};
+
+/// Name of the file, next to the inputs of a day, which records their answers
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// Recorded answers of one input of a day.
+///
+/// In the answers file each input is a `[<input file>]` section followed by a `part one: <answer>`
+/// and a `part two: <answer>` line. Multi-line answers start on the line after the `part` key and
+/// end at the next blank line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expected {
+    /// File name of the input (eg. `input.txt` or `input-2.test.txt`)
+    pub file: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        answer: String,
+    },
+    /// No answer was recorded for the part
+    Missing,
+    /// The input could not be read or parsed
+    Error(String),
+    /// Not checked, as only the other part was asked for
+    Skipped,
+}
+
+/// Result of checking one input of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub year: u16,
+    pub day: u8,
+    pub file: String,
+    pub part_one: Status,
+    pub part_two: Status,
+}
+
+impl Expected {
+    /// Parses the content of an answers file
+    pub fn parse_all(text: &str) -> Result<Vec<Expected>, ParseError> {
+        let mut inputs: Vec<Expected> = Vec::new();
+        let mut lines = lines(text).peekable();
+
+        while let Some(line) = lines.next() {
+            let trimmed = line.text.trim();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            if let Some(file) = trimmed.strip_prefix('[') {
+                let file = line.split_once(file, "]")?.0.trim();
+                if source(file).is_none() {
+                    return Err(line.error(file, "an input file name"));
+                }
+
+                inputs.push(Expected {
+                    file: file.to_owned(),
+                    part_one: None,
+                    part_two: None,
+                });
+                continue;
+            }
+
+            let (key, value) = line.split_once(line.text, ":")?;
+            let input = inputs
+                .last_mut()
+                .ok_or_else(|| line.error(line.text, "an `[<input file>]` section"))?;
+            let answer = match key.trim() {
+                "part one" => &mut input.part_one,
+                "part two" => &mut input.part_two,
+                _ => return Err(line.error(key, "`part one` or `part two`")),
+            };
+
+            let value = value.trim();
+            *answer = if value.is_empty() {
+                let mut drawing: Vec<&str> = Vec::new();
+                while let Some(line) = lines.next_if(|line| !line.text.trim().is_empty()) {
+                    drawing.push(line.text.trim_end());
+                }
+
+                if drawing.is_empty() {
+                    return Err(line.error_at_end("an answer"));
+                }
+
+                Some(drawing.join("\n"))
+            } else {
+                Some(value.to_owned())
+            };
+        }
+
+        Ok(inputs)
+    }
+
+    fn status(&self, part: Part, answer: &Answer) -> Status {
+        let expected = match part {
+            Part::One => &self.part_one,
+            Part::Two => &self.part_two,
+        };
+
+        let Some(expected) = expected else {
+            return Status::Missing;
+        };
+
+        let answer = answer
+            .to_string()
+            .lines()
+            .map(str::trim_end)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        if *expected == answer {
+            Status::Pass
+        } else {
+            Status::Fail {
+                expected: expected.clone(),
+                answer,
+            }
+        }
+    }
+}
+
+/// Input read from the file `file` of a day, or `None` if it is not named like an input
+fn source(file: &str) -> Option<Source> {
+    match file {
+        "input.txt" => Some(Source::Puzzle),
+        "input.test.txt" => Some(Source::Example(None)),
+        _ => {
+            let name = file.strip_prefix("input-")?.strip_suffix(".test.txt")?;
+            Some(Source::Example(Some(name.to_owned())))
+        }
+    }
+}
+
+/// Checks the answers of `day` on every input of its answers file, or only `part` if it is set
+///
+/// The real input is always checked, and reported as missing when it has no recorded answers.
+pub fn check_day(day: &Day, part: Option<Part>) -> Vec<Check> {
+    let path = day.dir().join(ANSWERS_FILE);
+    let mut inputs = match fs::read_to_string(&path) {
+        Ok(text) => match Expected::parse_all(&text) {
+            Ok(inputs) => inputs,
+            Err(error) => {
+                let error = error.with_day(day.year, day.number);
+                return vec![Check::error(day, ANSWERS_FILE, error.render(&text))];
+            }
+        },
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(error) => {
+            let message = format!("cannot read '{}' ({error})", path.display());
+            return vec![Check::error(day, ANSWERS_FILE, message)];
+        }
+    };
+
+    if !inputs.iter().any(|input| input.file == "input.txt") {
+        inputs.insert(
+            0,
+            Expected {
+                file: "input.txt".to_owned(),
+                part_one: None,
+                part_two: None,
+            },
+        );
+    }
+
+    inputs
+        .iter()
+        .map(|expected| check_input(day, expected, part))
+        .collect()
+}
+
+fn check_input(day: &Day, expected: &Expected, part: Option<Part>) -> Check {
+    let source = source(&expected.file).expect("answers file should only list inputs");
+    let input = Input {
+        example: source != Source::Puzzle,
+        source,
+    };
+
+    let text = match input.read(day) {
+        Ok(text) => text,
+        Err(message) => return Check::error(day, &expected.file, message),
+    };
+
+    let parsed = match (day.prepare)(&text, input.example) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            let message = error.with_day(day.year, day.number).render(&text);
+            return Check::error(day, &expected.file, message);
+        }
+    };
+
+    let mut check = Check {
+        year: day.year,
+        day: day.number,
+        file: expected.file.clone(),
+        part_one: Status::Skipped,
+        part_two: Status::Skipped,
+    };
+
+    if part != Some(Part::Two) {
+        check.part_one = expected.status(Part::One, &parsed.part_one());
+    }
+
+    if part != Some(Part::One) {
+        check.part_two = expected.status(Part::Two, &parsed.part_two());
+    }
+
+    check
+}
+
+impl Check {
+    fn error(day: &Day, file: &str, message: String) -> Self {
+        Self {
+            year: day.year,
+            day: day.number,
+            file: file.to_owned(),
+            part_one: Status::Error(message.clone()),
+            part_two: Status::Error(message),
+        }
+    }
+
+    fn statuses(&self) -> [(&'static str, &Status); 2] {
+        [("one", &self.part_one), ("two", &self.part_two)]
+    }
+}
+
+/// Table with a row per checked input, followed by the details of the failures and a summary
+pub struct Table<'a>(pub &'a [Check]);
+
+impl Table<'_> {
+    /// Whether every recorded answer was found
+    pub fn passed(&self) -> bool {
+        self.0.iter().all(|check| {
+            check
+                .statuses()
+                .iter()
+                .all(|(_, status)| !matches!(status, Status::Fail { .. } | Status::Error(_)))
+        })
+    }
+}
+
+impl Display for Table<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .0
+            .iter()
+            .map(|check| check.file.len())
+            .max()
+            .unwrap_or(0)
+            .max("Input".len());
+
+        writeln!(f, "Year  Day  {:width$}  Part one  Part two", "Input")?;
+        for check in self.0 {
+            writeln!(
+                f,
+                "{:<4}  {:<3}  {:width$}  {:8}  {}",
+                check.year, check.day, check.file, check.part_one, check.part_two
+            )?;
+        }
+
+        let (mut passed, mut failed, mut missing, mut errors) = (0, 0, 0, 0);
+        for check in self.0 {
+            for (name, status) in check.statuses() {
+                match status {
+                    Status::Fail { expected, answer } => {
+                        let separator = if expected.contains('\n') || answer.contains('\n') {
+                            "\n"
+                        } else {
+                            " "
+                        };
+
+                        write!(
+                            f,
+                            "\nDay {} of {}, {}, part {name}: expected{separator}{expected}\
+                             {separator}but got{separator}{answer}",
+                            check.day, check.year, check.file
+                        )?;
+                        failed += 1;
+                    }
+                    // Both parts share the error of their input
+                    Status::Error(message) if name == "one" => {
+                        write!(
+                            f,
+                            "\nDay {} of {}, {}: {message}",
+                            check.day, check.year, check.file
+                        )?;
+                        errors += 1;
+                    }
+                    Status::Error(_) | Status::Skipped => (),
+                    Status::Pass => passed += 1,
+                    Status::Missing => missing += 1,
+                }
+            }
+        }
+
+        write!(
+            f,
+            "\n{passed} passed, {failed} failed, {missing} missing, {errors} errors"
+        )
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Pass => "pass",
+            Status::Fail { .. } => "FAIL",
+            Status::Missing => "missing",
+            Status::Error(_) => "ERROR",
+            Status::Skipped => "-",
+        };
+
+        // Pads like a `str` so the table columns line up
+        f.pad(status)
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ANSWERS: &str = "# Day 1
+[input.test.txt]
+part one: 24000
+part two: 45000
+
+[input-big.test.txt]
+part two:
+##..
+.##.
+
+part one: 7
+";
+
+    #[test]
+    fn parse_answers() {
+        let inputs = Expected::parse_all(ANSWERS).unwrap();
+
+        assert_eq!(
+            inputs,
+            vec![
+                Expected {
+                    file: "input.test.txt".to_owned(),
+                    part_one: Some("24000".to_owned()),
+                    part_two: Some("45000".to_owned()),
+                },
+                Expected {
+                    file: "input-big.test.txt".to_owned(),
+                    part_one: Some("7".to_owned()),
+                    part_two: Some("##..\n.##.".to_owned()),
+                },
+            ]
+        );
+
+        let error = Expected::parse_all("part one: 1").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        let error = Expected::parse_all("[input.txt]\npart three: 1").unwrap_err();
+        assert_eq!(error.expected, "`part one` or `part two`");
+        let error = Expected::parse_all("[answers.txt]").unwrap_err();
+        assert_eq!(error.expected, "an input file name");
+    }
+
+    #[test]
+    fn sources() {
+        assert_eq!(source("input.txt"), Some(Source::Puzzle));
+        assert_eq!(source("input.test.txt"), Some(Source::Example(None)));
+        assert_eq!(
+            source("input-2.test.txt"),
+            Some(Source::Example(Some("2".to_owned())))
+        );
+        assert_eq!(source("input-2.txt"), None);
+    }
+
+    #[test]
+    fn check_example() {
+        let day = Day::find(2022, 1).unwrap();
+        let expected = &Expected::parse_all(ANSWERS).unwrap()[0];
+        let check = check_input(day, expected, None);
+        assert_eq!(
+            (&check.part_one, &check.part_two),
+            (&Status::Pass, &Status::Pass)
+        );
+
+        let expected = Expected {
+            part_one: Some("1".to_owned()),
+            part_two: None,
+            ..expected.clone()
+        };
+        let check = check_input(day, &expected, Some(Part::One));
+        assert_eq!(
+            check.part_one,
+            Status::Fail {
+                expected: "1".to_owned(),
+                answer: "24000".to_owned()
+            }
+        );
+        assert_eq!(check.part_two, Status::Skipped);
+        assert!(!Table(&[check]).passed());
+    }
+}
@@ -0,0 +1,260 @@
+use std::{
+    fmt::{self, Display},
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use animation::Canvas;
+use aoc_core::{Answer, ParseError, Part, Rng, Solution};
+use raster::Image;
+
+/// Parsed input of a day, ready to be solved.
+pub trait Parsed {
+    fn part_one(&self) -> Answer;
+
+    fn part_two(&self) -> Answer;
+}
+
+struct Prepared<S: Solution> {
+    solution: S,
+    input: S::Input,
+}
+
+impl<S: Solution> Parsed for Prepared<S> {
+    fn part_one(&self) -> Answer {
+        self.solution.part_one(&self.input)
+    }
+
+    fn part_two(&self) -> Answer {
+        self.solution.part_two(&self.input)
+    }
+}
+
+type Prepare = Result<Box<dyn Parsed>, ParseError>;
+
+fn prepare<S: Solution + 'static>(input: &str, example: bool) -> Prepare {
+    let solution = if example { S::example() } else { S::default() };
+    let input = solution.parse(input)?;
+
+    Ok(Box::new(Prepared { solution, input }))
+}
+
+type Animate = fn(input: &str, example: bool, part: Part, canvas: &mut Screen) -> Animated;
+
+type Animated = Result<(), ParseError>;
+
+/// Canvas drawing in the terminal, or only recording the frames
+pub type Screen = Canvas<Box<dyn Write>>;
+
+fn animate<S: animation::Animate + 'static>(
+    input: &str,
+    example: bool,
+    part: Part,
+    canvas: &mut Screen,
+) -> Animated {
+    let solution = if example { S::example() } else { S::default() };
+    let input = solution.parse(input)?;
+
+    solution.animate(&input, part, canvas);
+
+    Ok(())
+}
+
+type Render = fn(input: &str, example: bool, part: Part) -> Result<Image, ParseError>;
+
+fn render<S: raster::Render + 'static>(
+    input: &str,
+    example: bool,
+    part: Part,
+) -> Result<Image, ParseError> {
+    let solution = if example { S::example() } else { S::default() };
+    let input = solution.parse(input)?;
+
+    Ok(solution.render(&input, part))
+}
+
+type Generate = fn(example: bool, rng: &mut Rng, size: Option<usize>) -> String;
+
+fn generate<S: aoc_core::Generate + 'static>(
+    example: bool,
+    rng: &mut Rng,
+    size: Option<usize>,
+) -> String {
+    let solution = if example { S::example() } else { S::default() };
+
+    solution.generate(rng, size.unwrap_or(S::SIZE))
+}
+
+pub struct Day {
+    /// Year of the calendar of the day, which keys its inputs in the input cache
+    pub year: u16,
+    pub number: u8,
+    /// Parses the input with the parameters of the real input or, if `example` is set, with the
+    /// ones of the puzzle examples
+    pub prepare: fn(input: &str, example: bool) -> Prepare,
+    /// Draws the simulation of a part on a canvas, for the days which can be animated
+    pub animate: Option<Animate>,
+    /// Exports the final state of a part as an image, for the days which can be rendered
+    pub render: Option<Render>,
+    /// Generates a random input of the given size, or of the size of the real inputs, for the
+    /// days which have a generator
+    pub generate: Option<Generate>,
+}
+
+impl Day {
+    const fn new(
+        year: u16,
+        number: u8,
+        prepare: fn(input: &str, example: bool) -> Prepare,
+    ) -> Self {
+        Self {
+            year,
+            number,
+            prepare,
+            animate: None,
+            render: None,
+            generate: None,
+        }
+    }
+
+    pub fn find(year: u16, number: u8) -> Option<&'static Day> {
+        DAYS.iter()
+            .find(|day| day.year == year && day.number == number)
+    }
+
+    /// Directory of the day crate (`<year>/day-<day>`), which holds its inputs
+    pub fn dir(&self) -> PathBuf {
+        workspace_dir()
+            .join(self.year.to_string())
+            .join(format!("day-{}", self.number))
+    }
+}
+
+impl Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {} of {}", self.number, self.year)
+    }
+}
+
+/// Years with registered days, in order
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = DAYS.iter().map(|day| day.year).collect();
+    years.dedup();
+
+    years
+}
+
+/// Most recent year with registered days, used when a command does not name one
+pub fn latest_year() -> u16 {
+    DAYS.last().map_or(FIRST_YEAR, |day| day.year)
+}
+
+/// Year of the first Advent of Code calendar
+pub const FIRST_YEAR: u16 = 2015;
+
+/// Root of the cargo workspace holding the runner and the days
+pub fn workspace_dir() -> PathBuf {
+    let runner = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    runner.ancestors().nth(2).unwrap_or(runner).to_path_buf()
+}
+
+/// Registers a day, followed by the optional features it implements (`animate`, `render` and
+/// `generate`)
+macro_rules! day {
+    ($year:literal, $number:literal, $solution:ty $(, $feature:ident)*) => {
+        Day {
+            $($feature: Some($feature::<$solution>),)*
+            ..Day::new($year, $number, prepare::<$solution>)
+        }
+    };
+}
+
+/// Registered days, ordered by year and number (`aoc new` adds new days here)
+pub const DAYS: &[Day] = &[
+    day!(2022, 1, aoc_2022_day_1::Day1, generate),
+    day!(2022, 2, aoc_2022_day_2::Day2, generate),
+    day!(2022, 3, aoc_2022_day_3::Day3, generate),
+    day!(2022, 4, aoc_2022_day_4::Day4, generate),
+    day!(2022, 5, aoc_2022_day_5::Day5, generate),
+    day!(2022, 6, aoc_2022_day_6::Day6, generate),
+    day!(2022, 7, aoc_2022_day_7::Day7, generate),
+    day!(2022, 8, aoc_2022_day_8::Day8, render, generate),
+    day!(2022, 9, aoc_2022_day_9::Day9, animate, generate),
+    day!(2022, 10, aoc_2022_day_10::Day10, generate),
+    day!(2022, 11, aoc_2022_day_11::Day11, generate),
+    day!(2022, 12, aoc_2022_day_12::Day12, render, generate),
+    day!(2022, 13, aoc_2022_day_13::Day13, generate),
+    day!(2022, 14, aoc_2022_day_14::Day14, animate, render, generate),
+    day!(2022, 15, aoc_2022_day_15::Day15, generate),
+    day!(2022, 16, aoc_2022_day_16::Day16, generate),
+    day!(2022, 17, aoc_2022_day_17::Day17, animate, generate),
+    day!(2022, 18, aoc_2022_day_18::Day18, generate),
+    day!(2022, 19, aoc_2022_day_19::Day19, generate),
+    day!(2022, 20, aoc_2022_day_20::Day20, generate),
+    day!(2022, 21, aoc_2022_day_21::Day21, generate),
+    day!(2022, 22, aoc_2022_day_22::Day22, generate),
+    day!(2022, 23, aoc_2022_day_23::Day23, animate, generate),
+    day!(2022, 24, aoc_2022_day_24::Day24, animate, generate),
+    day!(2022, 25, aoc_2022_day_25::Day25, generate),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn example_parameters() {
+        let day = Day::find(2022, 15).unwrap();
+        let input = include_str!("../../../2022/day-15/input.test.txt");
+
+        let parsed = (day.prepare)(input, true).unwrap();
+        assert_eq!(parsed.part_one(), Answer::Number(26));
+        assert_eq!(parsed.part_two(), Answer::Number(56000011));
+    }
+
+    #[test]
+    fn registry() {
+        let keys: Vec<_> = DAYS.iter().map(|day| (day.year, day.number)).collect();
+        assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+
+        assert_eq!(years(), [2022]);
+        assert_eq!(latest_year(), 2022);
+        assert!(Day::find(2021, 1).is_none());
+
+        let day = Day::find(2022, 7).unwrap();
+        assert_eq!(day.to_string(), "Day 7 of 2022");
+        assert!(day.dir().ends_with("2022/day-7"));
+    }
+
+    #[test]
+    fn invalid_input() {
+        let day = Day::find(2022, 1).unwrap();
+
+        let error = (day.prepare)("1000\n2x00\n", false).err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        // Days created by `aoc new` have no generator until one is written
+        for day in DAYS.iter().filter(|day| day.generate.is_some()) {
+            let generate = day.generate.unwrap();
+
+            // The smallest sizes are the most likely to miss what the solvers expect
+            for (seed, size) in (0..5).flat_map(|seed| [0, 1, 2, 10].map(|size| (seed, size))) {
+                let input = generate(true, &mut Rng::new(seed), Some(size));
+
+                let parsed = (day.prepare)(&input, true).unwrap_or_else(|error| {
+                    panic!(
+                        "seed {seed}, size {size}: {}",
+                        error.with_day(day.year, day.number).render(&input)
+                    )
+                });
+
+                parsed.part_one();
+                parsed.part_two();
+            }
+        }
+    }
+}
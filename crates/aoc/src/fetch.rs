@@ -0,0 +1,219 @@
+use std::{
+    fs,
+    io::Write,
+    path::PathBuf,
+    process::{Command, Stdio},
+    time::Duration,
+};
+
+/// Source of the puzzle inputs, which are personal to each Advent of Code account.
+pub trait Fetcher {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, String>;
+}
+
+/// Downloads the inputs from the Advent of Code website, logged in with a session token.
+///
+/// The requests are made by `curl`, which handles TLS so the runner does not need to.
+pub struct Http {
+    /// Website root, eg. `https://adventofcode.com`
+    pub base_url: String,
+    /// Value of the `session` cookie of a logged in browser
+    pub session: String,
+    /// Time to download an input before giving up, including the connection
+    pub timeout: Duration,
+}
+
+impl Http {
+    pub const BASE_URL: &'static str = "https://adventofcode.com";
+    pub const TIMEOUT: Duration = Duration::from_secs(60);
+    /// Time to connect to the website, within the timeout
+    const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+    pub fn new(session: String) -> Self {
+        Self {
+            base_url: Self::BASE_URL.to_owned(),
+            session,
+            timeout: Self::TIMEOUT,
+        }
+    }
+
+    pub fn url(&self, year: u16, day: u8) -> String {
+        format!(
+            "{}/{year}/day/{day}/input",
+            self.base_url.trim_end_matches('/')
+        )
+    }
+}
+
+impl Fetcher for Http {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = self.url(year, day);
+
+        let connect_timeout = Self::CONNECT_TIMEOUT.min(self.timeout);
+
+        // The cookie is written to stdin so that the token does not show in the process list
+        let mut curl = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--header", "@-"])
+            .args(["--user-agent", "aoc runner (cargo aoc fetch)"])
+            .args([
+                "--connect-timeout",
+                &connect_timeout.as_secs_f64().to_string(),
+            ])
+            .args(["--max-time", &self.timeout.as_secs_f64().to_string()])
+            .arg(&url)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| format!("cannot run curl, which the downloads need ({err})"))?;
+
+        let mut stdin = curl.stdin.take().expect("stdin should be piped");
+        writeln!(stdin, "Cookie: session={}", self.session.trim())
+            .map_err(|err| format!("cannot write to curl ({err})"))?;
+        drop(stdin);
+
+        let output = curl
+            .wait_with_output()
+            .map_err(|err| format!("cannot run curl ({err})"))?;
+        if !output.status.success() {
+            let error = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("cannot download '{url}' ({})", error.trim()));
+        }
+
+        String::from_utf8(output.stdout).map_err(|_| format!("'{url}' is not a text input"))
+    }
+}
+
+/// Reads the inputs from a directory laid out like the cache (`<year>/day-<day>.txt`), as a
+/// stand-in for the website in tests or to copy inputs from a shared directory.
+pub struct Directory {
+    pub root: PathBuf,
+}
+
+impl Fetcher for Directory {
+    fn fetch(&self, year: u16, day: u8) -> Result<String, String> {
+        let path = self
+            .root
+            .join(year.to_string())
+            .join(format!("day-{day}.txt"));
+
+        fs::read_to_string(&path).map_err(|err| format!("cannot read '{}' ({err})", path.display()))
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use std::{
+        env,
+        io::{BufRead, BufReader},
+        net::TcpListener,
+        thread,
+    };
+
+    use super::*;
+
+    /// Serves `body` to the requests with the `session=secret` cookie, returning the address of
+    /// the server and the request lines it received
+    fn serve(body: &'static str, requests: usize) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let mut received = Vec::new();
+
+            for stream in listener.incoming().take(requests) {
+                let mut stream = stream.unwrap();
+                let headers: Vec<String> = BufReader::new(&stream)
+                    .lines()
+                    .map(Result::unwrap)
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+
+                let response = if headers.iter().any(|h| h == "Cookie: session=secret") {
+                    format!(
+                        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{body}",
+                        body.len()
+                    )
+                } else {
+                    "HTTP/1.1 400 Bad Request\r\nContent-Length: 0\r\n\r\n".to_owned()
+                };
+                stream.write_all(response.as_bytes()).unwrap();
+                received.push(headers[0].clone());
+            }
+
+            received
+        });
+
+        (address, server)
+    }
+
+    /// Whether curl can be run, the downloads being skipped (with a note) on machines without it
+    fn has_curl() -> bool {
+        let installed = Command::new("curl").arg("--version").output().is_ok();
+        if !installed {
+            eprintln!("skipped: curl is not installed");
+        }
+
+        installed
+    }
+
+    #[test]
+    fn http() {
+        if !has_curl() {
+            return;
+        }
+
+        let (base_url, server) = serve("1\n2\n", 2);
+        let fetcher = |session: &str| Http {
+            base_url: base_url.clone(),
+            session: session.to_owned(),
+            timeout: Http::TIMEOUT,
+        };
+
+        assert_eq!(fetcher("secret").fetch(2022, 5), Ok("1\n2\n".to_owned()));
+        assert!(fetcher("expired")
+            .fetch(2022, 5)
+            .unwrap_err()
+            .contains("400"));
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0], "GET /2022/day/5/input HTTP/1.1");
+    }
+
+    #[test]
+    fn timeout() {
+        if !has_curl() {
+            return;
+        }
+
+        // Accepts the connection but never answers
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let http = Http {
+            base_url: format!("http://{}", listener.local_addr().unwrap()),
+            session: "secret".to_owned(),
+            timeout: Duration::from_millis(500),
+        };
+
+        let error = http.fetch(2022, 5).unwrap_err();
+        assert!(error.contains("timed out"), "{error}");
+    }
+
+    #[test]
+    fn directory() {
+        let root = env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        fs::create_dir_all(root.join("2022")).unwrap();
+        fs::write(root.join("2022/day-3.txt"), "abc\n").unwrap();
+
+        let directory = Directory { root: root.clone() };
+        assert_eq!(directory.fetch(2022, 3), Ok("abc\n".to_owned()));
+        assert!(directory.fetch(2022, 4).is_err());
+        assert_eq!(
+            Http::new(String::new()).url(2022, 4),
+            "https://adventofcode.com/2022/day/4/input"
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
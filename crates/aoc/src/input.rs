@@ -0,0 +1,127 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+};
+
+use crate::{cache::Cache, days::Day};
+
+/// Where the input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// `<year>/day-<day>/input.txt` or, when it is missing or empty, the input cache
+    Puzzle,
+    /// `<year>/day-<day>/input.test.txt` or, for a named example,
+    /// `<year>/day-<day>/input-<name>.test.txt`
+    Example(Option<String>),
+    File(PathBuf),
+    Stdin,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub source: Source,
+    /// Solves with the parameters of the puzzle examples instead of the ones of the real input
+    pub example: bool,
+}
+
+impl Default for Input {
+    fn default() -> Self {
+        Self {
+            source: Source::Puzzle,
+            example: false,
+        }
+    }
+}
+
+impl Input {
+    /// Path of the input of `day`, or `None` when reading from stdin
+    pub fn path(&self, day: &Day) -> Option<PathBuf> {
+        match &self.source {
+            Source::Puzzle => Some(day.dir().join("input.txt")),
+            Source::Example(None) => Some(day.dir().join("input.test.txt")),
+            Source::Example(Some(name)) => Some(day.dir().join(format!("input-{name}.test.txt"))),
+            Source::File(path) => Some(path.clone()),
+            Source::Stdin => None,
+        }
+    }
+
+    pub fn read(&self, day: &Day) -> Result<String, String> {
+        if self.source == Source::Puzzle {
+            return read_puzzle(day);
+        }
+
+        match self.path(day) {
+            Some(path) => fs::read_to_string(&path)
+                .map_err(|err| format!("cannot read '{}' ({err})", path.display())),
+            None => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|err| format!("cannot read stdin ({err})"))?;
+
+                Ok(input)
+            }
+        }
+    }
+}
+
+/// Reads `<year>/day-<day>/input.txt`, falling back on the input cache when it is missing or
+/// empty (as left by `aoc new`)
+fn read_puzzle(day: &Day) -> Result<String, String> {
+    let path = day.dir().join("input.txt");
+
+    let error = match fs::read_to_string(&path) {
+        Ok(input) if !input.trim().is_empty() => return Ok(input),
+        Ok(_) => format!("'{}' is empty", path.display()),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            format!("cannot find '{}'", path.display())
+        }
+        Err(err) => return Err(format!("cannot read '{}' ({err})", path.display())),
+    };
+
+    let cache = Cache::user()?;
+    match cache.read(day.year, day.number)? {
+        Some(input) => Ok(input),
+        None => Err(format!(
+            "{error} and the input is not cached, download it with 'cargo aoc fetch {} {}'",
+            day.year, day.number
+        )),
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn paths() {
+        let day = Day::find(2022, 9).unwrap();
+        let example = |name: Option<&str>| Input {
+            source: Source::Example(name.map(String::from)),
+            example: true,
+        };
+
+        assert!(Input::default()
+            .path(day)
+            .unwrap()
+            .ends_with("2022/day-9/input.txt"));
+        assert!(example(None)
+            .path(day)
+            .unwrap()
+            .ends_with("2022/day-9/input.test.txt"));
+
+        let second = example(Some("2"));
+        assert!(second
+            .path(day)
+            .unwrap()
+            .ends_with("2022/day-9/input-2.test.txt"));
+
+        let parsed = (day.prepare)(&second.read(day).unwrap(), second.example).unwrap();
+        assert_eq!(parsed.part_two(), aoc_core::Answer::Number(36));
+
+        assert!(example(Some("missing")).read(day).is_err());
+    }
+}
@@ -0,0 +1,1144 @@
+use std::{
+    env,
+    fs::{self, File},
+    io::{self, BufWriter},
+    iter::Peekable,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use animation::{Asciicast, Canvas, Replay, ReplayRecorder};
+use aoc_core::{Answer, Part, Rng};
+use cache::{Cache, Fetched};
+use days::{Day, Screen, DAYS};
+use fetch::Fetcher;
+use input::{Input, Source};
+
+mod batch;
+mod bench;
+mod cache;
+mod check;
+mod days;
+mod fetch;
+mod input;
+mod memory;
+mod scaffold;
+
+const USAGE: &str = "usage: aoc run [year] <day|all> [--part <1|2>] [--input <path|->] [--example [name]]
+       aoc run [year] <day|all> --jobs <n> [--part <1|2>] [--input <path|->] [--example [name]]
+       aoc run [year] <day|all> --check [--part <1|2>]
+       aoc run [year] <day> [--animate] [--record <file.cast|file.replay>] [--fps <n>] [--steps <n>] [--part <1|2>] [--input <path|->] [--example [name]]
+       aoc run [year] <day> --image <file.png|file.ppm> [--scale <n>] [--part <1|2>] [--input <path|->] [--example [name]]
+       aoc replay <file.replay> [--fps <n>]
+       aoc generate [year] <day> [--seed <n>] [--size <n>] [--example]
+       aoc new [year] <day>
+       aoc fetch [year] <day|all> [--force] [--from <dir>]
+       aoc bench [year] <day|all> [--iterations <n>] [--warmup <n>] [--json] [--input <path|->] [--example [name]]
+
+The days are the ones of the latest year unless a year is given, and 'all' is every day of the
+year, or of every year if none is given.";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = Command::parse(&args).unwrap_or_else(|message| {
+        eprintln!("error: {message}\n{USAGE}");
+        process::exit(2);
+    });
+
+    match command {
+        Command::Run { days, part, input } => {
+            for day in days.iter() {
+                run_day(day, part, &input);
+            }
+        }
+        Command::Batch {
+            days,
+            part,
+            input,
+            jobs,
+        } => {
+            let days: Vec<_> = days.iter().collect();
+
+            let start = Instant::now();
+            let reports = batch::run(&days, part, &input, jobs);
+            let summary = batch::Summary {
+                reports: &reports,
+                jobs,
+                wall: start.elapsed(),
+            };
+
+            println!("{summary}");
+            if !summary.passed() {
+                process::exit(1);
+            }
+        }
+        Command::Animate {
+            year,
+            day,
+            part,
+            input,
+            live,
+            record,
+            options,
+        } => {
+            let day = Day::find(year, day).expect("animated day should exist");
+            if let Some(text) = read_input(day, &input) {
+                animate_day(day, part, &input, &text, live, record.as_deref(), options);
+            }
+        }
+        Command::Render {
+            year,
+            day,
+            part,
+            input,
+            path,
+            scale,
+        } => {
+            let day = Day::find(year, day).expect("rendered day should exist");
+            if let Some(text) = read_input(day, &input) {
+                render_day(day, part, &input, &text, &path, scale);
+            }
+        }
+        Command::Replay { path, fps } => {
+            if let Err(message) = play_replay(&path, fps) {
+                eprintln!("{message}");
+                process::exit(1);
+            }
+        }
+        Command::Generate {
+            year,
+            day,
+            seed,
+            size,
+            example,
+        } => {
+            let day = Day::find(year, day).expect("generated day should exist");
+            let generate = day.generate.expect("day should have a generator");
+
+            // The seed is printed so that an interesting input can be generated again
+            let seed = seed.unwrap_or_else(|| {
+                let now = SystemTime::now().duration_since(UNIX_EPOCH);
+                now.map_or(0, |now| now.as_nanos() as u64)
+            });
+            eprintln!("{day}: generated with --seed {seed}");
+
+            println!("{}", generate(example, &mut Rng::new(seed), size));
+        }
+        Command::New { year, day } => match scaffold::new_day(&days::workspace_dir(), year, day) {
+            Ok(files) => {
+                for file in files {
+                    println!("Created {}", file.display());
+                }
+                println!(
+                    "Day {day} of {year} registered in the runner, run it with 'cargo aoc run {year} {day}'"
+                );
+            }
+            Err(message) => {
+                eprintln!("error: {message}");
+                process::exit(1);
+            }
+        },
+        Command::Fetch { days, force, from } => match fetch_inputs(&days, force, from.as_deref()) {
+            Ok(true) => (),
+            Ok(false) => process::exit(1),
+            Err(message) => {
+                eprintln!("error: {message}");
+                process::exit(1);
+            }
+        },
+        Command::Check { days, part } => {
+            let checks: Vec<_> = days
+                .iter()
+                .flat_map(|day| check::check_day(day, part))
+                .collect();
+
+            let table = check::Table(&checks);
+            println!("{table}");
+            if !table.passed() {
+                process::exit(1);
+            }
+        }
+        Command::Bench {
+            days,
+            input,
+            options,
+        } => {
+            let reports: Vec<_> = days
+                .iter()
+                .filter_map(|day| bench_day(day, &input, options))
+                .collect();
+
+            if options.json {
+                let reports: Vec<_> = reports.iter().map(bench::Report::to_json).collect();
+                println!("[{}]", reports.join(","));
+            }
+        }
+    }
+}
+
+fn run_day(day: &Day, part: Option<Part>, input: &Input) {
+    let Some(text) = read_input(day, input) else {
+        return;
+    };
+
+    println!("{day}");
+
+    let start = Instant::now();
+    let (parsed, usage) = memory::measure(|| (day.prepare)(&text, input.example));
+    let stats = stats(start.elapsed(), usage);
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            eprintln!("{}", error.with_day(day.year, day.number).render(&text));
+            return;
+        }
+    };
+    println!("Input parsed [{stats}]");
+
+    if part != Some(Part::Two) {
+        run_part(|| parsed.part_one(), "one");
+    }
+
+    if part != Some(Part::One) {
+        run_part(|| parsed.part_two(), "two");
+    }
+}
+
+/// Draws the simulation of `day` in the terminal if `live` is set, and records it to `record`
+fn animate_day(
+    day: &Day,
+    part: Option<Part>,
+    input: &Input,
+    text: &str,
+    live: bool,
+    record: Option<&Path>,
+    options: animation::Options,
+) {
+    let animate = day.animate.expect("day should be animated");
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![Part::One, Part::Two],
+    };
+
+    let mut canvas: Screen = if live {
+        Canvas::with_writer(Box::new(io::stdout()), options)
+    } else {
+        // Only recording, the frames are not drawn so there is no need to wait between them
+        let options = animation::Options { fps: 0, ..options };
+        Canvas::with_writer(Box::new(io::sink()), options)
+    };
+
+    if let Some(path) = record {
+        let file = match File::create(path) {
+            Ok(file) => BufWriter::new(file),
+            Err(error) => {
+                eprintln!("error: cannot create {}: {error}", path.display());
+                return;
+            }
+        };
+
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "cast")
+        {
+            canvas.record(Asciicast::new(file, options.fps));
+        } else {
+            canvas.record(ReplayRecorder::new(file, options.fps));
+        }
+    }
+
+    for part in parts {
+        if let Err(error) = animate(text, input.example, part, &mut canvas) {
+            eprintln!("{}", error.with_day(day.year, day.number).render(text));
+            return;
+        }
+        if canvas.failed() {
+            break;
+        }
+    }
+
+    match canvas.finish() {
+        Ok(()) => {}
+        // The output was closed before the end (eg. piped to `head`)
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => return,
+        Err(error) => {
+            eprintln!("error: cannot draw or record the animation: {error}");
+            return;
+        }
+    }
+
+    if let Some(path) = record {
+        println!("Recorded {} frames to {}", canvas.frames(), path.display());
+    }
+}
+
+/// Exports the final state of the parts of `day` as images, suffixing `path` with the part number
+/// unless a single part is rendered
+fn render_day(day: &Day, part: Option<Part>, input: &Input, text: &str, path: &Path, scale: usize) {
+    let render = day.render.expect("day should be rendered");
+    let parts = match part {
+        Some(part) => vec![(part, path.to_path_buf())],
+        None => vec![
+            (Part::One, part_path(path, 1)),
+            (Part::Two, part_path(path, 2)),
+        ],
+    };
+
+    for (part, path) in parts {
+        let image = match render(text, input.example, part) {
+            Ok(image) => image.scale(scale),
+            Err(error) => {
+                eprintln!("{}", error.with_day(day.year, day.number).render(text));
+                return;
+            }
+        };
+
+        if let Err(error) = image.save(&path) {
+            eprintln!("error: cannot write {}: {error}", path.display());
+            return;
+        }
+
+        println!(
+            "Rendered a {}x{} image to {}",
+            image.width(),
+            image.height(),
+            path.display()
+        );
+    }
+}
+
+/// `path` with `-<part>` added to its file name, before the extension
+fn part_path(path: &Path, part: u8) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = path.extension().unwrap_or_default().to_string_lossy();
+
+    path.with_file_name(format!("{stem}-{part}.{extension}"))
+}
+
+/// Plays a replay recorded by `run --record` at its frame rate, or at `fps`, returning the error
+/// message when it cannot be read
+fn play_replay(path: &Path, fps: Option<u32>) -> Result<(), String> {
+    let text = fs::read_to_string(path)
+        .map_err(|error| format!("error: cannot read {}: {error}", path.display()))?;
+    let replay = Replay::parse(&text).map_err(|error| error.render(&text))?;
+
+    let options = animation::Options {
+        fps: fps.unwrap_or(replay.fps),
+        steps_per_frame: 1,
+    };
+    let mut canvas = Canvas::new(options);
+    replay.play(&mut canvas);
+
+    match canvas.finish() {
+        Err(error) if error.kind() != io::ErrorKind::BrokenPipe => {
+            Err(format!("error: cannot draw the replay: {error}"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Benchmarks `day`, printing its report unless it is collected as JSON
+fn bench_day(day: &Day, input: &Input, options: bench::Options) -> Option<bench::Report> {
+    let text = read_input(day, input)?;
+
+    match bench::bench(day, &text, input.example, options) {
+        Ok(report) => {
+            if !options.json {
+                println!("{report}");
+            }
+
+            Some(report)
+        }
+        Err(error) => {
+            eprintln!("{}", error.with_day(day.year, day.number).render(&text));
+            None
+        }
+    }
+}
+
+/// Downloads the inputs of `days` to the input cache, or copies them from the `from` directory,
+/// returning whether all of them are cached
+fn fetch_inputs(days: &Selection, force: bool, from: Option<&Path>) -> Result<bool, String> {
+    let cache = Cache::user()?;
+    let fetcher: Box<dyn Fetcher> = match from {
+        Some(root) => Box::new(fetch::Directory {
+            root: root.to_path_buf(),
+        }),
+        None => Box::new(fetch::Http::new(cache.session()?)),
+    };
+
+    let mut cached = true;
+    for day in days.iter() {
+        let path = cache.path(day.year, day.number);
+
+        match cache.fetch(day.year, day.number, fetcher.as_ref(), force) {
+            Ok(Fetched::Downloaded) => println!("{day}: saved to {}", path.display()),
+            Ok(Fetched::AlreadyCached) => println!("{day}: already in {}", path.display()),
+            Err(message) => {
+                eprintln!("{day}: {message}");
+                cached = false;
+            }
+        }
+    }
+
+    Ok(cached)
+}
+
+fn read_input(day: &Day, input: &Input) -> Option<String> {
+    match input.read(day) {
+        Ok(text) => Some(text),
+        Err(message) => {
+            eprintln!("{day}: {message}");
+            None
+        }
+    }
+}
+
+fn run_part(solve: impl FnOnce() -> Answer, name: &str) {
+    let start = Instant::now();
+    let (answer, usage) = memory::measure(solve);
+    let stats = stats(start.elapsed(), usage);
+
+    if answer.is_multiline() {
+        println!("Part {name} result:\n{answer} [{stats}]");
+    } else {
+        println!("Part {name} result: {answer} [{stats}]");
+    }
+}
+
+/// Time of a step, followed by its allocations when they are counted
+fn stats(elapsed: Duration, usage: Option<memory::Usage>) -> String {
+    match usage {
+        Some(usage) => format!("time: {elapsed:.2?}, {usage}"),
+        None => format!("time: {elapsed:.2?}"),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Run {
+        days: Selection,
+        part: Option<Part>,
+        input: Input,
+    },
+    /// Runs days on a pool of `jobs` threads and prints a summary of their answers and timings
+    Batch {
+        days: Selection,
+        part: Option<Part>,
+        input: Input,
+        jobs: usize,
+    },
+    /// Draws the simulation of a day in the terminal and/or records it
+    Animate {
+        year: u16,
+        day: u8,
+        part: Option<Part>,
+        input: Input,
+        live: bool,
+        /// `.cast` (asciicast) or `.replay` file
+        record: Option<PathBuf>,
+        options: animation::Options,
+    },
+    /// Exports the final state of a day as an image
+    Render {
+        year: u16,
+        day: u8,
+        part: Option<Part>,
+        input: Input,
+        /// `.png` or `.ppm` file
+        path: PathBuf,
+        scale: usize,
+    },
+    /// Plays a recorded replay in the terminal
+    Replay { path: PathBuf, fps: Option<u32> },
+    /// Prints a random input of a day, generated from `seed`
+    Generate {
+        year: u16,
+        day: u8,
+        seed: Option<u64>,
+        /// Size of the input, which depends on the day, or the size of the real inputs
+        size: Option<usize>,
+        /// Generates with the parameters of the puzzle examples
+        example: bool,
+    },
+    /// Creates the crate of a new day from `template/`
+    New { year: u16, day: u8 },
+    /// Stores the inputs of days in the input cache, downloading them with the session token
+    /// unless they are copied from the `from` directory
+    Fetch {
+        days: Selection,
+        /// Replaces the inputs which are already cached
+        force: bool,
+        from: Option<PathBuf>,
+    },
+    /// Compares the answers of every input listed in the answers files with the recorded ones
+    Check { days: Selection, part: Option<Part> },
+    Bench {
+        days: Selection,
+        input: Input,
+        options: bench::Options,
+    },
+}
+
+/// Days a command applies to
+#[derive(Debug, PartialEq, Eq)]
+enum Selection {
+    All,
+    Year(u16),
+    Day(u16, u8),
+}
+
+impl Command {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut args = args.iter().map(|arg| arg.as_str()).peekable();
+
+        let bench = match args.next() {
+            Some("run") => false,
+            Some("bench") => true,
+            Some("new") => return Command::parse_new(args),
+            Some("replay") => return Command::parse_replay(args),
+            Some("generate") => return Command::parse_generate(args),
+            Some("fetch") => return Command::parse_fetch(args),
+            Some(other) => return Err(format!("unknown command '{other}'")),
+            None => return Err("missing command".to_owned()),
+        };
+
+        let days = Selection::parse(&mut args)?;
+        let mut part = None;
+        let mut path = None;
+        let mut example = None;
+        let mut options = bench::Options::default();
+        let mut check = false;
+        let mut jobs = None;
+        let mut animate = false;
+        let mut record = None;
+        let mut image = None;
+        let mut scale = None;
+        let mut animation = animation::Options::default();
+
+        while let Some(arg) = args.next() {
+            match arg {
+                "--part" | "-p" if !bench => {
+                    part = Some(parse_part(args.next().ok_or("missing part")?)?)
+                }
+                "--iterations" | "-n" if bench => {
+                    options.iterations = parse_count(args.next(), "iterations")?;
+                    if options.iterations == 0 {
+                        return Err("at least one iteration is needed".to_owned());
+                    }
+                }
+                "--warmup" if bench => options.warmup = parse_count(args.next(), "warmup")?,
+                "--json" if bench => options.json = true,
+                "--check" | "-c" if !bench => check = true,
+                "--jobs" | "-j" if !bench => jobs = Some(parse_count(args.next(), "jobs")?),
+                "--animate" | "-a" if !bench => animate = true,
+                "--image" if !bench => {
+                    image = Some(PathBuf::from(args.next().ok_or("missing image path")?))
+                }
+                "--scale" if !bench => scale = Some(parse_count(args.next(), "scale")?),
+                "--record" | "-r" if !bench => {
+                    record = Some(PathBuf::from(args.next().ok_or("missing record path")?))
+                }
+                "--fps" if !bench => animation.fps = parse_count(args.next(), "fps")?,
+                "--steps" if !bench => {
+                    animation.steps_per_frame = parse_count(args.next(), "steps")?
+                }
+                "--input" | "-i" => path = Some(args.next().ok_or("missing input path")?),
+                "--example" | "-e" => {
+                    example = Some(
+                        args.next_if(|name| !name.starts_with('-'))
+                            .map(String::from),
+                    )
+                }
+                other => return Err(format!("unexpected argument '{other}'")),
+            }
+        }
+
+        if animation != animation::Options::default() && !animate && record.is_none() {
+            return Err("--fps and --steps can only be used with --animate or --record".to_owned());
+        }
+
+        if scale.is_some() && image.is_none() {
+            return Err("--scale can only be used with --image".to_owned());
+        }
+        if image.is_some() && (animate || record.is_some()) {
+            return Err("--image cannot be used with --animate or --record".to_owned());
+        }
+
+        if jobs.is_some() && (check || animate || record.is_some() || image.is_some()) {
+            return Err(
+                "--jobs cannot be used with --check, --animate, --record or --image".to_owned(),
+            );
+        }
+        if jobs == Some(0) {
+            return Err("at least one job is needed".to_owned());
+        }
+
+        if check {
+            if animate || record.is_some() || image.is_some() {
+                return Err("--check cannot be used with --animate, --record or --image".to_owned());
+            }
+            if path.is_some() || example.is_some() {
+                return Err("--check verifies the inputs listed in the answers files".to_owned());
+            }
+
+            return Ok(Command::Check { days, part });
+        }
+
+        let input = match (path, example) {
+            (Some(_), _) if !matches!(days, Selection::Day(..)) => {
+                return Err("--input can only be used with a single day".to_owned())
+            }
+            (Some(_), Some(Some(_))) => {
+                return Err("example names cannot be used with --input".to_owned())
+            }
+            (Some(path), example) => Input {
+                source: if path == "-" {
+                    Source::Stdin
+                } else {
+                    Source::File(PathBuf::from(path))
+                },
+                example: example.is_some(),
+            },
+            (None, Some(name)) => Input {
+                source: Source::Example(name),
+                example: true,
+            },
+            (None, None) => Input::default(),
+        };
+
+        if let Some(path) = image {
+            let Selection::Day(year, day) = days else {
+                return Err("--image can only be used with a single day".to_owned());
+            };
+            if Day::find(year, day).is_some_and(|day| day.render.is_none()) {
+                return Err(format!("day {day} of {year} cannot be rendered"));
+            }
+
+            let extension = path.extension().and_then(|extension| extension.to_str());
+            if !matches!(extension, Some("png" | "ppm")) {
+                return Err("images are written to .png or .ppm files".to_owned());
+            }
+            if scale == Some(0) {
+                return Err("the scale should be at least 1".to_owned());
+            }
+
+            return Ok(Command::Render {
+                year,
+                day,
+                part,
+                input,
+                path,
+                scale: scale.unwrap_or(1) as usize,
+            });
+        }
+
+        if animate || record.is_some() {
+            let Selection::Day(year, day) = days else {
+                return Err("--animate and --record can only be used with a single day".to_owned());
+            };
+            if Day::find(year, day).is_some_and(|day| day.animate.is_none()) {
+                return Err(format!("day {day} of {year} cannot be animated"));
+            }
+
+            if let Some(path) = &record {
+                let extension = path.extension().and_then(|extension| extension.to_str());
+                if !matches!(extension, Some("cast" | "replay")) {
+                    return Err("recordings are written to .cast or .replay files".to_owned());
+                }
+                if animation.fps == 0 {
+                    return Err("recordings need a frame rate above 0".to_owned());
+                }
+            }
+
+            return Ok(Command::Animate {
+                year,
+                day,
+                part,
+                input,
+                live: animate,
+                record,
+                options: animation,
+            });
+        }
+
+        if let Some(jobs) = jobs {
+            return Ok(Command::Batch {
+                days,
+                part,
+                input,
+                jobs: jobs as usize,
+            });
+        }
+
+        if bench {
+            Ok(Command::Bench {
+                days,
+                input,
+                options,
+            })
+        } else {
+            Ok(Command::Run { days, part, input })
+        }
+    }
+
+    fn parse_new<'a>(mut args: impl Iterator<Item = &'a str>) -> Result<Self, String> {
+        let first = args.next().ok_or("missing day")?;
+        let (year, day) = match args.next() {
+            Some(day) => (
+                parse_year(first).ok_or(format!("invalid year '{first}'"))?,
+                day,
+            ),
+            None => (days::latest_year(), first),
+        };
+        let day = day.parse().map_err(|_| format!("invalid day '{day}'"))?;
+
+        match args.next() {
+            Some(other) => Err(format!("unexpected argument '{other}'")),
+            None => Ok(Command::New { year, day }),
+        }
+    }
+
+    fn parse_replay<'a>(mut args: impl Iterator<Item = &'a str>) -> Result<Self, String> {
+        let path = PathBuf::from(args.next().ok_or("missing replay path")?);
+        let mut fps = None;
+
+        while let Some(arg) = args.next() {
+            match arg {
+                "--fps" => fps = Some(parse_count(args.next(), "fps")?),
+                other => return Err(format!("unexpected argument '{other}'")),
+            }
+        }
+
+        Ok(Command::Replay { path, fps })
+    }
+
+    fn parse_generate<'a>(
+        mut args: Peekable<impl Iterator<Item = &'a str>>,
+    ) -> Result<Self, String> {
+        let Selection::Day(year, day) = Selection::parse(&mut args)? else {
+            return Err("inputs are generated for a single day".to_owned());
+        };
+        if Day::find(year, day).is_some_and(|day| day.generate.is_none()) {
+            return Err(format!("day {day} of {year} has no input generator"));
+        }
+
+        let mut seed = None;
+        let mut size = None;
+        let mut example = false;
+
+        while let Some(arg) = args.next() {
+            match arg {
+                "--seed" | "-s" => {
+                    let arg = args.next().ok_or("missing seed")?;
+                    seed = Some(arg.parse().map_err(|_| format!("invalid seed '{arg}'"))?);
+                }
+                "--size" => size = Some(parse_count(args.next(), "size")? as usize),
+                "--example" | "-e" => example = true,
+                other => return Err(format!("unexpected argument '{other}'")),
+            }
+        }
+
+        Ok(Command::Generate {
+            year,
+            day,
+            seed,
+            size,
+            example,
+        })
+    }
+
+    fn parse_fetch<'a>(mut args: Peekable<impl Iterator<Item = &'a str>>) -> Result<Self, String> {
+        let days = Selection::parse(&mut args)?;
+        let mut force = false;
+        let mut from = None;
+
+        while let Some(arg) = args.next() {
+            match arg {
+                "--force" | "-f" => force = true,
+                "--from" => from = Some(PathBuf::from(args.next().ok_or("missing directory")?)),
+                other => return Err(format!("unexpected argument '{other}'")),
+            }
+        }
+
+        Ok(Command::Fetch { days, force, from })
+    }
+}
+
+fn parse_count(arg: Option<&str>, name: &str) -> Result<u32, String> {
+    let arg = arg.ok_or_else(|| format!("missing {name}"))?;
+
+    arg.parse().map_err(|_| format!("invalid {name} '{arg}'"))
+}
+
+/// Year of an Advent of Code calendar, which cannot be mistaken for a day
+fn parse_year(arg: &str) -> Option<u16> {
+    arg.parse().ok().filter(|year| *year >= days::FIRST_YEAR)
+}
+
+impl Selection {
+    /// Parses `[year] <day|all>`, a year alone selecting all of its days
+    fn parse<'a>(args: &mut Peekable<impl Iterator<Item = &'a str>>) -> Result<Self, String> {
+        let arg = args.next().ok_or("missing day")?;
+
+        let (year, arg) = match parse_year(arg) {
+            Some(year) => {
+                if !days::years().contains(&year) {
+                    return Err(format!("no days of {year} are registered"));
+                }
+                match args.next_if(|arg| !arg.starts_with('-')) {
+                    Some(day) => (Some(year), day),
+                    None => return Ok(Selection::Year(year)),
+                }
+            }
+            None => (None, arg),
+        };
+
+        match (year, arg) {
+            (Some(year), "all") => Ok(Selection::Year(year)),
+            (None, "all") => Ok(Selection::All),
+            (year, arg) => {
+                let year = year.unwrap_or_else(days::latest_year);
+
+                match arg.parse() {
+                    Ok(day) if Day::find(year, day).is_some() => Ok(Selection::Day(year, day)),
+                    _ => Err(format!("invalid day '{arg}' of {year}")),
+                }
+            }
+        }
+    }
+
+    fn iter(&self) -> impl Iterator<Item = &'static Day> + '_ {
+        DAYS.iter().filter(move |day| match self {
+            Selection::All => true,
+            Selection::Year(year) => day.year == *year,
+            Selection::Day(year, number) => day.year == *year && day.number == *number,
+        })
+    }
+}
+
+fn parse_part(arg: &str) -> Result<Part, String> {
+    match arg {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("invalid part '{arg}'")),
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &str) -> Result<Command, String> {
+        let args: Vec<String> = args.split_whitespace().map(String::from).collect();
+        Command::parse(&args)
+    }
+
+    #[test]
+    fn parse_run() {
+        assert_eq!(
+            parse("run 14 --part 2"),
+            Ok(Command::Run {
+                days: Selection::Day(2022, 14),
+                part: Some(Part::Two),
+                input: Input::default(),
+            })
+        );
+        assert_eq!(
+            parse("run all"),
+            Ok(Command::Run {
+                days: Selection::All,
+                part: None,
+                input: Input::default(),
+            })
+        );
+
+        assert!(parse("").is_err());
+        assert!(parse("run").is_err());
+        assert!(parse("run 26").is_err());
+        assert!(parse("run 1 --part 3").is_err());
+        assert!(parse("walk 1").is_err());
+    }
+
+    #[test]
+    fn parse_year() {
+        assert_eq!(parse("run 2022 14 --part 2"), parse("run 14 --part 2"));
+        assert_eq!(
+            parse("run 2022"),
+            Ok(Command::Run {
+                days: Selection::Year(2022),
+                part: None,
+                input: Input::default(),
+            })
+        );
+        assert_eq!(parse("run 2022 all"), parse("run 2022"));
+        assert!(parse("run 2021 14").is_err());
+        assert!(parse("run 2022 26").is_err());
+        assert!(parse("run 2022 --input day-1.txt").is_err());
+    }
+
+    #[test]
+    fn parse_jobs() {
+        assert_eq!(
+            parse("run all --jobs 4 -p 1"),
+            Ok(Command::Batch {
+                days: Selection::All,
+                part: Some(Part::One),
+                input: Input::default(),
+                jobs: 4,
+            })
+        );
+        assert!(parse("run all --jobs 0").is_err());
+        assert!(parse("run all -j").is_err());
+        assert!(parse("run all --jobs 4 --check").is_err());
+        assert!(parse("run 14 --jobs 2 --animate").is_err());
+        assert!(parse("bench all --jobs 4").is_err());
+    }
+
+    #[test]
+    fn parse_check() {
+        assert_eq!(
+            parse("run all --check --part 1"),
+            Ok(Command::Check {
+                days: Selection::All,
+                part: Some(Part::One)
+            })
+        );
+        assert!(parse("run 9 --check --example 2").is_err());
+        assert!(parse("bench 9 --check").is_err());
+    }
+
+    #[test]
+    fn parse_animate() {
+        assert_eq!(
+            parse("run 14 --animate --fps 60 --steps 5 -p 2 -e"),
+            Ok(Command::Animate {
+                year: 2022,
+                day: 14,
+                part: Some(Part::Two),
+                input: Input {
+                    source: Source::Example(None),
+                    example: true
+                },
+                live: true,
+                record: None,
+                options: animation::Options {
+                    fps: 60,
+                    steps_per_frame: 5
+                },
+            })
+        );
+        assert!(parse("run all --animate").is_err());
+        assert!(parse("run 1 --animate").is_err());
+        assert!(parse("run 14 --fps 10").is_err());
+        assert!(parse("run 14 --animate --check").is_err());
+    }
+
+    #[test]
+    fn parse_record() {
+        assert_eq!(
+            parse("run 17 --record rocks.cast --fps 10"),
+            Ok(Command::Animate {
+                year: 2022,
+                day: 17,
+                part: None,
+                input: Input::default(),
+                live: false,
+                record: Some("rocks.cast".into()),
+                options: animation::Options {
+                    fps: 10,
+                    steps_per_frame: 1
+                },
+            })
+        );
+        assert!(parse("run 17 --record rocks.gif").is_err());
+        assert!(parse("run 17 --record rocks.replay --fps 0").is_err());
+        assert!(parse("run all --record rocks.replay").is_err());
+    }
+
+    #[test]
+    fn parse_image() {
+        assert_eq!(
+            parse("run 14 --image cave.png --scale 4 -p 2"),
+            Ok(Command::Render {
+                year: 2022,
+                day: 14,
+                part: Some(Part::Two),
+                input: Input::default(),
+                path: "cave.png".into(),
+                scale: 4,
+            })
+        );
+        assert!(parse("run 1 --image calories.png").is_err());
+        assert!(parse("run 14 --image cave.gif").is_err());
+        assert!(parse("run 14 --image cave.png --scale 0").is_err());
+        assert!(parse("run 14 --scale 2").is_err());
+        assert!(parse("run 14 --image cave.png --animate").is_err());
+        assert!(parse("run all --image cave.png").is_err());
+        assert_eq!(
+            part_path(Path::new("out/cave.png"), 2),
+            Path::new("out/cave-2.png")
+        );
+    }
+
+    #[test]
+    fn parse_replay() {
+        assert_eq!(
+            parse("replay rocks.replay --fps 5"),
+            Ok(Command::Replay {
+                path: "rocks.replay".into(),
+                fps: Some(5)
+            })
+        );
+        assert!(parse("replay").is_err());
+    }
+
+    #[test]
+    fn parse_generate() {
+        assert_eq!(
+            parse("generate 16 --seed 42 --size 30 -e"),
+            Ok(Command::Generate {
+                year: 2022,
+                day: 16,
+                seed: Some(42),
+                size: Some(30),
+                example: true,
+            })
+        );
+        assert_eq!(
+            parse("generate 5"),
+            Ok(Command::Generate {
+                year: 2022,
+                day: 5,
+                seed: None,
+                size: None,
+                example: false,
+            })
+        );
+        assert!(parse("generate all").is_err());
+        assert!(parse("generate 16 --seed -1").is_err());
+        assert!(parse("generate 16 --part 1").is_err());
+    }
+
+    #[test]
+    fn parse_new() {
+        assert_eq!(parse("new 7"), Ok(Command::New { year: 2022, day: 7 }));
+        assert_eq!(parse("new 2023 5"), Ok(Command::New { year: 2023, day: 5 }));
+        assert!(parse("new").is_err());
+        assert!(parse("new 7 8").is_err());
+        assert!(parse("new 2023 5 6").is_err());
+    }
+
+    #[test]
+    fn parse_fetch() {
+        assert_eq!(
+            parse("fetch all --force"),
+            Ok(Command::Fetch {
+                days: Selection::All,
+                force: true,
+                from: None,
+            })
+        );
+        assert_eq!(
+            parse("fetch 3 --from /mnt/inputs"),
+            Ok(Command::Fetch {
+                days: Selection::Day(2022, 3),
+                force: false,
+                from: Some("/mnt/inputs".into()),
+            })
+        );
+        assert!(parse("fetch").is_err());
+        assert!(parse("fetch 3 --from").is_err());
+        assert!(parse("fetch 3 --part 1").is_err());
+    }
+
+    #[test]
+    fn parse_input() {
+        let input = |args| match parse(args) {
+            Ok(
+                Command::Run { input, .. }
+                | Command::Batch { input, .. }
+                | Command::Bench { input, .. },
+            ) => Ok(input),
+            Ok(Command::Animate { input, .. } | Command::Render { input, .. }) => Ok(input),
+            Ok(
+                Command::Check { .. }
+                | Command::New { .. }
+                | Command::Fetch { .. }
+                | Command::Replay { .. }
+                | Command::Generate { .. },
+            ) => Err("no input".to_owned()),
+            Err(message) => Err(message),
+        };
+
+        assert_eq!(
+            input("run 9 --example 2 --part 2"),
+            Ok(Input {
+                source: Source::Example(Some("2".to_owned())),
+                example: true
+            })
+        );
+        assert_eq!(
+            input("run all -e"),
+            Ok(Input {
+                source: Source::Example(None),
+                example: true
+            })
+        );
+        assert_eq!(
+            input("run 15 --input - --example"),
+            Ok(Input {
+                source: Source::Stdin,
+                example: true
+            })
+        );
+        assert_eq!(
+            input("run 15 -i ci/day-15.txt"),
+            Ok(Input {
+                source: Source::File("ci/day-15.txt".into()),
+                example: false
+            })
+        );
+
+        assert!(input("run 1 --input").is_err());
+        assert!(input("run all --input -").is_err());
+        assert!(input("run 9 --input - --example 2").is_err());
+        assert!(input("bench all --input -").is_err());
+    }
+
+    #[test]
+    fn parse_bench() {
+        assert_eq!(
+            parse("bench 14 -n 50 --warmup 0 --json -e"),
+            Ok(Command::Bench {
+                days: Selection::Day(2022, 14),
+                input: Input {
+                    source: Source::Example(None),
+                    example: true
+                },
+                options: bench::Options {
+                    warmup: 0,
+                    iterations: 50,
+                    json: true
+                },
+            })
+        );
+        assert_eq!(
+            parse("bench all"),
+            Ok(Command::Bench {
+                days: Selection::All,
+                input: Input::default(),
+                options: bench::Options::default(),
+            })
+        );
+
+        assert!(parse("bench 1 --iterations 0").is_err());
+        assert!(parse("bench 1 --warmup").is_err());
+        assert!(parse("bench 1 --part 1").is_err());
+        assert!(parse("run 1 --json").is_err());
+    }
+}
@@ -0,0 +1,182 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::{self, Display},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// Whether the runner was built with the counting allocator (`count-allocations` feature)
+pub const INSTALLED: bool = cfg!(feature = "count-allocations");
+
+#[cfg(feature = "count-allocations")]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+/// Bytes currently allocated
+static LIVE: AtomicU64 = AtomicU64::new(0);
+/// Highest value of `LIVE` since the last measure started
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+/// System allocator which counts the allocations and the allocated bytes.
+#[cfg_attr(not(feature = "count-allocations"), allow(dead_code))]
+pub struct Counting;
+
+#[cfg_attr(not(feature = "count-allocations"), allow(dead_code))]
+impl Counting {
+    fn allocated(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+
+        let live = LIVE.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn freed(size: usize) {
+        LIVE.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::allocated(layout.size());
+        }
+
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::freed(layout.size());
+    }
+
+    /// Counts as an allocation of `new_size` bytes replacing the old one
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::freed(layout.size());
+            Self::allocated(new_size);
+        }
+
+        new_ptr
+    }
+}
+
+/// Allocations made while running a step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: u64,
+    /// Bytes allocated, including the ones freed before the end of the step
+    pub bytes: u64,
+    /// Highest memory allocated at once during the step, above the one allocated before it
+    pub peak: u64,
+}
+
+/// Runs `f`, measuring its allocations if the counting allocator is installed
+///
+/// The counters are shared by all the threads, so only one step should be measured at a time.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if INSTALLED {
+        let (value, usage) = count(f);
+        (value, Some(usage))
+    } else {
+        (f(), None)
+    }
+}
+
+fn count<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let value = f();
+
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+
+    (value, usage)
+}
+
+/// Size in bytes with a binary unit, eg. `1.5 KiB`
+fn size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+impl Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "allocations: {} ({}), peak: {}",
+            self.allocations,
+            size(self.bytes),
+            size(self.peak)
+        )
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn counted() {
+        let layout = Layout::array::<u64>(512).unwrap();
+
+        let ((), usage) = count(|| unsafe {
+            let first = Counting.alloc(layout);
+            let second = Counting.alloc_zeroed(layout);
+            Counting.dealloc(first, layout);
+            let second = Counting.realloc(second, layout, 2 * layout.size());
+            Counting.dealloc(second, Layout::array::<u64>(1024).unwrap());
+        });
+
+        // Other tests may allocate at the same time when the allocator is installed
+        assert!(usage.allocations >= 3);
+        assert!(usage.bytes >= 16 * 1024);
+        assert!(usage.peak >= 8 * 1024);
+    }
+
+    #[test]
+    fn sizes() {
+        assert_eq!(size(1023), "1023 B");
+        assert_eq!(size(1536), "1.5 KiB");
+        assert_eq!(size(3 * 1024 * 1024), "3.0 MiB");
+
+        let usage = Usage {
+            allocations: 12,
+            bytes: 2048,
+            peak: 100,
+        };
+        assert_eq!(usage.to_string(), "allocations: 12 (2.0 KiB), peak: 100 B");
+    }
+}
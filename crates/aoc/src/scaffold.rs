@@ -0,0 +1,430 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::days::FIRST_YEAR;
+
+/// Days of an Advent of Code calendar
+const DAY_RANGE: std::ops::RangeInclusive<u8> = 1..=25;
+
+/// Creates the crate of `day` of `year` (`<year>/day-<day>`) from `template/` inside of the
+/// workspace at `root` and registers it in the workspace and in the runner, returning the created
+/// files.
+///
+/// The files are first written next to their destination, then moved in place, so a failure
+/// leaves the workspace as it was, and an existing day is never overwritten.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    if !DAY_RANGE.contains(&day) {
+        return Err(format!("invalid day '{day}', expected a day from 1 to 25"));
+    }
+    if year < FIRST_YEAR {
+        return Err(format!(
+            "invalid year '{year}', the first calendar is {FIRST_YEAR}'s"
+        ));
+    }
+
+    let name = format!("aoc-{year}-day-{day}");
+    let dir = root.join(year.to_string()).join(format!("day-{day}"));
+    if dir.exists() {
+        return Err(format!("'{}' already exists", dir.display()));
+    }
+
+    let template = root.join("template");
+    let manifest = read(&template.join("Cargo.toml"))?.replacen(
+        "name = \"template\"",
+        &format!("name = \"{name}\""),
+        1,
+    );
+    let lib = read(&template.join("src/lib.rs"))?.replace("DayN", &format!("Day{day}"));
+
+    let workspace_path = root.join("Cargo.toml");
+    let workspace = register_member(&read(&workspace_path)?, year, day)?;
+
+    let runner_manifest_path = root.join("crates/aoc/Cargo.toml");
+    let runner_manifest = register_dependency(&read(&runner_manifest_path)?, year, day)?;
+
+    let days_path = root.join("crates/aoc/src/days.rs");
+    let days = register_day(&read(&days_path)?, year, day)?;
+
+    let files = [
+        ("Cargo.toml", manifest),
+        ("src/lib.rs", lib),
+        ("README.md", String::new()),
+        ("input.txt", String::new()),
+        ("input.test.txt", String::new()),
+        ("answers.txt", String::new()),
+    ];
+
+    let mut registrations = vec![(runner_manifest_path, runner_manifest), (days_path, days)];
+    if let Some(workspace) = workspace {
+        registrations.push((workspace_path, workspace));
+    }
+
+    // The crate is staged in a hidden directory of the workspace, and each registration in a
+    // `.new` file next to the one it replaces
+    let staging = root.join(format!(".new-{year}-day-{day}"));
+    let staged: Vec<_> = registrations
+        .iter()
+        .map(|(path, _)| {
+            let mut staged = path.clone().into_os_string();
+            staged.push(".new");
+            PathBuf::from(staged)
+        })
+        .collect();
+    let clean_up = || {
+        let _ = fs::remove_dir_all(&staging);
+        for path in &staged {
+            let _ = fs::remove_file(path);
+        }
+    };
+
+    let written = stage(&staging, &files).and_then(|()| {
+        registrations
+            .iter()
+            .zip(&staged)
+            .try_for_each(|((_, content), path)| write(path, content))
+    });
+    if let Err(err) = written {
+        clean_up();
+        return Err(err);
+    }
+
+    let moved = dir
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|()| fs::rename(&staging, &dir))
+        .map_err(|err| format!("cannot create '{}' ({err})", dir.display()));
+    if let Err(err) = moved {
+        clean_up();
+        return Err(err);
+    }
+    for ((path, _), staged) in registrations.iter().zip(&staged) {
+        fs::rename(staged, path)
+            .map_err(|err| format!("cannot write '{}' ({err})", path.display()))?;
+    }
+
+    Ok(files.iter().map(|(path, _)| dir.join(path)).collect())
+}
+
+/// Writes `files` (relative paths with their content) in a new `dir`
+fn stage(dir: &Path, files: &[(&str, String)]) -> Result<(), String> {
+    let _ = fs::remove_dir_all(dir);
+    fs::create_dir_all(dir.join("src"))
+        .map_err(|err| format!("cannot create '{}' ({err})", dir.display()))?;
+
+    files
+        .iter()
+        .try_for_each(|(path, content)| write(&dir.join(path), content))
+}
+
+/// Adds `<year>/day-<day>` to the members of the workspace, or `None` if a `*/day-*` or
+/// `<year>/day-*` glob already includes it
+fn register_member(workspace: &str, year: u16, day: u8) -> Result<Option<String>, String> {
+    let start = workspace
+        .find("members = [")
+        .ok_or("workspace members not found in 'Cargo.toml'")?;
+    let end = start
+        + workspace[start..]
+            .find(']')
+            .ok_or("unterminated workspace members in 'Cargo.toml'")?;
+
+    let members = &workspace[start..end];
+    if members.contains("\"*/day-*\"") || members.contains(&format!("\"{year}/day-*\"")) {
+        return Ok(None);
+    }
+
+    let name = format!("{year}/day-{day}");
+    if members.contains(&format!("\"{name}\"")) {
+        return Err(format!("'{name}' is already a workspace member"));
+    }
+
+    let separator = if members.trim_end().ends_with('[') {
+        ""
+    } else {
+        ", "
+    };
+    let mut workspace = workspace.to_owned();
+    workspace.insert_str(end, &format!("{separator}\"{name}\""));
+
+    Ok(Some(workspace))
+}
+
+/// Adds the crate of `day` to the dependencies of the runner, keeping them ordered by year and day
+fn register_dependency(manifest: &str, year: u16, day: u8) -> Result<String, String> {
+    let entry = format!("aoc-{year}-day-{day} = {{ path = \"../../{year}/day-{day}\" }}");
+
+    insert_ordered(manifest, entry, (year, day), "[dependencies]", |line| {
+        let (name, _) = line.strip_prefix("aoc-")?.split_once(' ')?;
+        let (year, day) = name.split_once("-day-")?;
+
+        Some((year.parse().ok()?, day.parse().ok()?))
+    })
+    .ok_or_else(|| format!("day {day} of {year} is already a dependency of the runner"))
+}
+
+/// Adds `day` to the `DAYS` of the runner, keeping them ordered by year and day
+fn register_day(days: &str, year: u16, day: u8) -> Result<String, String> {
+    const START: &str = "pub const DAYS: &[Day] = &[";
+    if !days.contains(START) {
+        return Err("`DAYS` not found in the runner".to_owned());
+    }
+
+    let entry = format!("    day!({year}, {day}, aoc_{year}_day_{day}::Day{day}),");
+
+    insert_ordered(days, entry, (year, day), START, |line| {
+        let mut arguments = line.trim_start().strip_prefix("day!(")?.split(", ");
+
+        Some((
+            arguments.next()?.parse().ok()?,
+            arguments.next()?.parse().ok()?,
+        ))
+    })
+    .ok_or_else(|| format!("day {day} of {year} is already registered in the runner"))
+}
+
+/// Inserts the line `entry` among the lines for which `day_of` returns a year and day, before the
+/// first one of a later day (or after the `anchor` line if there are none), or `None` if `day`
+/// already has a line
+fn insert_ordered(
+    text: &str,
+    entry: String,
+    day: (u16, u8),
+    anchor: &str,
+    day_of: impl Fn(&str) -> Option<(u16, u8)>,
+) -> Option<String> {
+    let mut lines: Vec<String> = text.lines().map(String::from).collect();
+    let days: Vec<(usize, (u16, u8))> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| Some((i, day_of(line)?)))
+        .collect();
+
+    if days.iter().any(|(_, other)| *other == day) {
+        return None;
+    }
+
+    let index = match days.iter().find(|(_, other)| *other > day) {
+        Some((i, _)) => *i,
+        None => match days.last() {
+            Some((i, _)) => i + 1,
+            None => lines
+                .iter()
+                .position(|line| line.trim() == anchor)
+                .map_or(lines.len(), |i| i + 1),
+        },
+    };
+    lines.insert(index, entry);
+
+    Some(lines.join("\n") + "\n")
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("cannot read '{}' ({err})", path.display()))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|err| format!("cannot write '{}' ({err})", path.display()))
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use std::env;
+
+    use super::*;
+
+    const DAYS: &str = "pub const DAYS: &[Day] = &[
+    day!(2022, 1, aoc_2022_day_1::Day1),
+    day!(2022, 3, aoc_2022_day_3::Day3),
+];
+";
+
+    /// Workspace with days 1 and 3 of 2022, in a directory unique to `name`
+    fn workspace(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+
+        let files = [
+            (
+                "Cargo.toml",
+                "[workspace]\nmembers = [\"crates/*\", \"2022/day-1\", \"2022/day-3\"]\n",
+            ),
+            (
+                "template/Cargo.toml",
+                "[package]\nname = \"template\"\nversion = \"0.1.0\"\n",
+            ),
+            ("template/src/lib.rs", "pub struct DayN;\n"),
+            (
+                "crates/aoc/Cargo.toml",
+                "[dependencies]\naoc-core = { workspace = true }\n\
+                 aoc-2022-day-1 = { path = \"../../2022/day-1\" }\n\
+                 aoc-2022-day-3 = { path = \"../../2022/day-3\" }\n",
+            ),
+            ("crates/aoc/src/days.rs", DAYS),
+            ("2022/day-3/src/lib.rs", "pub struct Day3;\n"),
+        ];
+
+        for (path, content) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+
+        root
+    }
+
+    #[test]
+    fn create_day() {
+        let root = workspace("create");
+
+        let files = new_day(&root, 2022, 2).unwrap();
+        assert_eq!(files.len(), 6);
+        assert!(files.iter().all(|file| file.exists()));
+
+        assert_eq!(
+            read(&root.join("2022/day-2/Cargo.toml")).unwrap(),
+            "[package]\nname = \"aoc-2022-day-2\"\nversion = \"0.1.0\"\n"
+        );
+        assert_eq!(
+            read(&root.join("2022/day-2/src/lib.rs")).unwrap(),
+            "pub struct Day2;\n"
+        );
+        assert_eq!(read(&root.join("2022/day-2/input.test.txt")).unwrap(), "");
+        assert_eq!(
+            read(&root.join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\"crates/*\", \"2022/day-1\", \"2022/day-3\", \"2022/day-2\"]\n"
+        );
+        assert!(read(&root.join("crates/aoc/Cargo.toml"))
+            .unwrap()
+            .ends_with(
+                "day-1\" }\naoc-2022-day-2 = { path = \"../../2022/day-2\" }\n\
+                 aoc-2022-day-3 = { path = \"../../2022/day-3\" }\n"
+            ));
+        assert_eq!(
+            read(&root.join("crates/aoc/src/days.rs")).unwrap(),
+            DAYS.replace(
+                "Day1),\n",
+                "Day1),\n    day!(2022, 2, aoc_2022_day_2::Day2),\n"
+            )
+        );
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn new_year() {
+        let root = workspace("year");
+
+        new_day(&root, 2023, 1).unwrap();
+        assert!(root.join("2023/day-1/src/lib.rs").exists());
+        assert!(read(&root.join("Cargo.toml"))
+            .unwrap()
+            .contains("\"2022/day-3\", \"2023/day-1\"]"));
+        assert!(read(&root.join("crates/aoc/Cargo.toml"))
+            .unwrap()
+            .ends_with("aoc-2023-day-1 = { path = \"../../2023/day-1\" }\n"));
+        assert!(read(&root.join("crates/aoc/src/days.rs"))
+            .unwrap()
+            .ends_with("Day3),\n    day!(2023, 1, aoc_2023_day_1::Day1),\n];\n"));
+
+        // The same day of another year is a different crate
+        assert!(new_day(&root, 2023, 3).is_ok());
+        assert!(new_day(&root, 2014, 1).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn failed_write() {
+        let root = workspace("failed");
+        let workspace = read(&root.join("Cargo.toml")).unwrap();
+
+        // The staged file of the runner's days cannot be written over a directory
+        fs::create_dir_all(root.join("crates/aoc/src/days.rs.new")).unwrap();
+        assert!(new_day(&root, 2022, 2).is_err());
+
+        assert!(!root.join("2022/day-2").exists());
+        assert!(!root.join(".new-2022-day-2").exists());
+        assert!(!root.join("crates/aoc/Cargo.toml.new").exists());
+        assert_eq!(read(&root.join("Cargo.toml")).unwrap(), workspace);
+        assert_eq!(read(&root.join("crates/aoc/src/days.rs")).unwrap(), DAYS);
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn refuse_overwrite() {
+        let root = workspace("overwrite");
+
+        assert!(new_day(&root, 2022, 3).is_err());
+        assert_eq!(
+            read(&root.join("2022/day-3/src/lib.rs")).unwrap(),
+            "pub struct Day3;\n"
+        );
+
+        // Registered in the runner without a crate: nothing is created
+        assert!(new_day(&root, 2022, 1).is_err());
+        assert!(!root.join("2022/day-1").exists());
+
+        assert!(new_day(&root, 2022, 26).is_err());
+        assert!(new_day(&root, 2022, 0).is_err());
+
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn glob_members() {
+        let workspace = "[workspace]\nmembers = [\"crates/*\", \"*/day-*\"]\n";
+        assert_eq!(register_member(workspace, 2023, 4), Ok(None));
+
+        let workspace = "[workspace]\nmembers = [\"crates/*\", \"2022/day-*\"]\n";
+        assert_eq!(register_member(workspace, 2022, 4), Ok(None));
+        assert_eq!(
+            register_member(workspace, 2023, 4),
+            Ok(Some(
+                "[workspace]\nmembers = [\"crates/*\", \"2022/day-*\", \"2023/day-4\"]\n"
+                    .to_owned()
+            ))
+        );
+
+        assert_eq!(
+            register_member("members = []", 2022, 4),
+            Ok(Some("members = [\"2022/day-4\"]".to_owned()))
+        );
+    }
+
+    #[test]
+    fn runner_files() {
+        let days = include_str!("days.rs");
+        let without = days.replace(
+            "    day!(2022, 25, aoc_2022_day_25::Day25, generate),\n",
+            "",
+        );
+        // New days are registered without the optional features
+        let registered = days.replace(
+            "day!(2022, 25, aoc_2022_day_25::Day25, generate)",
+            "day!(2022, 25, aoc_2022_day_25::Day25)",
+        );
+        assert_eq!(register_day(&without, 2022, 25), Ok(registered));
+
+        let manifest = include_str!("../Cargo.toml");
+        let without = manifest.replace("aoc-2022-day-12 = { path = \"../../2022/day-12\" }\n", "");
+        assert_eq!(
+            register_dependency(&without, 2022, 12).as_deref(),
+            Ok(manifest)
+        );
+    }
+
+    #[test]
+    fn first_day() {
+        assert_eq!(
+            register_day("pub const DAYS: &[Day] = &[\n];", 2022, 7),
+            Ok(
+                "pub const DAYS: &[Day] = &[\n    day!(2022, 7, aoc_2022_day_7::Day7),\n];\n"
+                    .to_owned()
+            )
+        );
+    }
+}
@@ -0,0 +1,120 @@
+use crate::Cycle;
+
+/// Floyd's tortoise and hare: finds the cycle of the states reached from `start` by `step`,
+/// comparing the states themselves, with only two of them in memory at a time.
+///
+/// Never returns if the states do not repeat.
+pub fn floyd<S: Clone + PartialEq>(start: S, mut step: impl FnMut(&mut S)) -> Cycle {
+    // The hare goes twice as fast as the tortoise until they meet in the cycle, after a number
+    // of steps which is a multiple of the period
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    loop {
+        step(&mut tortoise);
+        step(&mut hare);
+        step(&mut hare);
+
+        if tortoise == hare {
+            break;
+        }
+    }
+
+    // Both then meet at the start of the cycle when one of them is back to the initial state
+    let mut tortoise = start;
+    let mut start = 0;
+    while tortoise != hare {
+        step(&mut tortoise);
+        step(&mut hare);
+        start += 1;
+    }
+
+    let mut period = 1;
+    step(&mut hare);
+    while tortoise != hare {
+        step(&mut hare);
+        period += 1;
+    }
+
+    Cycle { start, period }
+}
+
+/// Brent's algorithm: finds the same cycle as `floyd`, usually with fewer steps.
+///
+/// Never returns if the states do not repeat.
+pub fn brent<S: Clone + PartialEq>(start: S, mut step: impl FnMut(&mut S)) -> Cycle {
+    // The tortoise teleports to the hare at each power of two, until the hare reaches it in less
+    // steps, which is then the period
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = start.clone();
+    let mut hare = start.clone();
+    step(&mut hare);
+    while tortoise != hare {
+        if period == power {
+            tortoise = hare.clone();
+            power *= 2;
+            period = 0;
+        }
+
+        step(&mut hare);
+        period += 1;
+    }
+
+    // A hare `period` steps ahead of the tortoise meets it at the start of the cycle
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..period {
+        step(&mut hare);
+    }
+
+    let mut start = 0;
+    while tortoise != hare {
+        step(&mut tortoise);
+        step(&mut hare);
+        start += 1;
+    }
+
+    Cycle { start, period }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// 0, 1, 2, 5, 26, 2, 5, 26, ...
+    fn squares(n: &mut u64) {
+        *n = (*n * *n + 1) % 675;
+    }
+
+    #[test]
+    fn cycles() {
+        let expected = Cycle {
+            start: 2,
+            period: 3,
+        };
+        assert_eq!(floyd(0, squares), expected);
+        assert_eq!(brent(0, squares), expected);
+
+        // Starting in the cycle
+        let expected = Cycle {
+            start: 0,
+            period: 3,
+        };
+        assert_eq!(floyd(5, squares), expected);
+        assert_eq!(brent(5, squares), expected);
+    }
+
+    #[test]
+    fn fixed_point() {
+        let halve = |n: &mut u32| *n /= 2;
+        let expected = Cycle {
+            start: 7,
+            period: 1,
+        };
+
+        assert_eq!(floyd(100, halve), expected);
+        assert_eq!(brent(100, halve), expected);
+    }
+}
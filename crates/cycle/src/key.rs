@@ -0,0 +1,208 @@
+use std::{
+    collections::{hash_map::DefaultHasher, HashMap},
+    hash::{Hash, Hasher},
+};
+
+use crate::Cycle;
+
+/// Finds a cycle from the keys of the states, which should be the same for two states if and
+/// only if the simulation goes on the same way from them. It can leave out what does not matter
+/// to the next steps (eg. the rows buried under the surface), or be a hash of the state.
+#[derive(Debug, Clone)]
+pub struct Detector<K> {
+    /// State in which each key was first seen
+    seen: HashMap<K, usize>,
+}
+
+impl<K: Eq + Hash> Detector<K> {
+    pub fn new() -> Self {
+        Self {
+            seen: HashMap::new(),
+        }
+    }
+
+    /// Records the key of the next state, the first recorded being the state 0, returning the
+    /// cycle if a previous state had the same key
+    pub fn record(&mut self, key: K) -> Option<Cycle> {
+        let step = self.seen.len();
+
+        match self.seen.get(&key) {
+            Some(&start) => Some(Cycle {
+                start,
+                period: step - start,
+            }),
+            None => {
+                self.seen.insert(key, step);
+                None
+            }
+        }
+    }
+
+    /// Number of states recorded
+    pub fn len(&self) -> usize {
+        self.seen.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.seen.is_empty()
+    }
+}
+
+impl<K: Eq + Hash> Default for Detector<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Runs `step` on `state` until `key` gives the key of a previous state, leaving `state` at the
+/// end of the cycle.
+///
+/// Never returns if the keys do not repeat.
+pub fn by_key<S, K: Eq + Hash>(
+    state: &mut S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle {
+    let mut detector = Detector::new();
+
+    loop {
+        if let Some(cycle) = detector.record(key(state)) {
+            return cycle;
+        }
+
+        step(state);
+    }
+}
+
+/// Runs `step` on `state` until a step leaves its key unchanged, leaving `state` at the fixed
+/// point. The cycle starts at the fixed point, with a period of 1: its `end` is the number of steps
+/// done. Only consecutive keys are compared, so a state can have the same key as an earlier one.
+///
+/// Never returns if the keys keep changing.
+pub fn fixed_point<S, K: PartialEq>(
+    state: &mut S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+) -> Cycle {
+    let mut previous = key(state);
+    let mut start = 0;
+
+    loop {
+        step(state);
+
+        let next = key(state);
+        if next == previous {
+            return Cycle { start, period: 1 };
+        }
+
+        previous = next;
+        start += 1;
+    }
+}
+
+/// Hash of a collection which does not depend on the order of its items, to use as the key of a
+/// state stored in a `HashSet` or `HashMap` without keeping a copy of it
+pub fn hash_unordered<T: Hash>(items: impl IntoIterator<Item = T>) -> u64 {
+    items
+        .into_iter()
+        .map(|item| {
+            let mut hasher = DefaultHasher::new();
+            item.hash(&mut hasher);
+            hasher.finish()
+        })
+        .fold(0, u64::wrapping_add)
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::brent;
+
+    #[test]
+    fn detector() {
+        let mut detector = Detector::new();
+
+        for key in ['a', 'b', 'c', 'd'] {
+            assert_eq!(detector.record(key), None);
+        }
+        assert_eq!(
+            detector.record('b'),
+            Some(Cycle {
+                start: 1,
+                period: 3
+            })
+        );
+        assert_eq!(detector.len(), 4);
+    }
+
+    #[test]
+    fn partial_key() {
+        // A counter and a position moving back and forth on 0..5, which only depends on itself
+        let step = |(count, pos, dir): &mut (u32, i32, i32)| {
+            if !(0..5).contains(&(*pos + *dir)) {
+                *dir = -*dir;
+            }
+            *pos += *dir;
+            *count += 1;
+        };
+
+        let mut state = (0, 2, 1);
+        let cycle = by_key(&mut state, step, |&(_, pos, dir)| (pos, dir));
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 0,
+                period: 8
+            }
+        );
+        assert_eq!(state, (8, 2, 1));
+
+        // The full states never repeat, but their positions do
+        let positions = brent((2, 1), |state: &mut (i32, i32)| {
+            let mut full = (0, state.0, state.1);
+            step(&mut full);
+            *state = (full.1, full.2);
+        });
+        assert_eq!(positions, cycle);
+    }
+
+    #[test]
+    fn fixed_point() {
+        // Goes through 2 twice before stopping at 0: only consecutive states are compared
+        let values = [2, 1, 2, 0];
+        let value = |&step: &usize| values[step.min(values.len() - 1)];
+
+        let mut step = 0;
+        let cycle = super::fixed_point(&mut step, |step| *step += 1, value);
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 3,
+                period: 1
+            }
+        );
+        assert_eq!(cycle.end(), 4);
+        assert_eq!(step, 4);
+
+        let mut step = 0;
+        assert_eq!(by_key(&mut step, |step| *step += 1, value).start, 0);
+
+        // Already at its fixed point
+        let cycle = super::fixed_point(&mut 0, |value: &mut u32| *value /= 2, |&value| value);
+        assert_eq!(cycle.end(), 1);
+    }
+
+    #[test]
+    fn unordered() {
+        let a: HashSet<_> = (0..100).collect();
+        let b: HashSet<_> = (0..100).rev().collect();
+        let c: HashSet<_> = (1..101).collect();
+
+        assert_eq!(hash_unordered(&a), hash_unordered(&b));
+        assert_ne!(hash_unordered(&a), hash_unordered(&c));
+    }
+}
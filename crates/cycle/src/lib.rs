@@ -0,0 +1,105 @@
+pub mod floyd;
+pub mod key;
+
+use std::ops::{Add, Mul, Sub};
+
+pub use floyd::{brent, floyd};
+pub use key::{by_key, fixed_point, hash_unordered, Detector};
+
+/// Cycle of a simulation whose states are numbered by the steps done to reach them, the initial
+/// state being the state 0: after `start` steps, the states repeat every `period` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Number of steps before the first state of the cycle
+    pub start: usize,
+    pub period: usize,
+}
+
+impl Cycle {
+    /// First state which is the same as a previous one
+    pub fn end(&self) -> usize {
+        self.start + self.period
+    }
+
+    /// State before the end of the first period which is the same as the state `step`
+    pub fn equivalent(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.period
+        }
+    }
+
+    /// Value of a metric at the state `step`, from its values for the states from 0 to at least
+    /// the end of the cycle (`metrics[i]` being its value at the state `i`), assuming that it
+    /// changes by the same amount during each period.
+    pub fn extrapolate<M>(&self, metrics: &[M], step: usize) -> M
+    where
+        M: Copy + Add<Output = M> + Sub<Output = M> + Mul<Output = M> + TryFrom<usize>,
+    {
+        assert!(
+            metrics.len() > self.end(),
+            "the metric should be known until the end of the cycle"
+        );
+
+        if let Some(&metric) = metrics.get(step) {
+            return metric;
+        }
+
+        let equivalent = self.equivalent(step);
+        let periods = M::try_from((step - equivalent) / self.period)
+            .ok()
+            .expect("the number of periods should fit in the metric");
+        let per_period = metrics[self.end()] - metrics[self.start];
+
+        metrics[equivalent] + per_period * periods
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn equivalent() {
+        let cycle = Cycle {
+            start: 3,
+            period: 4,
+        };
+
+        assert_eq!(cycle.end(), 7);
+        assert_eq!(cycle.equivalent(2), 2);
+        assert_eq!(cycle.equivalent(3), 3);
+        assert_eq!(cycle.equivalent(7), 3);
+        assert_eq!(cycle.equivalent(12), 4);
+    }
+
+    #[test]
+    fn extrapolate() {
+        // Grows by 1 then by 5 each period of 2 steps, after 1, 1, 2
+        let metrics: Vec<u64> = vec![1, 1, 2, 3, 8, 9, 14];
+        let cycle = Cycle {
+            start: 2,
+            period: 2,
+        };
+
+        assert_eq!(cycle.extrapolate(&metrics, 1), 1);
+        assert_eq!(cycle.extrapolate(&metrics, 6), 14);
+        assert_eq!(cycle.extrapolate(&metrics, 7), 15);
+        assert_eq!(cycle.extrapolate(&metrics, 8), 20);
+        assert_eq!(
+            cycle.extrapolate(&metrics, 1_000_000_000_000),
+            2 + 6 * (500_000_000_000 - 1)
+        );
+
+        // Decreasing
+        let metrics: Vec<i64> = vec![0, -3, -6];
+        let cycle = Cycle {
+            start: 0,
+            period: 1,
+        };
+        assert_eq!(cycle.extrapolate(&metrics, 10), -30);
+    }
+}
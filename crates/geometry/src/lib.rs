@@ -0,0 +1,7 @@
+pub mod number;
+pub mod point;
+pub mod point3;
+
+pub use number::{Number, Signed};
+pub use point::Point;
+pub use point3::Point3;
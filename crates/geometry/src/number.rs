@@ -0,0 +1,78 @@
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Rem, Sub, SubAssign},
+};
+
+/// Integer which can be used as a coordinate of a [`Point`](crate::Point) or a
+/// [`Point3`](crate::Point3).
+pub trait Number:
+    Copy
+    + Debug
+    + Default
+    + Hash
+    + Ord
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + SubAssign
+    + Mul<Output = Self>
+    + MulAssign
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    /// Type of the distance between two numbers (eg. `u32` for `i32`)
+    type Unsigned: Number;
+
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs_diff(self, other: Self) -> Self::Unsigned;
+
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+/// Number which can be negative, required by the operations which change the direction of a point.
+pub trait Signed: Number + Neg<Output = Self> {
+    fn signum(self) -> Self;
+}
+
+macro_rules! impl_number {
+    ($($number:ty => $unsigned:ty),*) => {
+        $(
+            impl Number for $number {
+                type Unsigned = $unsigned;
+
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs_diff(self, other: Self) -> Self::Unsigned {
+                    <$number>::abs_diff(self, other)
+                }
+
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    <$number>::rem_euclid(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed {
+    ($($number:ty),*) => {
+        $(
+            impl Signed for $number {
+                fn signum(self) -> Self {
+                    <$number>::signum(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_number!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, isize => usize
+);
+
+impl_signed!(i8, i16, i32, i64, isize);
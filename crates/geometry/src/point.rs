@@ -0,0 +1,267 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::number::{Number, Signed};
+
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from(p: (T, T)) -> Self {
+        Self { x: p.0, y: p.1 }
+    }
+}
+
+impl<T: Number> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::Output {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T: Number> AddAssign for Point<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+    }
+}
+
+impl<T: Number> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::Output {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T: Number> SubAssign for Point<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+    }
+}
+
+impl<T: Number> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::Output {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+impl<T: Signed> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::Output {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T: Number> Point<T> {
+    pub const ZERO: Self = Self::new(T::ZERO, T::ZERO);
+
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan_dist(self, other: Self) -> T::Unsigned {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn rem_euclid(self, other: Self) -> Self {
+        Self {
+            x: self.x.rem_euclid(other.x),
+            y: self.y.rem_euclid(other.y),
+        }
+    }
+
+    /// Corners of the smallest rectangle containing all `points`
+    pub fn min_max(points: impl IntoIterator<Item = Self>) -> Option<(Self, Self)> {
+        let mut points = points.into_iter();
+        let mut min = points.next()?;
+        let mut max = min;
+
+        for point in points {
+            min.x = min.x.min(point.x);
+            min.y = min.y.min(point.y);
+
+            max.x = max.x.max(point.x);
+            max.y = max.y.max(point.y);
+        }
+
+        Some((min, max))
+    }
+}
+
+impl<T: Signed> Point<T> {
+    pub fn signum(self) -> Self {
+        Self {
+            x: self.x.signum(),
+            y: self.y.signum(),
+        }
+    }
+
+    /// Rotates 90° counterclockwise around the origin (assuming `y` points up)
+    pub fn rotate_left(self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    /// Rotates 90° clockwise around the origin (assuming `y` points up)
+    pub fn rotate_right(self) -> Self {
+        Self {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+
+    /// The 4 orthogonally adjacent points, counterclockwise starting from `x + 1`
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        let (one, zero) = (T::ONE, T::ZERO);
+        let offsets = [(one, zero), (zero, one), (-one, zero), (zero, -one)];
+
+        offsets.into_iter().map(move |offset| self + offset.into())
+    }
+
+    /// The 8 orthogonally and diagonally adjacent points, counterclockwise starting from `x + 1`
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        let (one, zero) = (T::ONE, T::ZERO);
+        let offsets = [
+            (one, zero),
+            (one, one),
+            (zero, one),
+            (-one, one),
+            (-one, zero),
+            (-one, -one),
+            (zero, -one),
+            (one, -one),
+        ];
+
+        offsets.into_iter().map(move |offset| self + offset.into())
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a: Point = (5, -3).into();
+        let b: Point = (2, 7).into();
+
+        assert_eq!(a + b, Point::new(7, 4));
+        assert_eq!(a - b, Point::new(3, -10));
+        assert_eq!(b - a, Point::new(-3, 10));
+        assert_eq!(a * 3, Point::new(15, -9));
+        assert_eq!(-a, Point::new(-5, 3));
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, a + b);
+        c -= b;
+        assert_eq!(c, a);
+
+        let unsigned: Point<usize> = Point::new(4, 9) - Point::new(1, 2);
+        assert_eq!(unsigned, Point::new(3, 7));
+    }
+
+    #[test]
+    fn manhattan_dist() {
+        let a: Point = (8, 7).into();
+        let b: Point = (2, 10).into();
+
+        assert_eq!(a.manhattan_dist(b), 9u32);
+        assert_eq!(b.manhattan_dist(a), 9u32);
+        assert_eq!(a.manhattan_dist(a), 0u32);
+
+        let far: Point<i64> = Point::new(-4_000_000_000, 4_000_000_000);
+        assert_eq!(far.manhattan_dist(Point::ZERO), 8_000_000_000u64);
+    }
+
+    #[test]
+    fn signum() {
+        assert_eq!(Point::new(-7, 0).signum(), Point::new(-1, 0));
+        assert_eq!(Point::new(3, -12).signum(), Point::new(1, -1));
+        assert_eq!(Point::<isize>::ZERO.signum(), Point::ZERO);
+    }
+
+    #[test]
+    fn rem_euclid() {
+        let size = Point::new(6, 4);
+
+        assert_eq!(Point::new(7, 3).rem_euclid(size), Point::new(1, 3));
+        assert_eq!(Point::new(-1, -5).rem_euclid(size), Point::new(5, 3));
+        assert_eq!(Point::new(-6, 8).rem_euclid(size), Point::new(0, 0));
+    }
+
+    #[test]
+    fn min_max() {
+        let points = [(2, 3), (-1, 5), (4, -2), (0, 0)].map(Point::from);
+
+        assert_eq!(
+            Point::min_max(points),
+            Some((Point::new(-1, -2), Point::new(4, 5)))
+        );
+        assert_eq!(
+            Point::min_max([Point::new(1, 1)]),
+            Some((Point::new(1, 1), Point::new(1, 1)))
+        );
+        assert_eq!(Point::<i32>::min_max([]), None);
+    }
+
+    #[test]
+    fn rotations() {
+        let p = Point::new(3, 1);
+
+        assert_eq!(p.rotate_left(), Point::new(-1, 3));
+        assert_eq!(p.rotate_right(), Point::new(1, -3));
+        assert_eq!(p.rotate_left().rotate_left(), -p);
+        assert_eq!(p.rotate_left().rotate_right(), p);
+        assert_eq!(
+            p.rotate_right()
+                .rotate_right()
+                .rotate_right()
+                .rotate_right(),
+            p
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        let p = Point::new(0, 0);
+
+        let neighbours4: Vec<_> = p.neighbours4().collect();
+        assert_eq!(
+            neighbours4,
+            [(1, 0), (0, 1), (-1, 0), (0, -1)].map(Point::from)
+        );
+
+        let center: Point = Point::new(10, 10);
+        let neighbours8: Vec<_> = center.neighbours8().collect();
+        assert_eq!(neighbours8.len(), 8);
+        assert!(neighbours8
+            .iter()
+            .all(|n| n.x.abs_diff(10) <= 1 && n.y.abs_diff(10) <= 1 && *n != center));
+        assert!(neighbours4.iter().all(|n| p.neighbours8().any(|m| m == *n)));
+    }
+}
@@ -0,0 +1,265 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::number::{Number, Signed};
+
+#[derive(Copy, Clone, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Point3<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from(p: (T, T, T)) -> Self {
+        Self {
+            x: p.0,
+            y: p.1,
+            z: p.2,
+        }
+    }
+}
+
+impl<T: Number> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::Output {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl<T: Number> AddAssign for Point3<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl<T: Number> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::Output {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl<T: Number> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl<T: Number> Mul<T> for Point3<T> {
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self::Output {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+impl<T: Signed> Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::Output {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl<T: Number> Point3<T> {
+    pub const ZERO: Self = Self::new(T::ZERO, T::ZERO, T::ZERO);
+
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan_dist(self, other: Self) -> T::Unsigned {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    pub fn rem_euclid(self, other: Self) -> Self {
+        Self {
+            x: self.x.rem_euclid(other.x),
+            y: self.y.rem_euclid(other.y),
+            z: self.z.rem_euclid(other.z),
+        }
+    }
+
+    /// Corners of the smallest box containing all `points`
+    pub fn min_max(points: impl IntoIterator<Item = Self>) -> Option<(Self, Self)> {
+        let mut points = points.into_iter();
+        let mut min = points.next()?;
+        let mut max = min;
+
+        for p in points {
+            min.x = p.x.min(min.x);
+            min.y = p.y.min(min.y);
+            min.z = p.z.min(min.z);
+
+            max.x = p.x.max(max.x);
+            max.y = p.y.max(max.y);
+            max.z = p.z.max(max.z);
+        }
+
+        Some((min, max))
+    }
+}
+
+impl<T: Signed> Point3<T> {
+    pub fn signum(self) -> Self {
+        Self {
+            x: self.x.signum(),
+            y: self.y.signum(),
+            z: self.z.signum(),
+        }
+    }
+
+    /// Rotates 90° counterclockwise around the `x` axis (right-hand rule)
+    pub fn rotate_x(self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.z,
+            z: self.y,
+        }
+    }
+
+    /// Rotates 90° counterclockwise around the `y` axis (right-hand rule)
+    pub fn rotate_y(self) -> Self {
+        Self {
+            x: self.z,
+            y: self.y,
+            z: -self.x,
+        }
+    }
+
+    /// Rotates 90° counterclockwise around the `z` axis (right-hand rule)
+    pub fn rotate_z(self) -> Self {
+        Self {
+            x: -self.y,
+            y: self.x,
+            z: self.z,
+        }
+    }
+
+    /// The 6 points sharing a face with this one
+    pub fn neighbours6(self) -> impl Iterator<Item = Self> {
+        let (one, zero) = (T::ONE, T::ZERO);
+        let offsets = [
+            (one, zero, zero),
+            (-one, zero, zero),
+            (zero, one, zero),
+            (zero, -one, zero),
+            (zero, zero, one),
+            (zero, zero, -one),
+        ];
+
+        offsets.into_iter().map(move |offset| self + offset.into())
+    }
+
+    /// The 26 points sharing a face, an edge or a corner with this one
+    pub fn neighbours26(self) -> impl Iterator<Item = Self> {
+        let deltas = [-T::ONE, T::ZERO, T::ONE];
+
+        deltas
+            .into_iter()
+            .flat_map(move |x| {
+                deltas
+                    .into_iter()
+                    .flat_map(move |y| deltas.into_iter().map(move |z| Point3::new(x, y, z)))
+            })
+            .filter(|offset| *offset != Self::ZERO)
+            .map(move |offset| self + offset)
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a: Point3 = (1, -2, 3).into();
+        let b: Point3 = (4, 5, -6).into();
+
+        assert_eq!(a + b, Point3::new(5, 3, -3));
+        assert_eq!(a - b, Point3::new(-3, -7, 9));
+        assert_eq!(a * -2, Point3::new(-2, 4, -6));
+        assert_eq!(-b, Point3::new(-4, -5, 6));
+
+        let mut c = a;
+        c += b;
+        assert_eq!(c, Point3::new(5, 3, -3));
+        c -= b;
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    fn distances() {
+        let a: Point3 = (1, -2, 3).into();
+
+        assert_eq!(a.manhattan_dist(Point3::ZERO), 6u32);
+        assert_eq!(a.signum(), Point3::new(1, -1, 1));
+        assert_eq!(
+            Point3::new(-1, 7, 2).rem_euclid(Point3::new(3, 3, 3)),
+            Point3::new(2, 1, 2)
+        );
+    }
+
+    #[test]
+    fn min_max() {
+        let points = [(1, 1, 1), (2, 1, 5), (-3, 4, 0)].map(Point3::from);
+
+        assert_eq!(
+            Point3::min_max(points),
+            Some((Point3::new(-3, 1, 0), Point3::new(2, 4, 5)))
+        );
+        assert_eq!(Point3::<i32>::min_max([]), None);
+    }
+
+    #[test]
+    fn rotations() {
+        let p = Point3::new(1, 2, 3);
+
+        assert_eq!(Point3::new(0, 1, 0).rotate_x(), Point3::new(0, 0, 1));
+        assert_eq!(Point3::new(0, 0, 1).rotate_y(), Point3::new(1, 0, 0));
+        assert_eq!(Point3::new(1, 0, 0).rotate_z(), Point3::new(0, 1, 0));
+
+        for rotate in [Point3::rotate_x, Point3::rotate_y, Point3::rotate_z] {
+            assert_eq!(rotate(rotate(rotate(rotate(p)))), p);
+            assert_eq!(rotate(p).manhattan_dist(Point3::ZERO), 6u32);
+        }
+    }
+
+    #[test]
+    fn neighbours() {
+        let p = Point3::new(5, 5, 5);
+
+        let faces: Vec<_> = p.neighbours6().collect();
+        assert_eq!(faces.len(), 6);
+        assert!(faces.iter().all(|n| n.manhattan_dist(p) == 1u32));
+
+        let all: Vec<_> = p.neighbours26().collect();
+        assert_eq!(all.len(), 26);
+        assert!(!all.contains(&p));
+        assert!(faces.iter().all(|n| all.contains(n)));
+    }
+}
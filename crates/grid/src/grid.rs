@@ -0,0 +1,400 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use aoc_core::{parse::lines, ParseError};
+use geometry::Point;
+
+/// Dense 2D grid covering every point from `min` to `max` (inclusive), which can have any origin.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    min: Point,
+    width: usize,
+    height: usize,
+    data: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a `width` x `height` grid with its origin at `(0, 0)`
+    pub fn new(width: usize, height: usize, default: T) -> Self {
+        Self {
+            min: Point::ZERO,
+            width,
+            height,
+            data: vec![default; width * height],
+        }
+    }
+
+    /// Creates a grid covering every point from `min` to `max` (inclusive)
+    pub fn with_bounds(min: Point, max: Point, default: T) -> Self {
+        let width = (max.x - min.x + 1).max(0) as usize;
+        let height = (max.y - min.y + 1).max(0) as usize;
+
+        Self {
+            min,
+            width,
+            height,
+            data: vec![default; width * height],
+        }
+    }
+
+    /// Sets every cell of the grid to `value`
+    pub fn fill(&mut self, value: T) {
+        self.data.fill(value);
+    }
+
+    /// Extends the bounds of the grid so that `point` is inside it, filling the new cells with
+    /// `default`
+    pub fn grow_to_include(&mut self, point: Point, default: T) {
+        if self.in_bounds(point) {
+            return;
+        }
+
+        let (min, max) = if self.data.is_empty() {
+            (point, point)
+        } else {
+            Point::min_max([self.min(), self.max(), point]).unwrap()
+        };
+
+        let mut grown = Self::with_bounds(min, max, default);
+        for (point, value) in self.iter() {
+            grown[point] = value.clone();
+        }
+
+        *self = grown;
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses a character map, with the first line at `y = 0` and the first column at `x = 0`.
+    ///
+    /// `parse_cell` returns what was expected (eg. "a digit") when a character is not a valid cell.
+    pub fn parse(
+        input: &str,
+        mut parse_cell: impl FnMut(char) -> Result<T, &'static str>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut data = vec![];
+
+        for line in lines(input) {
+            let row_len = data.len();
+            for (i, c) in line.text.char_indices() {
+                let cell =
+                    parse_cell(c).map_err(|expected| line.error(&line.text[i..], expected))?;
+                data.push(cell);
+            }
+
+            let width = *width.get_or_insert(data.len());
+            let row_len = data.len() - row_len;
+            if row_len != width {
+                let end = line
+                    .text
+                    .char_indices()
+                    .nth(width)
+                    .map_or(line.text.len(), |(i, _)| i);
+                return Err(line.error(&line.text[end..], format!("a line of {width} cells")));
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            min: Point::ZERO,
+            width: width.unwrap_or(0),
+            height,
+            data,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Smallest point of the grid
+    pub fn min(&self) -> Point {
+        self.min
+    }
+
+    /// Biggest point of the grid
+    pub fn max(&self) -> Point {
+        self.min + Point::new(self.width as i32 - 1, self.height as i32 - 1)
+    }
+
+    pub fn in_bounds(&self, point: Point) -> bool {
+        let offset = point - self.min;
+
+        (0..self.width as i32).contains(&offset.x) && (0..self.height as i32).contains(&offset.y)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.in_bounds(point) {
+            Some(self.get_unchecked(point))
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.in_bounds(point) {
+            Some(self.get_mut_unchecked(point))
+        } else {
+            None
+        }
+    }
+
+    /// Does not check if `point` is in bounds: an out of bounds `x` may wrap into another row
+    pub fn get_unchecked(&self, point: Point) -> &T {
+        &self.data[self.index(point)]
+    }
+
+    /// Does not check if `point` is in bounds: an out of bounds `x` may wrap into another row
+    pub fn get_mut_unchecked(&mut self, point: Point) -> &mut T {
+        let index = self.index(point);
+        &mut self.data[index]
+    }
+
+    fn index(&self, point: Point) -> usize {
+        let offset = point - self.min;
+        offset.y as usize * self.width + offset.x as usize
+    }
+
+    /// Every point of the grid, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (min, max) = (self.min, self.max());
+
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every point of the grid with its value, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.data.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point, &mut T)> {
+        self.points().zip(self.data.iter_mut())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    /// Rows from the smallest to the biggest `y`
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.data.chunks_exact(self.width.max(1))
+    }
+
+    pub fn row(&self, y: i32) -> Option<&[T]> {
+        if !(0..self.height as i32).contains(&(y - self.min.y)) {
+            return None;
+        }
+
+        let start = self.index(Point::new(self.min.x, y));
+        Some(&self.data[start..start + self.width])
+    }
+
+    /// Values of the column `x`, from the smallest to the biggest `y`
+    pub fn column(&self, x: i32) -> impl DoubleEndedIterator<Item = &T> {
+        let ys = if (0..self.width as i32).contains(&(x - self.min.x)) {
+            self.min.y..self.min.y + self.height as i32
+        } else {
+            0..0
+        };
+
+        ys.map(move |y| self.get_unchecked(Point::new(x, y)))
+    }
+
+    /// Columns from the smallest to the biggest `x`
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (self.min.x..=self.max().x).map(|x| self.column(x))
+    }
+
+    /// Orthogonally adjacent points which are in bounds
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbours4().filter(|p| self.in_bounds(*p))
+    }
+
+    /// Orthogonally and diagonally adjacent points which are in bounds
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        point.neighbours8().filter(|p| self.in_bounds(*p))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            min: self.min,
+            width: self.width,
+            height: self.height,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Display> Grid<T> {
+    /// Displays the rows from the biggest to the smallest `y`, for grids where `y` points up
+    pub fn display_y_up(&self) -> impl Display + '_ {
+        DisplayYUp(self)
+    }
+
+    fn fmt_rows<'a>(
+        f: &mut std::fmt::Formatter<'_>,
+        rows: impl Iterator<Item = &'a [T]>,
+    ) -> std::fmt::Result
+    where
+        T: 'a,
+    {
+        for row in rows {
+            for value in row {
+                write!(f, "{value}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point).expect("Point should be in the grid bounds")
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(point)
+            .expect("Point should be in the grid bounds")
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Self::fmt_rows(f, self.rows())
+    }
+}
+
+struct DisplayYUp<'a, T>(&'a Grid<T>);
+
+impl<T: Display> Display for DisplayYUp<'_, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Grid::fmt_rows(f, self.0.rows().rev())
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn get() {
+        let mut grid = Grid::with_bounds(Point::new(-1, 2), Point::new(0, 3), 0);
+        assert_eq!(grid.width(), 2);
+        assert_eq!(grid.height(), 2);
+
+        grid[Point::new(-1, 2)] = 1;
+        grid[Point::new(0, 2)] = 2;
+        grid[Point::new(-1, 3)] = 3;
+        *grid.get_mut(Point::new(0, 3)).unwrap() = 4;
+
+        assert_eq!(grid.get(Point::new(-1, 2)), Some(&1));
+        assert_eq!(grid.get(Point::new(0, 3)), Some(&4));
+        assert_eq!(grid.get(Point::new(1, 3)), None);
+        assert_eq!(grid.get(Point::new(-1, 1)), None);
+
+        assert_eq!(format!("{grid}"), "12\n34\n");
+        assert_eq!(format!("{}", grid.display_y_up()), "34\n12\n");
+    }
+
+    #[test]
+    fn parse() {
+        let grid = Grid::parse("#..\n.#.\n", |c| Ok(c == '#')).unwrap();
+
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.min(), Point::ZERO);
+        assert_eq!(grid.max(), Point::new(2, 1));
+        assert!(grid[Point::new(1, 1)]);
+        assert_eq!(
+            grid.iter()
+                .filter(|(_, v)| **v)
+                .map(|(p, _)| p)
+                .collect::<Vec<_>>(),
+            [Point::new(0, 0), Point::new(1, 1)]
+        );
+        assert_eq!(
+            format!("{}", grid.map(|v| if *v { '#' } else { '.' })),
+            "#..\n.#.\n"
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let digit = |c: char| c.to_digit(10).ok_or("a digit");
+
+        assert_eq!(
+            Grid::parse("123\n4x6", digit),
+            Err(ParseError::new(2, 2, "a digit"))
+        );
+        assert_eq!(
+            Grid::parse("123\n45", digit),
+            Err(ParseError::new(2, 3, "a line of 3 cells"))
+        );
+        assert_eq!(
+            Grid::parse("123\n4567", digit),
+            Err(ParseError::new(2, 4, "a line of 3 cells"))
+        );
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = Grid::parse("123\n456", |c| c.to_digit(10).ok_or("a digit")).unwrap();
+
+        assert_eq!(grid.rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(grid.row(1), Some([4, 5, 6].as_slice()));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+        assert_eq!(grid.column(3).count(), 0);
+        assert_eq!(
+            grid.columns()
+                .map(|c| c.rev().copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            [[4, 1], [5, 2], [6, 3]]
+        );
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(3, 3, ());
+
+        assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours4(Point::ZERO).collect::<Vec<_>>(),
+            [Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours8(Point::new(2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn grow() {
+        let mut grid = Grid::new(2, 1, 1);
+        grid.grow_to_include(Point::new(-1, 2), 0);
+
+        assert_eq!(grid.min(), Point::new(-1, 0));
+        assert_eq!(grid.max(), Point::new(1, 2));
+        assert_eq!(format!("{grid}"), "011\n000\n000\n");
+
+        let mut empty = Grid::new(0, 0, 0);
+        empty.grow_to_include(Point::new(5, -5), 7);
+        assert_eq!(empty.min(), Point::new(5, -5));
+        assert_eq!(empty[Point::new(5, -5)], 7);
+    }
+}
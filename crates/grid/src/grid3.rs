@@ -0,0 +1,220 @@
+use std::ops::{Index, IndexMut};
+
+use geometry::Point3;
+
+/// Dense 3D grid covering every point from `min` to `max` (inclusive), which can have any origin.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid3<T> {
+    min: Point3,
+    width: usize,
+    height: usize,
+    depth: usize,
+    data: Vec<T>,
+}
+
+impl<T: Clone> Grid3<T> {
+    /// Creates a grid covering every point from `min` to `max` (inclusive)
+    pub fn with_bounds(min: Point3, max: Point3, default: T) -> Self {
+        let width = (max.x - min.x + 1).max(0) as usize;
+        let height = (max.y - min.y + 1).max(0) as usize;
+        let depth = (max.z - min.z + 1).max(0) as usize;
+
+        Self {
+            min,
+            width,
+            height,
+            depth,
+            data: vec![default; width * height * depth],
+        }
+    }
+
+    /// Sets every cell of the grid to `value`
+    pub fn fill(&mut self, value: T) {
+        self.data.fill(value);
+    }
+
+    /// Extends the bounds of the grid so that `point` is inside it, filling the new cells with
+    /// `default`
+    pub fn grow_to_include(&mut self, point: Point3, default: T) {
+        if self.in_bounds(point) {
+            return;
+        }
+
+        let (min, max) = if self.data.is_empty() {
+            (point, point)
+        } else {
+            Point3::min_max([self.min(), self.max(), point]).unwrap()
+        };
+
+        let mut grown = Self::with_bounds(min, max, default);
+        for (point, value) in self.iter() {
+            grown[point] = value.clone();
+        }
+
+        *self = grown;
+    }
+}
+
+impl<T> Grid3<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Smallest point of the grid
+    pub fn min(&self) -> Point3 {
+        self.min
+    }
+
+    /// Biggest point of the grid
+    pub fn max(&self) -> Point3 {
+        self.min
+            + Point3::new(
+                self.width as i32 - 1,
+                self.height as i32 - 1,
+                self.depth as i32 - 1,
+            )
+    }
+
+    pub fn in_bounds(&self, point: Point3) -> bool {
+        let offset = point - self.min;
+
+        (0..self.width as i32).contains(&offset.x)
+            && (0..self.height as i32).contains(&offset.y)
+            && (0..self.depth as i32).contains(&offset.z)
+    }
+
+    pub fn get(&self, point: Point3) -> Option<&T> {
+        if self.in_bounds(point) {
+            Some(self.get_unchecked(point))
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point3) -> Option<&mut T> {
+        if self.in_bounds(point) {
+            Some(self.get_mut_unchecked(point))
+        } else {
+            None
+        }
+    }
+
+    /// Does not check if `point` is in bounds: an out of bounds coordinate may wrap into another
+    /// row
+    pub fn get_unchecked(&self, point: Point3) -> &T {
+        &self.data[self.index(point)]
+    }
+
+    /// Does not check if `point` is in bounds: an out of bounds coordinate may wrap into another
+    /// row
+    pub fn get_mut_unchecked(&mut self, point: Point3) -> &mut T {
+        let index = self.index(point);
+        &mut self.data[index]
+    }
+
+    fn index(&self, point: Point3) -> usize {
+        let offset = point - self.min;
+        (offset.z as usize * self.height + offset.y as usize) * self.width + offset.x as usize
+    }
+
+    /// Every point of the grid, layer by layer (`z`), then row by row (`y`)
+    pub fn points(&self) -> impl Iterator<Item = Point3> {
+        let (min, max) = (self.min, self.max());
+
+        (min.z..=max.z).flat_map(move |z| {
+            (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point3::new(x, y, z)))
+        })
+    }
+
+    /// Every point of the grid with its value, in the same order as [`Grid3::points`]
+    pub fn iter(&self) -> impl Iterator<Item = (Point3, &T)> {
+        self.points().zip(self.data.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Point3, &mut T)> {
+        self.points().zip(self.data.iter_mut())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.data.iter()
+    }
+
+    /// Points sharing a face with `point` which are in bounds
+    pub fn neighbours6(&self, point: Point3) -> impl Iterator<Item = Point3> + '_ {
+        point.neighbours6().filter(|p| self.in_bounds(*p))
+    }
+
+    /// Points sharing a face, an edge or a corner with `point` which are in bounds
+    pub fn neighbours26(&self, point: Point3) -> impl Iterator<Item = Point3> + '_ {
+        point.neighbours26().filter(|p| self.in_bounds(*p))
+    }
+}
+
+impl<T> Index<Point3> for Grid3<T> {
+    type Output = T;
+
+    fn index(&self, point: Point3) -> &Self::Output {
+        self.get(point).expect("Point should be in the grid bounds")
+    }
+}
+
+impl<T> IndexMut<Point3> for Grid3<T> {
+    fn index_mut(&mut self, point: Point3) -> &mut Self::Output {
+        self.get_mut(point)
+            .expect("Point should be in the grid bounds")
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn simple_usage() {
+        let mut grid = Grid3::with_bounds(Point3::new(-1, 0, 2), Point3::new(1, 1, 3), 0);
+        assert_eq!((grid.width(), grid.height(), grid.depth()), (3, 2, 2));
+
+        for (i, (_, value)) in grid.iter_mut().enumerate() {
+            *value = i;
+        }
+
+        assert_eq!(grid[Point3::new(-1, 0, 2)], 0);
+        assert_eq!(grid[Point3::new(0, 0, 2)], 1);
+        assert_eq!(grid[Point3::new(-1, 1, 2)], 3);
+        assert_eq!(grid[Point3::new(-1, 0, 3)], 6);
+        assert_eq!(grid[Point3::new(1, 1, 3)], 11);
+        assert_eq!(grid.get(Point3::new(2, 1, 3)), None);
+        assert_eq!(grid.get(Point3::new(0, 0, 1)), None);
+
+        assert!(grid
+            .iter()
+            .all(|(point, value)| *grid.get_unchecked(point) == *value));
+    }
+
+    #[test]
+    fn neighbours_and_grow() {
+        let mut grid = Grid3::with_bounds(Point3::ZERO, Point3::new(2, 2, 2), false);
+
+        assert_eq!(grid.neighbours6(Point3::new(1, 1, 1)).count(), 6);
+        assert_eq!(grid.neighbours6(Point3::ZERO).count(), 3);
+        assert_eq!(grid.neighbours26(Point3::ZERO).count(), 7);
+
+        grid[Point3::new(2, 2, 2)] = true;
+        grid.grow_to_include(Point3::new(-1, 0, 3), false);
+
+        assert_eq!(grid.min(), Point3::new(-1, 0, 0));
+        assert_eq!(grid.max(), Point3::new(2, 2, 3));
+        assert_eq!(grid.values().filter(|v| **v).count(), 1);
+        assert!(grid[Point3::new(2, 2, 2)]);
+    }
+}
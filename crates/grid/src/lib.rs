@@ -0,0 +1,5 @@
+pub mod grid;
+pub mod grid3;
+
+pub use grid::Grid;
+pub use grid3::Grid3;
@@ -0,0 +1,36 @@
+use std::fmt::Debug;
+
+/// Integers which can bound the ranges of an `IntervalSet`
+pub trait Integer: Copy + Ord + Debug {
+    /// Integer right after this one, or `None` for the largest one
+    fn next(self) -> Option<Self>;
+
+    /// Integer right before this one, or `None` for the smallest one
+    fn prev(self) -> Option<Self>;
+
+    /// Number of integers from this one to `end`, both included, saturating at `u64::MAX`
+    fn count_to(self, end: Self) -> u64;
+}
+
+macro_rules! integer {
+    ($($integer:ty),*) => {
+        $(
+            impl Integer for $integer {
+                fn next(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn prev(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn count_to(self, end: Self) -> u64 {
+                    let count = end as i128 - self as i128 + 1;
+                    u64::try_from(count.max(0)).unwrap_or(u64::MAX)
+                }
+            }
+        )*
+    };
+}
+
+integer!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
@@ -0,0 +1,291 @@
+pub mod integer;
+
+use std::ops::RangeInclusive;
+
+pub use integer::Integer;
+
+/// Set of integers, stored as the smallest list of sorted inclusive ranges: they neither overlap
+/// nor touch each other, so two equal sets have the same ranges.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<RangeInclusive<T>>,
+}
+
+impl<T: Integer> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// Sorted ranges of the set, separated by at least one integer
+    pub fn ranges(&self) -> &[RangeInclusive<T>] {
+        &self.ranges
+    }
+
+    /// Number of integers in the set
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| range.start().count_to(*range.end()))
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Smallest range containing the whole set
+    pub fn span(&self) -> Option<RangeInclusive<T>> {
+        let first = self.ranges.first()?;
+        let last = self.ranges.last()?;
+
+        Some(*first.start()..=*last.end())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| *range.end() < value);
+
+        self.ranges
+            .get(index)
+            .is_some_and(|range| *range.start() <= value)
+    }
+
+    /// Adds the integers of `range`, merging it with the ranges it overlaps or touches
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let (mut start, mut end) = range.into_inner();
+
+        let first = self
+            .ranges
+            .partition_point(|range| range.end().next().is_some_and(|next| next < start));
+        let last = self
+            .ranges
+            .partition_point(|range| end.next().is_none_or(|next| *range.start() <= next));
+
+        if first < last {
+            start = start.min(*self.ranges[first].start());
+            end = end.max(*self.ranges[last - 1].end());
+        }
+        self.ranges.splice(first..last, [start..=end]);
+    }
+
+    /// Integers in this set or in `other`
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    /// Integers in both this set and `other`
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut mine, mut theirs) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+
+        while let (Some(a), Some(b)) = (mine.peek(), theirs.peek()) {
+            let start = *a.start().max(b.start());
+            let end = *a.end().min(b.end());
+            if start <= end {
+                ranges.push(start..=end);
+            }
+
+            // The range ending first cannot overlap the next ranges of the other set
+            if a.end() < b.end() {
+                mine.next();
+            } else {
+                theirs.next();
+            }
+        }
+
+        Self { ranges }
+    }
+
+    /// Integers in this set but not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        match self.span() {
+            Some(span) => self.intersection(&other.complement(span)),
+            None => Self::new(),
+        }
+    }
+
+    /// Integers of `bounds` which are not in the set
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> Self {
+        let mut ranges = Vec::new();
+        let (start, end) = bounds.into_inner();
+
+        // First integer of the bounds which is not known to be in the set, if any
+        let mut from = Some(start);
+        for range in &self.ranges {
+            let Some(gap_start) = from.filter(|&from| from <= end) else {
+                break;
+            };
+            if *range.start() > end {
+                break;
+            }
+
+            if *range.start() > gap_start {
+                let gap_end = range
+                    .start()
+                    .prev()
+                    .expect("a range should start after the gap");
+                ranges.push(gap_start..=gap_end);
+            }
+            if *range.end() >= gap_start {
+                from = range.end().next();
+            }
+        }
+
+        if let Some(from) = from.filter(|&from| from <= end) {
+            ranges.push(from..=end);
+        }
+
+        Self { ranges }
+    }
+
+    /// Ranges of the integers missing between the ranges of the set
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.windows(2).map(|pair| {
+            let start = pair[0].end().next().expect("a range should follow");
+            let end = pair[1].start().prev().expect("a range should precede");
+
+            start..=end
+        })
+    }
+
+    /// Whether every integer of `other` is in this set
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.ranges.iter().all(|range| {
+            let index = self
+                .ranges
+                .partition_point(|mine| mine.end() < range.start());
+
+            self.ranges
+                .get(index)
+                .is_some_and(|mine| mine.start() <= range.start() && mine.end() >= range.end())
+        })
+    }
+
+    /// Whether this set and `other` have no integer in common
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+}
+
+impl<T: Integer> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Integer> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+
+        set
+    }
+}
+
+impl<T: Integer> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    /// Sorts then merges the ranges, rather than inserting them one by one
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(ranges: I) -> Self {
+        let mut ranges: Vec<_> = ranges.into_iter().filter(|r| !r.is_empty()).collect();
+        ranges.sort_unstable_by_key(|range| *range.start());
+
+        // Each range is merged in the previous one it overlaps or touches
+        ranges.dedup_by(|range, previous| {
+            let touching = previous
+                .end()
+                .next()
+                .is_none_or(|next| *range.start() <= next);
+            if touching && range.end() > previous.end() {
+                *previous = *previous.start()..=*range.end();
+            }
+
+            touching
+        });
+
+        Self { ranges }
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn set(ranges: &[RangeInclusive<i32>]) -> IntervalSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn normalized() {
+        let merged = set(&[8..=9, 1..=3, 5..=5, 2..=4, 12..=11, 10..=10]);
+        assert_eq!(merged.ranges(), [1..=5, 8..=10]);
+        assert_eq!(merged.len(), 8);
+        assert_eq!(merged.span(), Some(1..=10));
+
+        let mut inserted = IntervalSet::new();
+        for range in [8..=9, 1..=3, 5..=5, 2..=4, 12..=11, 10..=10] {
+            inserted.insert(range);
+        }
+        assert_eq!(inserted, merged);
+
+        inserted.insert(0..=20);
+        assert_eq!(inserted.ranges(), [0..=20]);
+
+        assert!(set(&[]).is_empty());
+        assert_eq!(set(&[3..=2]).span(), None);
+    }
+
+    #[test]
+    fn contains() {
+        let set = set(&[1..=3, 7..=8]);
+
+        assert!(set.contains(1) && set.contains(3) && set.contains(8));
+        assert!(!set.contains(0) && !set.contains(5) && !set.contains(9));
+
+        assert!(set.is_superset(&(2..=3).into()));
+        assert!(set.is_superset(&IntervalSet::new()));
+        assert!(!set.is_superset(&(2..=7).into()));
+        assert!(!set.is_superset(&(9..=9).into()));
+    }
+
+    #[test]
+    fn operations() {
+        let a = set(&[1..=5, 10..=15]);
+        let b = set(&[4..=11, 20..=20]);
+
+        assert_eq!(a.union(&b).ranges(), [1..=15, 20..=20]);
+        assert_eq!(a.intersection(&b).ranges(), [4..=5, 10..=11]);
+        assert_eq!(a.difference(&b).ranges(), [1..=3, 12..=15]);
+        assert_eq!(b.difference(&a).ranges(), [6..=9, 20..=20]);
+
+        assert!(!a.is_disjoint(&b));
+        assert!(a.is_disjoint(&set(&[6..=9])));
+        assert!(a.difference(&a).is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn complement() {
+        let set = set(&[1..=5, 10..=15]);
+
+        assert_eq!(set.complement(0..=20).ranges(), [0..=0, 6..=9, 16..=20]);
+        assert_eq!(set.complement(3..=12).ranges(), [6..=9]);
+        assert_eq!(set.complement(6..=9).ranges(), [6..=9]);
+        assert!(set.complement(10..=15).is_empty());
+        assert!(set.complement(1..=0).is_empty());
+        assert_eq!(set.gaps().collect::<Vec<_>>(), [6..=9]);
+
+        // At the limits of the integers
+        let full = IntervalSet::from(0..=u8::MAX);
+        assert!(full.complement(0..=u8::MAX).is_empty());
+        assert_eq!(full.len(), 256);
+        let edges = IntervalSet::from_iter([0..=0, 255..=255]);
+        assert_eq!(edges.complement(0..=u8::MAX).ranges(), [1..=254]);
+    }
+}
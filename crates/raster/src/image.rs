@@ -0,0 +1,221 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use geometry::Point;
+use grid::Grid;
+
+use crate::png;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    /// Gray of the given brightness
+    pub const fn gray(level: u8) -> Self {
+        Self(level, level, level)
+    }
+
+    /// Colour of a heatmap, from dark blue for `t` = 0 through red to light yellow for `t` = 1
+    pub fn heat(t: f64) -> Self {
+        const STOPS: [Rgb; 5] = [
+            Rgb(0, 0, 48),
+            Rgb(80, 20, 160),
+            Rgb(200, 40, 80),
+            Rgb(250, 140, 20),
+            Rgb(255, 250, 180),
+        ];
+
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let scaled = t * (STOPS.len() - 1) as f64;
+        let i = (scaled as usize).min(STOPS.len() - 2);
+
+        Self::lerp(STOPS[i], STOPS[i + 1], scaled - i as f64)
+    }
+
+    fn lerp(from: Rgb, to: Rgb, t: f64) -> Self {
+        let channel =
+            |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * t).round() as u8;
+
+        Self(
+            channel(from.0, to.0),
+            channel(from.1, to.1),
+            channel(from.2, to.2),
+        )
+    }
+}
+
+/// Raster image, written as PPM or PNG.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    /// Rows from the top of the image
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// Image with a pixel per cell of `grid`, coloured by `color`. The row of the smallest y is at
+    /// the top of the image.
+    pub fn from_grid<T>(grid: &Grid<T>, color: impl Fn(&T) -> Rgb) -> Self {
+        let mut image = Self::new(grid.width(), grid.height(), Rgb::BLACK);
+        let min = grid.min();
+
+        for (point, value) in grid.iter() {
+            let pixel = point - min;
+            image.set(pixel.x as usize, pixel.y as usize, color(value));
+        }
+
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Rgb {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, color: Rgb) {
+        self.pixels[y * self.width + x] = color;
+    }
+
+    /// Sets the pixel of the grid cell `point`, in an image made by `from_grid` from `grid`
+    pub fn set_cell<T>(&mut self, grid: &Grid<T>, point: Point, color: Rgb) {
+        let pixel = point - grid.min();
+        self.set(pixel.x as usize, pixel.y as usize, color);
+    }
+
+    /// Flips the rows, for grids where y points up
+    pub fn flip_y(mut self) -> Self {
+        let rows: Vec<_> = self.pixels.chunks(self.width.max(1)).rev().collect();
+        self.pixels = rows.concat();
+        self
+    }
+
+    /// Enlarges every pixel to a `factor` × `factor` square
+    pub fn scale(self, factor: usize) -> Self {
+        if factor <= 1 {
+            return self;
+        }
+
+        let mut scaled = Self::new(self.width * factor, self.height * factor, Rgb::BLACK);
+        for y in 0..scaled.height {
+            for x in 0..scaled.width {
+                scaled.set(x, y, self.get(x / factor, y / factor));
+            }
+        }
+
+        scaled
+    }
+
+    /// Writes a binary PPM (`P6`) image
+    pub fn write_ppm(&self, mut out: impl Write) -> io::Result<()> {
+        write!(out, "P6\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.bytes())?;
+        out.flush()
+    }
+
+    /// Writes an uncompressed PNG image
+    pub fn write_png(&self, mut out: impl Write) -> io::Result<()> {
+        let rows: Vec<_> = self
+            .bytes()
+            .chunks(self.width.max(1) * 3)
+            .map(Vec::from)
+            .collect();
+
+        png::write(&mut out, self.width as u32, self.height as u32, &rows)?;
+        out.flush()
+    }
+
+    /// Writes the image as PNG or PPM depending on the extension of `path`
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        if !matches!(extension, Some("png" | "ppm")) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "images are written to .png or .ppm files",
+            ));
+        }
+
+        let out = BufWriter::new(File::create(path)?);
+        match extension {
+            Some("png") => self.write_png(out),
+            _ => self.write_ppm(out),
+        }
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|&Rgb(r, g, b)| [r, g, b])
+            .collect()
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn image() -> Image {
+        let mut grid = Grid::with_bounds(Point::new(-1, 5), Point::new(1, 6), 0);
+        grid[Point::new(1, 5)] = 1;
+
+        Image::from_grid(
+            &grid,
+            |&cell| if cell == 1 { Rgb::WHITE } else { Rgb::BLACK },
+        )
+    }
+
+    #[test]
+    fn ppm() {
+        let mut ppm = Vec::new();
+        image().write_ppm(&mut ppm).unwrap();
+
+        let mut expected = b"P6\n3 2\n255\n".to_vec();
+        expected.extend([0, 0, 0, 0, 0, 0, 255, 255, 255]);
+        expected.extend([0; 9]);
+        assert_eq!(ppm, expected);
+    }
+
+    #[test]
+    fn transform() {
+        let image = image().flip_y();
+        assert_eq!(image.get(2, 1), Rgb::WHITE);
+        assert_eq!(image.get(2, 0), Rgb::BLACK);
+
+        let image = image.scale(2);
+        assert_eq!((image.width(), image.height()), (6, 4));
+        assert_eq!(image.get(4, 2), Rgb::WHITE);
+        assert_eq!(image.get(5, 3), Rgb::WHITE);
+        assert_eq!(image.get(3, 3), Rgb::BLACK);
+    }
+
+    #[test]
+    fn heat() {
+        assert_eq!(Rgb::heat(0.0), Rgb(0, 0, 48));
+        assert_eq!(Rgb::heat(1.0), Rgb(255, 250, 180));
+        assert_eq!(Rgb::heat(2.0), Rgb::heat(1.0));
+        assert_eq!(Rgb::heat(0.125), Rgb(40, 10, 104));
+    }
+}
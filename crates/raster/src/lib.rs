@@ -0,0 +1,12 @@
+pub mod image;
+mod png;
+
+use aoc_core::{Part, Solution};
+
+pub use image::{Image, Rgb};
+
+/// Solution whose state can be exported as an image, to inspect it at full resolution.
+pub trait Render: Solution {
+    /// Solves `part` of `input`, returning an image of the final state
+    fn render(&self, input: &Self::Input, part: Part) -> Image;
+}
@@ -0,0 +1,127 @@
+//! Minimal PNG encoder: 8-bit RGB, without filtering nor compression (the zlib stream is made of
+//! stored deflate blocks).
+
+use std::io::{self, Write};
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+
+/// Largest length of a stored deflate block
+const MAX_BLOCK: usize = u16::MAX as usize;
+
+/// Writes an image made of `rows` of RGB bytes
+pub fn write(out: &mut impl Write, width: u32, height: u32, rows: &[Vec<u8>]) -> io::Result<()> {
+    out.write_all(&SIGNATURE)?;
+
+    let mut header = Vec::new();
+    header.extend(width.to_be_bytes());
+    header.extend(height.to_be_bytes());
+    // Bit depth, colour type (RGB), compression, filter and interlace methods
+    header.extend([8, 2, 0, 0, 0]);
+    chunk(out, b"IHDR", &header)?;
+
+    // Each row starts with its filter type, 0 for none
+    let mut data = Vec::new();
+    for row in rows {
+        data.push(0);
+        data.extend(row);
+    }
+    chunk(out, b"IDAT", &zlib(&data))?;
+
+    chunk(out, b"IEND", &[])
+}
+
+fn chunk(out: &mut impl Write, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    out.write_all(&(data.len() as u32).to_be_bytes())?;
+    out.write_all(kind)?;
+    out.write_all(data)?;
+
+    let crc = crc32(kind.iter().chain(data));
+    out.write_all(&crc.to_be_bytes())
+}
+
+fn zlib(data: &[u8]) -> Vec<u8> {
+    // Deflate with a 32K window, no preset dictionary, and the check bits of the header
+    let mut stream = vec![0x78, 0x01];
+
+    let mut blocks = data.chunks(MAX_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+
+        stream.push(last as u8);
+        stream.extend(len.to_le_bytes());
+        stream.extend((!len).to_le_bytes());
+        stream.extend(block);
+    }
+
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u32 {
+    let mut crc = u32::MAX;
+
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let (mut a, mut b) = (1, 0);
+
+    for &byte in bytes {
+        a = (a + byte as u32) % MOD;
+        b = (b + a) % MOD;
+    }
+
+    (b << 16) | a
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn stored_blocks() {
+        let data = vec![7; MAX_BLOCK + 10];
+        let stream = zlib(&data);
+
+        // Header, two blocks with their 5 bytes headers, and the checksum
+        assert_eq!(stream.len(), 2 + 5 + MAX_BLOCK + 5 + 10 + 4);
+        assert_eq!(stream[2..7], [0, 0xff, 0xff, 0, 0]);
+        assert_eq!(
+            stream[7 + MAX_BLOCK..12 + MAX_BLOCK],
+            [1, 10, 0, 0xf5, 0xff]
+        );
+    }
+
+    #[test]
+    fn png() {
+        let mut png = Vec::new();
+        write(&mut png, 1, 1, &[vec![255, 0, 0]]).unwrap();
+
+        assert_eq!(png[..8], SIGNATURE);
+        assert_eq!(png[12..16], *b"IHDR");
+        assert!(png.ends_with(&[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]));
+    }
+}
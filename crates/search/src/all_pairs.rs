@@ -0,0 +1,56 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::{dijkstra, Cost};
+
+/// Lowest costs between every pair of `states`: `costs[i][j]` is the cost from `states[i]` to
+/// `states[j]`, or `None` if it cannot be reached.
+///
+/// A search is made from each state, over the states given by `neighbours` with the cost of each
+/// move, which do not need to be in `states`.
+pub fn all_pairs<S, C, I>(states: &[S], mut neighbours: impl FnMut(&S) -> I) -> Vec<Vec<Option<C>>>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let indexes: HashMap<&S, usize> = states.iter().enumerate().map(|(i, s)| (s, i)).collect();
+
+    states
+        .iter()
+        .map(|from| {
+            let search = dijkstra([from.clone()], &mut neighbours, |_| false);
+
+            let mut costs = vec![None; states.len()];
+            for (state, cost) in search.costs() {
+                if let Some(&index) = indexes.get(state) {
+                    costs[index] = Some(cost);
+                }
+            }
+
+            costs
+        })
+        .collect()
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        // 0 -> 1 -> 2 -> 0 and 3 alone
+        let edges = [vec![1], vec![2], vec![0], vec![]];
+        let neighbours = |&i: &usize| edges[i].iter().map(|&j| (j, 1u32)).collect::<Vec<_>>();
+
+        let costs = all_pairs(&[0, 1, 2, 3], neighbours);
+        assert_eq!(costs[0], [Some(0), Some(1), Some(2), None]);
+        assert_eq!(costs[2], [Some(1), Some(2), Some(0), None]);
+        assert_eq!(costs[3], [None, None, None, Some(0)]);
+
+        // Only between the given states, through the others
+        let costs = all_pairs(&[2, 1], neighbours);
+        assert_eq!(costs, [[Some(0), Some(2)], [Some(1), Some(0)]]);
+    }
+}
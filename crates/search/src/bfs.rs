@@ -0,0 +1,84 @@
+use std::{collections::VecDeque, hash::Hash};
+
+use crate::Search;
+
+/// Breadth-first search from `starts`, each move costing 1, until a state for which `goal` is true
+/// is reached (`|_| false` reaches every reachable state)
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if search.reach(&start, None, 0) {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((state, cost)) = queue.pop_front() {
+        if goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        for next in neighbours(&state) {
+            if search.reach(&next, Some(&state), cost + 1) {
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+
+    search
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Moves on a line from 0 to 9, by 1 or by jumping from 3 to 7
+    fn line(&n: &i32) -> Vec<i32> {
+        let mut next: Vec<_> = [n - 1, n + 1]
+            .into_iter()
+            .filter(|n| (0..10).contains(n))
+            .collect();
+        if n == 3 {
+            next.push(7);
+        }
+
+        next
+    }
+
+    #[test]
+    fn shortest_path() {
+        let search = bfs([0], line, |&n| n == 8);
+
+        assert_eq!(search.goal(), Some(&8));
+        assert_eq!(search.goal_cost(), Some(5));
+        assert_eq!(search.path(&8), Some(vec![0, 1, 2, 3, 7, 8]));
+        assert_eq!(search.path(&0), Some(vec![0]));
+    }
+
+    #[test]
+    fn flood() {
+        let search = bfs([0, 9], line, |_| false);
+
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.len(), 10);
+        assert_eq!(search.cost(&5), Some(4));
+        assert_eq!(search.path(&6), Some(vec![9, 8, 7, 6]));
+
+        let search = bfs([20], line, |_| false);
+        assert_eq!(search.cost(&20), Some(0));
+        assert_eq!(search.cost(&19), None);
+        assert_eq!(search.path(&19), None);
+    }
+}
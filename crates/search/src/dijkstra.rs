@@ -0,0 +1,132 @@
+use std::{cmp::Reverse, collections::BinaryHeap, hash::Hash};
+
+use crate::{Cost, Search};
+
+/// Dijkstra's search from `starts`, `neighbours` giving the states reached from a state with the
+/// cost of each move, until a state for which `goal` is true is reached
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), goal)
+}
+
+/// A* search, like `dijkstra` but exploring first the states for which `heuristic` estimates the
+/// lowest cost to a goal.
+///
+/// The heuristic should never overestimate the cost to the nearest goal, nor decrease by more
+/// than the cost of a move, for the cost of the goal to be the lowest one.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new();
+    // The queued states are kept aside, so that they do not need to be ordered
+    let mut states = Vec::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        if search.reach(&start, None, C::default()) {
+            queue.push(Reverse((heuristic(&start), C::default(), states.len())));
+            states.push(start);
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        let state = states[index].clone();
+
+        // Queued again since, for a lower cost
+        if search.cost(&state) != Some(cost) {
+            continue;
+        }
+
+        if goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+
+            if search.reach(&next, Some(&state), next_cost) {
+                queue.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    states.len(),
+                )));
+                states.push(next);
+            }
+        }
+    }
+
+    search
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Weighted graph where the direct edges are more expensive than the detours
+    fn graph(&node: &char) -> Vec<(char, u32)> {
+        let edges: &[(char, u32)] = match node {
+            'a' => &[('b', 1), ('c', 10)],
+            'b' => &[('c', 2), ('d', 20)],
+            'c' => &[('d', 3)],
+            _ => &[],
+        };
+
+        edges.to_vec()
+    }
+
+    #[test]
+    fn weighted() {
+        let search = dijkstra(['a'], graph, |&node| node == 'd');
+
+        assert_eq!(search.goal_cost(), Some(6));
+        assert_eq!(search.path(&'d'), Some(vec!['a', 'b', 'c', 'd']));
+
+        let search = dijkstra(['c'], graph, |_| false);
+        assert_eq!(search.len(), 2);
+        assert_eq!(search.cost(&'a'), None);
+    }
+
+    #[test]
+    fn heuristic() {
+        let target = (7, 3);
+        let neighbours = |&(x, y): &(i32, i32)| {
+            // A wall at x = 4, except at y = 0
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| (-10..10).contains(&x) && (-10..10).contains(&y))
+                .filter(|&(x, y)| x != 4 || y == 0)
+                .map(|next| (next, 1))
+        };
+        let manhattan = |&(x, y): &(i32, i32)| x.abs_diff(target.0) + y.abs_diff(target.1);
+
+        let astar = astar([(0, 3)], neighbours, manhattan, |&p| p == target);
+        let dijkstra = dijkstra([(0, 3)], neighbours, |&p| p == target);
+
+        assert_eq!(astar.goal_cost(), Some(13));
+        assert_eq!(dijkstra.goal_cost(), Some(13));
+        assert!(astar.len() < dijkstra.len());
+
+        let path = astar.path(&target).unwrap();
+        assert_eq!(path.len(), 14);
+        assert!(path.contains(&(4, 0)));
+    }
+}
@@ -0,0 +1,96 @@
+pub mod all_pairs;
+pub mod bfs;
+pub mod dijkstra;
+
+use std::{collections::HashMap, hash::Hash, ops::Add};
+
+pub use all_pairs::all_pairs;
+pub use bfs::bfs;
+pub use dijkstra::{astar, dijkstra};
+
+/// Cost of the moves of a weighted search, `Default` being the cost of not moving.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// States reached by a search, with the lowest cost to reach each of them from one of the starts.
+///
+/// The search stops at the first goal it reaches, so the costs are only the lowest ones for the
+/// states settled before it.
+#[derive(Debug, Clone)]
+pub struct Search<S, C = usize> {
+    costs: HashMap<S, C>,
+    /// State from which each state was reached, the starts having none
+    parents: HashMap<S, S>,
+    goal: Option<S>,
+}
+
+impl<S: Clone + Eq + Hash, C: Cost> Search<S, C> {
+    fn new() -> Self {
+        Self {
+            costs: HashMap::new(),
+            parents: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// Lowest cost to reach `state`, or `None` if it was not reached
+    pub fn cost(&self, state: &S) -> Option<C> {
+        self.costs.get(state).copied()
+    }
+
+    /// Goal at which the search stopped, or `None` if it reached every state without finding one
+    pub fn goal(&self) -> Option<&S> {
+        self.goal.as_ref()
+    }
+
+    /// Cost of the goal at which the search stopped
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal.as_ref()?)
+    }
+
+    /// Reached states with their cost, in no particular order
+    pub fn costs(&self) -> impl Iterator<Item = (&S, C)> + '_ {
+        self.costs.iter().map(|(state, cost)| (state, *cost))
+    }
+
+    /// Number of states reached
+    pub fn len(&self) -> usize {
+        self.costs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.costs.is_empty()
+    }
+
+    /// States of the cheapest path found from a start to `to`, both included
+    pub fn path(&self, to: &S) -> Option<Vec<S>> {
+        if !self.costs.contains_key(to) {
+            return None;
+        }
+
+        let mut path = vec![to.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Records that `state` can be reached from `parent` (or is a start) for `cost`, returning
+    /// whether it is cheaper than the way it was reached before
+    fn reach(&mut self, state: &S, parent: Option<&S>, cost: C) -> bool {
+        if self.costs.get(state).is_some_and(|old| *old <= cost) {
+            return false;
+        }
+
+        self.costs.insert(state.clone(), cost);
+        match parent {
+            Some(parent) => self.parents.insert(state.clone(), parent.clone()),
+            None => self.parents.remove(state),
+        };
+
+        true
+    }
+}
@@ -1,5 +0,0 @@
-pub mod canvas;
-pub mod coord;
-pub mod grid;
-pub mod line;
-pub mod point;
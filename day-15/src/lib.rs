@@ -1,3 +0,0 @@
-pub mod circle;
-pub mod clamp;
-pub mod point;
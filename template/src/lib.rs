@@ -0,0 +1,46 @@
+//! DayN: <title of the puzzle>.
+//!
+//! The input is <what the puzzle input lists>.
+
+use aoc_core::{parse::lines, Answer, ParseError, Solution};
+
+/// Solution of the day, with the lines of the puzzle input as input
+#[derive(Default)]
+pub struct DayN;
+
+impl Solution for DayN {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Ok(lines(input).map(|line| line.text.trim().to_owned()).collect())
+    }
+
+    fn part_one(&self, _input: &Self::Input) -> Answer {
+        Answer::Unimplemented
+    }
+
+    fn part_two(&self, _input: &Self::Input) -> Answer {
+        Answer::Unimplemented
+    }
+}
+
+// --- TESTS ---
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let day = DayN::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_one(&input), Answer::Unimplemented);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let day = DayN::example();
+        let input = day.parse(include_str!("../input.test.txt")).unwrap();
+        assert_eq!(day.part_two(&input), Answer::Unimplemented);
+    }
+}